@@ -0,0 +1,91 @@
+//! Example demonstrating `GradientAnimation`, which rotates, scrolls and pulses gradients in the shader.
+
+use bevy::color::palettes::css::DARK_BLUE;
+use bevy::color::palettes::css::DEEP_SKY_BLUE;
+use bevy::color::palettes::css::GOLD;
+use bevy::color::palettes::css::MEDIUM_PURPLE;
+use bevy::color::palettes::css::ORANGE_RED;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+use std::f32::consts::TAU;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands.spawn((
+                panel(),
+                BackgroundGradient::from(LinearGradient::to_right(vec![
+                    DARK_BLUE.into(),
+                    DEEP_SKY_BLUE.into(),
+                ])),
+                GradientAnimation::rotate(0.25 * TAU),
+                children![Text::new("Rotate")],
+            ));
+
+            commands.spawn((
+                panel(),
+                BackgroundGradient::from(ConicGradient::new(vec![
+                    AngularColorStop::auto(GOLD),
+                    AngularColorStop::auto(ORANGE_RED),
+                    AngularColorStop::auto(MEDIUM_PURPLE),
+                    AngularColorStop::auto(GOLD),
+                ])),
+                GradientAnimation::rotate(0.5 * TAU),
+                children![Text::new("Spin")],
+            ));
+
+            commands.spawn((
+                panel(),
+                BackgroundGradient::from(RadialGradient::new(
+                    Position::CENTER,
+                    RadialGradientShape::FarthestCorner,
+                    vec![
+                        ColorStop::new(ORANGE_RED, Val::Px(0.)),
+                        ColorStop::new(GOLD, Val::Px(200.)),
+                    ],
+                )),
+                GradientAnimation::scroll(40.),
+                children![Text::new("Scroll")],
+            ));
+
+            commands.spawn((
+                panel(),
+                BackgroundGradient::from(LinearGradient::to_bottom(vec![
+                    MEDIUM_PURPLE.into(),
+                    DEEP_SKY_BLUE.into(),
+                ])),
+                GradientAnimation::pulse(0.8, 0.5),
+                children![Text::new("Pulse")],
+            ));
+        });
+}
+
+fn panel() -> impl Bundle {
+    (
+        Node {
+            width: Val::Px(200.),
+            height: Val::Px(200.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        },
+        BorderRadius::all(Val::Px(20.)),
+    )
+}
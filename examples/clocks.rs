@@ -63,13 +63,16 @@ fn update_clocks(time: Res<Time>, mut query: Query<(&ClockMode, &mut BackgroundG
             _ => TAU,
         };
         *gradient = BackgroundGradient::from(ConicGradient {
-            start: 0.,
+            start: Angle::ZERO,
             position: Default::default(),
-            stops: vec![
+            // Converting an array stores the stops inline, without allocating
+            stops: [
                 AngularColorStop::new(RED, 0.0),
                 AngularColorStop::new(RED, angle),
                 AngularColorStop::new(BLUE, angle),
-            ],
+            ]
+            .into(),
+            ..default()
         });
     }
 }
@@ -0,0 +1,82 @@
+//! Example of a color picker built from a `HueWheel` and a `SaturationValueBox`.
+//! Drag on the wheel to choose a hue, and on the square to choose the color shown by the swatch.
+
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, pick_colors)
+        .run();
+}
+
+#[derive(Component)]
+struct Swatch;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(40.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands.spawn((
+                hue_wheel(Val::Px(300.)),
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+            ));
+            commands.spawn((
+                saturation_value_box(0., Val::Px(300.)),
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+            ));
+            commands.spawn((
+                Node {
+                    width: Val::Px(100.),
+                    height: Val::Px(100.),
+                    ..Default::default()
+                },
+                BorderRadius::all(Val::Px(10.)),
+                BackgroundColor(Color::WHITE),
+                Swatch,
+            ));
+        });
+}
+
+fn pick_colors(
+    wheel_query: Query<(&Interaction, &RelativeCursorPosition), With<HueWheel>>,
+    mut square_query: Query<(
+        &Interaction,
+        &RelativeCursorPosition,
+        &ComputedNode,
+        &mut SaturationValueBox,
+    )>,
+    mut swatch_query: Query<&mut BackgroundColor, With<Swatch>>,
+) {
+    for (interaction, cursor) in &wheel_query {
+        if let (Interaction::Pressed, Some(position)) = (*interaction, cursor.normalized) {
+            for (.., mut square) in &mut square_query {
+                square.hue = HueWheel::hue_at(position - 0.5);
+            }
+        }
+    }
+
+    for (interaction, cursor, node, square) in &square_query {
+        if let (Interaction::Pressed, Some(position)) = (*interaction, cursor.normalized) {
+            let size = node.size();
+            let color = square.color_at((position - 0.5) * size, size);
+            for mut swatch in &mut swatch_query {
+                swatch.0 = color;
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Example of gradients drawn with a custom WGSL function: a spiral and a wave.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+const SPIRAL: u32 = 0;
+const WAVE: u32 = 1;
+
+const CUSTOM_GRADIENT: &str = r"
+const PI: f32 = 3.14159265358979323846;
+
+fn custom_gradient(id: u32, uv: vec2<f32>, t: f32) -> f32 {
+    let d = uv - 0.5;
+    switch id {
+        // Offsets the distance of a reflected radial gradient by one period per turn around the center
+        case 0u: {
+            return t + 80. * atan2(d.y, d.x) / PI;
+        }
+        // Offsets the distance of a linear gradient by a sine wave across the node
+        case 1u: {
+            return t + 30. * sin(6. * PI * uv.x);
+        }
+        default: {
+            return t;
+        }
+    }
+}
+";
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            UiGradientsPlugin,
+            CustomGradientFunctionPlugin::new(CUSTOM_GRADIENT),
+        ))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let stops = vec![
+        Color::srgb(0.1, 0.2, 0.8).into(),
+        Color::srgb(0.9, 0.9, 0.2).into(),
+        Color::srgb(0.9, 0.2, 0.3).into(),
+    ];
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(40.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(300.),
+                    ..Default::default()
+                },
+                BorderRadius::MAX,
+                BackgroundGradient::from(
+                    RadialGradient::new(
+                        Position::CENTER,
+                        RadialGradientShape::Circle(Val::Px(80.)),
+                        stops.clone(),
+                    )
+                    .with_spread(SpreadMethod::Reflect)
+                    .with_custom_function(SPIRAL),
+                ),
+            ));
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(300.),
+                    ..Default::default()
+                },
+                BackgroundGradient::from(
+                    LinearGradient::to_bottom(stops).with_custom_function(WAVE),
+                ),
+            ));
+        });
+}
@@ -0,0 +1,74 @@
+//! Example of `GradientDevToolsPlugin`.
+//! Press F2 to turn the tools on, then click a gradient to select it.
+//! Use `[` and `]` to select a layer, up and down to select a stop, left and right to move it,
+//! and `,` and `.` to turn the gradient. Hold shift for larger steps.
+
+use bevy::color::palettes::css::DEEP_PINK;
+use bevy::color::palettes::css::MIDNIGHT_BLUE;
+use bevy::color::palettes::css::ORANGE;
+use bevy::color::palettes::css::TURQUOISE;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin, GradientDevToolsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(40.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(300.),
+                    ..Default::default()
+                },
+                BackgroundGradient::from(LinearGradient::new(
+                    Angle::deg(45.),
+                    vec![
+                        ColorStop::percent(MIDNIGHT_BLUE, 0.),
+                        ColorStop::percent(DEEP_PINK, 50.),
+                        ColorStop::percent(ORANGE, 100.),
+                    ],
+                )),
+            ));
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(300.),
+                    ..Default::default()
+                },
+                BorderRadius::MAX,
+                BackgroundGradient(vec![
+                    ConicGradient::new(vec![
+                        AngularColorStop::percent(TURQUOISE, 0.),
+                        AngularColorStop::percent(DEEP_PINK, 50.),
+                        AngularColorStop::percent(TURQUOISE, 100.),
+                    ])
+                    .into(),
+                    RadialGradient::new(
+                        Position::CENTER,
+                        RadialGradientShape::ClosestSide,
+                        vec![
+                            ColorStop::percent(Color::WHITE, 0.),
+                            ColorStop::percent(Color::WHITE.with_alpha(0.), 60.),
+                        ],
+                    )
+                    .into(),
+                ]),
+            ));
+        });
+}
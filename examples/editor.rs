@@ -0,0 +1,55 @@
+//! Example of the `GradientEditor` widget editing a gradient live.
+//! Drag the handles to move the stops, press a swatch to recolor the selected stop,
+//! and drag on the dial to turn the gradient.
+//!
+//! Run with `cargo run --example editor --features editor`.
+
+use bevy::color::palettes::css::GOLD;
+use bevy::color::palettes::css::INDIGO;
+use bevy::color::palettes::css::TOMATO;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin, GradientEditorPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(40.),
+            ..Default::default()
+        })
+        .id();
+
+    let target = commands
+        .spawn((
+            Node {
+                width: Val::Px(400.),
+                height: Val::Px(400.),
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Px(20.)),
+            BackgroundGradient::from(LinearGradient::new(
+                Angle::deg(135.),
+                vec![
+                    ColorStop::auto(INDIGO),
+                    ColorStop::auto(TOMATO),
+                    ColorStop::auto(GOLD),
+                ],
+            )),
+            ChildOf(root),
+        ))
+        .id();
+
+    commands.spawn((gradient_editor(target, Val::Px(300.)), ChildOf(root)));
+}
@@ -0,0 +1,73 @@
+//! Example demonstrating `GradientSampler`.
+//! The swatch in the corner shows the gradient color under the cursor.
+
+use bevy::color::palettes::css::BLUE;
+use bevy::color::palettes::css::LIME;
+use bevy::color::palettes::css::RED;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_swatch)
+        .run();
+}
+
+#[derive(Component)]
+struct Palette;
+
+#[derive(Component)]
+struct Swatch;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                ..Default::default()
+            },
+            BackgroundGradient(vec![
+                LinearGradient::to_right(vec![RED.into(), LIME.into(), BLUE.into()]).into(),
+                LinearGradient::to_bottom(vec![
+                    ColorStop::auto(Color::WHITE.with_alpha(0.)),
+                    ColorStop::auto(Color::BLACK),
+                ])
+                .into(),
+            ]),
+            Palette,
+        ))
+        .with_child((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.),
+                top: Val::Px(20.),
+                width: Val::Px(80.),
+                height: Val::Px(80.),
+                border: UiRect::all(Val::Px(4.)),
+                ..Default::default()
+            },
+            BorderColor(Color::WHITE),
+            BackgroundColor(Color::NONE),
+            Swatch,
+        ));
+}
+
+fn update_swatch(
+    window: Single<&Window, With<PrimaryWindow>>,
+    palette: Single<Entity, With<Palette>>,
+    mut swatch: Single<&mut BackgroundColor, With<Swatch>>,
+    sampler: GradientSampler,
+) {
+    if let Some(color) = window
+        .cursor_position()
+        .and_then(|position| sampler.sample_viewport(*palette, position))
+    {
+        swatch.0 = color;
+    }
+}
@@ -0,0 +1,67 @@
+//! Example demonstrating gradients with fixed attachment.
+//! The panels show parts of one large gradient resolved against the window, which stays still as they move.
+
+use bevy::color::palettes::css::DEEP_SKY_BLUE;
+use bevy::color::palettes::css::HOT_PINK;
+use bevy::color::palettes::css::MIDNIGHT_BLUE;
+use bevy::color::palettes::css::ORANGE;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_panels)
+        .run();
+}
+
+#[derive(Component)]
+struct Panel(f32);
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let gradient = Gradient::from(ConicGradient::new(vec![
+        AngularColorStop::auto(HOT_PINK),
+        AngularColorStop::auto(ORANGE),
+        AngularColorStop::auto(DEEP_SKY_BLUE),
+        AngularColorStop::auto(HOT_PINK),
+    ]))
+    .with_attachment(GradientAttachment::Fixed);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(30.),
+                ..Default::default()
+            },
+            BackgroundColor(MIDNIGHT_BLUE.into()),
+        ))
+        .with_children(|commands| {
+            for i in 0..4 {
+                commands.spawn((
+                    Node {
+                        width: Val::Px(150.),
+                        height: Val::Px(250.),
+                        border: UiRect::all(Val::Px(4.)),
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Px(20.)),
+                    BorderColor(Color::WHITE.with_alpha(0.5)),
+                    BackgroundGradient::from(gradient.clone()),
+                    Panel(i as f32),
+                ));
+            }
+        });
+}
+
+fn move_panels(time: Res<Time>, mut panels: Query<(&Panel, &mut Node)>) {
+    for (Panel(phase), mut node) in &mut panels {
+        node.top = Val::Px(100. * ops::sin(time.elapsed_secs() + phase));
+    }
+}
@@ -0,0 +1,68 @@
+//! Example of random gradients generated from seeds. Press space to generate a new set.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+const COUNT: u64 = 24;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, reseed)
+        .run();
+}
+
+#[derive(Component)]
+struct Seeded(u64);
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_wrap: FlexWrap::Wrap,
+            align_content: AlignContent::Center,
+            justify_content: JustifyContent::Center,
+            column_gap: Val::Px(12.),
+            row_gap: Val::Px(12.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for seed in 0..COUNT {
+                commands.spawn((
+                    Node {
+                        width: Val::Px(140.),
+                        height: Val::Px(140.),
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Px(12.)),
+                    Seeded(seed),
+                    BackgroundGradient::default(),
+                ));
+            }
+        });
+}
+
+fn reseed(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut offset: Local<Option<u64>>,
+    mut query: Query<(&Seeded, &mut BackgroundGradient)>,
+) {
+    if offset.is_some() && !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+    let next = offset.map_or(0, |offset| offset + COUNT);
+    *offset = Some(next);
+    let generator = GradientGenerator::default();
+    for (Seeded(seed), mut gradient) in query.iter_mut() {
+        let seed = next + seed;
+        *gradient = match seed % 3 {
+            0 => generator.linear_gradient(seed).into(),
+            1 => generator.radial_gradient(seed).into(),
+            _ => generator.conic_gradient(seed).into(),
+        };
+    }
+}
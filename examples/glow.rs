@@ -0,0 +1,74 @@
+//! Example of `GradientGlow`, a soft halo around nodes. Hover over the buttons to highlight them.
+
+use bevy::color::palettes::css::{DEEP_SKY_BLUE, GOLD, HOT_PINK};
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, highlight_hovered)
+        .run();
+}
+
+#[derive(Component)]
+struct Highlight(GradientGlow);
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(40.),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.1)),
+        ))
+        .with_children(|commands| {
+            for (label, glow) in [
+                (
+                    "Linear",
+                    GradientGlow::new(DEEP_SKY_BLUE, 24., GlowFalloff::Linear),
+                ),
+                ("Smooth", GradientGlow::new(GOLD, 24., GlowFalloff::Smooth)),
+                (
+                    "Exponential",
+                    GradientGlow::new(HOT_PINK, 40., GlowFalloff::Exponential),
+                ),
+            ] {
+                commands.spawn((
+                    Node {
+                        width: Val::Px(240.),
+                        padding: UiRect::all(Val::Px(12.)),
+                        justify_content: JustifyContent::Center,
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Px(12.)),
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    Button,
+                    Highlight(glow),
+                    children![(Text::new(label), TextFont::from_font_size(24.))],
+                ));
+            }
+        });
+}
+
+fn highlight_hovered(
+    mut commands: Commands,
+    query: Query<(Entity, &Interaction, &Highlight), Changed<Interaction>>,
+) {
+    for (entity, interaction, highlight) in &query {
+        if *interaction == Interaction::None {
+            commands.entity(entity).remove::<GradientGlow>();
+        } else {
+            commands.entity(entity).insert(highlight.0.clone());
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Example of custom gradient shapes implemented with the `GradientKind` trait.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+use core::f32::consts::TAU;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            UiGradientsPlugin,
+            GradientKindPlugin::<DiamondGradient>::default(),
+            GradientKindPlugin::<SpiralGradient>::default(),
+        ))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// A gradient from the center of the node to its edges, with diamond shaped contours
+#[derive(Component)]
+struct DiamondGradient(Vec<ColorStop>);
+
+impl GradientKind for DiamondGradient {
+    fn parameterize(&self, point: Vec2) -> f32 {
+        let d = (point - 0.5).abs();
+        d.x + d.y
+    }
+
+    fn stops(&self) -> Vec<ColorStop> {
+        self.0.clone()
+    }
+}
+
+/// A repeating gradient that winds around the center of the node
+#[derive(Component)]
+struct SpiralGradient {
+    stops: Vec<ColorStop>,
+    turns: f32,
+}
+
+impl GradientKind for SpiralGradient {
+    fn parameterize(&self, point: Vec2) -> f32 {
+        let d = point - 0.5;
+        let angle = d.x.atan2(-d.y) / TAU;
+        self.turns * d.length() + angle
+    }
+
+    fn stops(&self) -> Vec<ColorStop> {
+        self.stops.clone()
+    }
+
+    fn spread(&self) -> SpreadMethod {
+        SpreadMethod::Repeat
+    }
+
+    fn resolution(&self) -> UVec2 {
+        UVec2::splat(256)
+    }
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(40.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(300.),
+                    ..Default::default()
+                },
+                DiamondGradient(vec![
+                    Color::WHITE.into(),
+                    Color::srgb(0.9, 0.6, 0.1).into(),
+                    Color::srgb(0.3, 0., 0.4).into(),
+                ]),
+            ));
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(300.),
+                    ..Default::default()
+                },
+                BorderRadius::MAX,
+                SpiralGradient {
+                    stops: vec![
+                        ColorStop::percent(Color::srgb(0.1, 0.3, 0.9), 0.),
+                        ColorStop::percent(Color::srgb(0.1, 0.9, 0.7), 50.),
+                        ColorStop::percent(Color::srgb(0.1, 0.3, 0.9), 100.),
+                    ],
+                    turns: 4.,
+                },
+            ));
+        });
+}
@@ -10,7 +10,6 @@ use bevy::color::palettes::css::VIOLET;
 use bevy::color::palettes::css::YELLOW;
 use bevy::prelude::*;
 use bevy_ui_gradients::*;
-use std::f32::consts::TAU;
 
 fn main() {
     App::new()
@@ -78,7 +77,7 @@ fn setup(mut commands: Commands) {
                                         ..Default::default()
                                     })
                                     .with_children(|commands| {
-                                        for angle in (0..8).map(|i| i as f32 * TAU / 8.) {
+                                        for angle in (0..8).map(|i| Angle::turns(i as f32 / 8.)) {
                                             commands.spawn((
                                                 Node {
                                                     width: Val::Px(w),
@@ -89,15 +88,18 @@ fn setup(mut commands: Commands) {
                                                 BorderRadius::all(Val::Px(20.)),
                                                 BackgroundGradient::from(LinearGradient {
                                                     angle,
-                                                    stops: stops.clone(),
+                                                    stops: stops.clone().into(),
+                                                    ..default()
                                                 }),
                                                 BorderGradient::from(LinearGradient {
-                                                    angle: 3. * TAU / 8.,
-                                                    stops: vec![
+                                                    angle: Angle::turns(3. / 8.),
+                                                    stops: [
                                                         YELLOW.into(),
                                                         Color::WHITE.into(),
                                                         ORANGE.into(),
-                                                    ],
+                                                    ]
+                                                    .into(),
+                                                    ..default()
                                                 }),
                                             ));
                                         }
@@ -116,12 +118,14 @@ fn setup(mut commands: Commands) {
                             },
                             BorderRadius::all(Val::Px(20.)),
                             BackgroundGradient::from(LinearGradient {
-                                angle: 0.,
-                                stops: stops.clone(),
+                                angle: Angle::ZERO,
+                                stops: stops.clone().into(),
+                                ..default()
                             }),
                             BorderGradient::from(LinearGradient {
-                                angle: 3. * TAU / 8.,
-                                stops: vec![YELLOW.into(), Color::WHITE.into(), ORANGE.into()],
+                                angle: Angle::turns(3. / 8.),
+                                stops: [YELLOW.into(), Color::WHITE.into(), ORANGE.into()].into(),
+                                ..default()
                             }),
                             AnimateMarker,
                         ));
@@ -136,13 +140,15 @@ fn setup(mut commands: Commands) {
                             },
                             BorderRadius::all(Val::Px(20.)),
                             BackgroundGradient::from(RadialGradient {
-                                stops: stops.clone(),
+                                stops: stops.clone().into(),
                                 shape: RadialGradientShape::ClosestSide,
                                 position: Position::CENTER,
+                                ..default()
                             }),
                             BorderGradient::from(LinearGradient {
-                                angle: 3. * TAU / 8.,
-                                stops: vec![YELLOW.into(), Color::WHITE.into(), ORANGE.into()],
+                                angle: Angle::turns(3. / 8.),
+                                stops: [YELLOW.into(), Color::WHITE.into(), ORANGE.into()].into(),
+                                ..default()
                             }),
                             AnimateMarker,
                         ));
@@ -156,16 +162,18 @@ fn setup(mut commands: Commands) {
                             },
                             BorderRadius::all(Val::Px(20.)),
                             BackgroundGradient::from(ConicGradient {
-                                start: (0.25001) * TAU * i as f32,
+                                start: Angle::turns(0.25001 * i as f32),
                                 stops: stops
                                     .iter()
                                     .map(|stop| AngularColorStop::auto(stop.color))
                                     .collect(),
                                 position: Position::CENTER,
+                                ..default()
                             }),
                             BorderGradient::from(LinearGradient {
-                                angle: 3. * TAU / 8.,
-                                stops: vec![YELLOW.into(), Color::WHITE.into(), ORANGE.into()],
+                                angle: Angle::turns(3. / 8.),
+                                stops: [YELLOW.into(), Color::WHITE.into(), ORANGE.into()].into(),
+                                ..default()
                             }),
                             AnimateMarker,
                         ));
@@ -182,7 +190,7 @@ fn update(time: Res<Time>, mut query: Query<&mut BackgroundGradient, With<Animat
     for mut gradients in query.iter_mut() {
         for gradient in gradients.0.iter_mut() {
             if let Gradient::Linear(LinearGradient { angle, .. }) = gradient {
-                *angle += 0.5 * time.delta_secs();
+                *angle += Angle::rad(0.5 * time.delta_secs());
             }
         }
     }
@@ -0,0 +1,68 @@
+//! Example demonstrating film grain on gradients.
+//!
+//! The left panel is a dark vignette without grain, which shows banding on most displays.
+//! The next panel uses subtle static noise to hide the banding, then an animated film grain,
+//! and a stylized 4x4 Bayer dither with 8 levels for each channel.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn vignette() -> RadialGradient {
+    RadialGradient::new(
+        Position::CENTER,
+        RadialGradientShape::FarthestCorner,
+        vec![
+            Color::srgb(0.12, 0.1, 0.16).into(),
+            Color::srgb(0.02, 0.02, 0.03).into(),
+        ],
+    )
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for (label, gradient) in [
+                ("No grain", vignette()),
+                ("Dither", vignette().with_grain(GradientGrain::dither())),
+                (
+                    "Film grain",
+                    vignette().with_grain(GradientGrain::new(0.08, 2.)),
+                ),
+                (
+                    "Bayer",
+                    vignette()
+                        .with_grain(GradientGrain::bayer(GrainPattern::Bayer4, 8).with_scale(2.)),
+                ),
+            ] {
+                commands.spawn((
+                    Node {
+                        width: Val::Px(280.),
+                        height: Val::Px(500.),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Px(20.)),
+                    BackgroundGradient::from(gradient),
+                    children![Text::new(label)],
+                ));
+            }
+        });
+}
@@ -0,0 +1,88 @@
+//! Example demonstrating a `GradientGroup`, where the segments of a progress bar and the halves
+//! of a split header show one continuous gradient.
+
+use bevy::color::palettes::css::GOLD;
+use bevy::color::palettes::css::LIME;
+use bevy::color::palettes::css::MEDIUM_PURPLE;
+use bevy::color::palettes::css::RED;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let progress = BackgroundGradient::from(LinearGradient::to_right(vec![
+        RED.into(),
+        GOLD.into(),
+        LIME.into(),
+    ]));
+    let header = BackgroundGradient::from(LinearGradient::new(
+        0.3,
+        vec![MEDIUM_PURPLE.into(), GOLD.into()],
+    ));
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(50.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands
+                .spawn((
+                    Node {
+                        column_gap: Val::Px(6.),
+                        ..Default::default()
+                    },
+                    GradientGroup,
+                ))
+                .with_children(|commands| {
+                    for _ in 0..10 {
+                        commands.spawn((
+                            Node {
+                                width: Val::Px(40.),
+                                height: Val::Px(30.),
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Px(6.)),
+                            progress.clone(),
+                        ));
+                    }
+                });
+
+            commands
+                .spawn((
+                    Node {
+                        column_gap: Val::Px(20.),
+                        ..Default::default()
+                    },
+                    GradientGroup,
+                ))
+                .with_children(|commands| {
+                    for text in ["Split", "Header"] {
+                        commands.spawn((
+                            Node {
+                                width: Val::Px(200.),
+                                height: Val::Px(80.),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            header.clone(),
+                            children![Text::new(text)],
+                        ));
+                    }
+                });
+        });
+}
@@ -0,0 +1,75 @@
+//! Example of inset gradients, which follow the node's rounded border inward.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(40.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            // An inner glow
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(200.),
+                    ..Default::default()
+                },
+                BorderRadius::all(Val::Px(40.)),
+                BackgroundColor(Color::srgb(0.05, 0.05, 0.15)),
+                BackgroundGradient::from(InsetGradient::new(vec![
+                    ColorStop::new(Color::srgb(0.3, 0.8, 1.), Val::Px(0.)),
+                    ColorStop::new(Color::srgba(0.3, 0.8, 1., 0.), Val::Px(30.)),
+                ])),
+            ));
+
+            // An inset shadow inside a border
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(200.),
+                    border: UiRect::all(Val::Px(8.)),
+                    ..Default::default()
+                },
+                BorderRadius::all(Val::Px(24.)),
+                BorderColor(Color::srgb(0.6, 0.6, 0.65)),
+                BackgroundColor(Color::srgb(0.85, 0.85, 0.9)),
+                BackgroundGradient::from(InsetGradient::new(vec![
+                    ColorStop::new(Color::srgba(0., 0., 0., 0.6), Val::Px(0.)),
+                    ColorStop::new(Color::NONE, Val::Px(16.)),
+                ])),
+            ));
+
+            // Repeated bands following the corners
+            commands.spawn((
+                Node {
+                    width: Val::Px(200.),
+                    height: Val::Px(200.),
+                    ..Default::default()
+                },
+                BorderRadius::all(Val::Px(60.)),
+                BackgroundGradient::from(
+                    InsetGradient::new(vec![
+                        ColorStop::new(Color::srgb(0.9, 0.3, 0.2), Val::Px(0.)),
+                        ColorStop::new(Color::srgb(0.9, 0.8, 0.2), Val::Px(20.)),
+                    ])
+                    .with_spread(SpreadMethod::Reflect),
+                ),
+            ));
+        });
+}
@@ -0,0 +1,87 @@
+//! Example demonstrating `InteractionGradients`, which cross-fades a button's gradients on hover and press,
+//! and `GradientButton`, which derives them from a single gradient.
+
+use bevy::color::palettes::css::DARK_BLUE;
+use bevy::color::palettes::css::DEEP_SKY_BLUE;
+use bevy::color::palettes::css::GOLD;
+use bevy::color::palettes::css::MEDIUM_PURPLE;
+use bevy::color::palettes::css::ORANGE_RED;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            // Gradients with matching stops are interpolated
+            commands.spawn((
+                button(),
+                InteractionGradients::new(
+                    LinearGradient::to_right(vec![DARK_BLUE.into(), MEDIUM_PURPLE.into()]),
+                    LinearGradient::to_right(vec![DEEP_SKY_BLUE.into(), MEDIUM_PURPLE.into()]),
+                    LinearGradient::to_left(vec![DEEP_SKY_BLUE.into(), GOLD.into()]),
+                ),
+                children![Text::new("Interpolated")],
+            ));
+
+            // Gradients of different kinds are faded in over each other
+            commands.spawn((
+                button(),
+                InteractionGradients::new(
+                    LinearGradient::to_bottom(vec![DARK_BLUE.into(), MEDIUM_PURPLE.into()]),
+                    RadialGradient::new(
+                        Position::CENTER,
+                        RadialGradientShape::FarthestCorner,
+                        vec![ORANGE_RED.into(), MEDIUM_PURPLE.into()],
+                    ),
+                    ConicGradient::new(vec![
+                        AngularColorStop::auto(GOLD),
+                        AngularColorStop::auto(ORANGE_RED),
+                        AngularColorStop::auto(GOLD),
+                    ]),
+                )
+                .with_duration(0.4),
+                children![Text::new("Cross-faded")],
+            ));
+
+            // `gradient_button` derives the hovered and pressed gradients and adds a border
+            commands.spawn((
+                gradient_button(
+                    LinearGradient::to_right(vec![ORANGE_RED.into(), GOLD.into()]),
+                    Val::Px(250.),
+                    Val::Px(80.),
+                ),
+                children![Text::new("Gradient button")],
+            ));
+        });
+}
+
+fn button() -> impl Bundle {
+    (
+        Button,
+        Node {
+            width: Val::Px(250.),
+            height: Val::Px(80.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        },
+        BorderRadius::all(Val::Px(20.)),
+    )
+}
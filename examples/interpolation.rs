@@ -0,0 +1,64 @@
+//! Example comparing the interpolation functions between color stops.
+
+use bevy::color::palettes::css::{GOLD, INDIGO};
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(16.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for (label, interpolation) in [
+                ("Linear", Interpolation::Linear),
+                ("SmoothStep", Interpolation::SmoothStep),
+                (
+                    "CubicBezier (ease-in-out)",
+                    Interpolation::CubicBezier(Vec2::new(0.42, 0.), Vec2::new(0.58, 1.)),
+                ),
+                (
+                    "CubicBezier (ease-in)",
+                    Interpolation::CubicBezier(Vec2::new(0.42, 0.), Vec2::new(1., 1.)),
+                ),
+                ("Step", Interpolation::Step),
+            ] {
+                commands
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.),
+                        ..Default::default()
+                    })
+                    .with_children(|commands| {
+                        commands.spawn((Text::new(label), TextFont::from_font_size(16.)));
+                        commands.spawn((
+                            Node {
+                                width: Val::Px(600.),
+                                height: Val::Px(60.),
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Px(8.)),
+                            BackgroundGradient::from(LinearGradient::to_right(vec![
+                                ColorStop::auto(INDIGO).with_interpolation(interpolation),
+                                ColorStop::auto(GOLD),
+                            ])),
+                        ));
+                    });
+            }
+        });
+}
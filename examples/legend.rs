@@ -0,0 +1,62 @@
+//! Example showing `GradientLegend` color scales for each `Colormap`, and a vertical legend for a conic gradient.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(80.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(30.),
+                    ..Default::default()
+                })
+                .with_children(|commands| {
+                    for colormap in Colormap::ALL {
+                        commands.spawn((
+                            Node {
+                                width: Val::Px(400.),
+                                height: Val::Px(50.),
+                                ..Default::default()
+                            },
+                            GradientLegend::new(
+                                colormap.linear_gradient(LinearGradient::TO_RIGHT, 16),
+                            )
+                            .with_even_ticks(5)
+                            .with_labels("0", format!("{colormap:?}")),
+                        ));
+                    }
+                });
+
+            commands.spawn((
+                Node {
+                    width: Val::Px(80.),
+                    height: Val::Px(400.),
+                    ..Default::default()
+                },
+                GradientLegend::new(Colormap::Turbo.palette(8).conic_gradient())
+                    .with_orientation(LegendOrientation::Vertical)
+                    .with_thickness(Val::Px(24.))
+                    .with_even_ticks(9)
+                    .with_labels("-40°", "40°"),
+            ));
+        });
+}
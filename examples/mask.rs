@@ -0,0 +1,68 @@
+//! Example demonstrating `GradientMask`, which fades out a node's background color using a gradient's alpha.
+
+use bevy::color::palettes::css::BLUE;
+use bevy::color::palettes::css::RED;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            // A list with its bottom edge faded out
+            commands
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(5.),
+                        padding: UiRect::all(Val::Px(10.)),
+                        ..Default::default()
+                    },
+                    BackgroundColor(BLUE.into()),
+                    GradientMask::from(LinearGradient::to_bottom(vec![
+                        ColorStop::new(Color::WHITE, Val::Percent(60.)),
+                        ColorStop::new(Color::NONE, Val::Percent(100.)),
+                    ])),
+                ))
+                .with_children(|commands| {
+                    for i in 0..10 {
+                        commands.spawn(Text::new(format!("Item {i}")));
+                    }
+                });
+
+            // A vignette
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(300.),
+                    ..Default::default()
+                },
+                BorderRadius::all(Val::Px(30.)),
+                BackgroundColor(RED.into()),
+                GradientMask::from(RadialGradient::new(
+                    Position::CENTER,
+                    RadialGradientShape::ClosestSide,
+                    vec![
+                        ColorStop::new(Color::WHITE, Val::Percent(50.)),
+                        ColorStop::auto(Color::NONE),
+                    ],
+                )),
+            ));
+        });
+}
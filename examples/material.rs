@@ -0,0 +1,56 @@
+//! Example demonstrating gradients drawn with `GradientMaterial` through the `MaterialNode` pipeline.
+
+use bevy::color::palettes::css::BLUE;
+use bevy::color::palettes::css::RED;
+use bevy::color::palettes::css::YELLOW;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut materials: ResMut<Assets<GradientMaterial>>) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for gradient in [
+                Gradient::from(LinearGradient::to_right(vec![RED.into(), BLUE.into()])),
+                RadialGradient::new(
+                    Position::CENTER,
+                    RadialGradientShape::FarthestCorner,
+                    vec![YELLOW.into(), RED.into(), BLUE.into()],
+                )
+                .into(),
+                ConicGradient::new(vec![
+                    AngularColorStop::auto(RED),
+                    AngularColorStop::auto(YELLOW),
+                    AngularColorStop::auto(BLUE),
+                    AngularColorStop::auto(RED),
+                ])
+                .into(),
+            ] {
+                commands.spawn((
+                    Node {
+                        width: Val::Px(200.),
+                        height: Val::Px(200.),
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Px(20.)),
+                    MaterialNode(materials.add(GradientMaterial::new(gradient))),
+                ));
+            }
+        });
+}
@@ -0,0 +1,76 @@
+//! Example demonstrating `GradientPickingPolicy`.
+//! Only the opaque areas of the radial gradients respond to the pointer.
+
+use bevy::color::palettes::css::RED;
+use bevy::color::palettes::css::YELLOW;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for policy in [
+                GradientPickingPolicy::Rect,
+                GradientPickingPolicy::AlphaThreshold(0.),
+                GradientPickingPolicy::AlphaThreshold(0.5),
+            ] {
+                commands
+                    .spawn((
+                        Node {
+                            width: Val::Px(200.),
+                            height: Val::Px(200.),
+                            ..Default::default()
+                        },
+                        BackgroundGradient::from(RadialGradient::new(
+                            Position::CENTER,
+                            RadialGradientShape::ClosestSide,
+                            vec![
+                                ColorStop::auto(YELLOW),
+                                ColorStop::auto(YELLOW.with_alpha(0.)),
+                            ],
+                        )),
+                        policy,
+                    ))
+                    .observe(
+                        |trigger: Trigger<Pointer<Over>>,
+                         mut query: Query<&mut BackgroundGradient>| {
+                            if let Ok(mut gradient) = query.get_mut(trigger.target()) {
+                                set_center_color(&mut gradient, RED.into());
+                            }
+                        },
+                    )
+                    .observe(
+                        |trigger: Trigger<Pointer<Out>>,
+                         mut query: Query<&mut BackgroundGradient>| {
+                            if let Ok(mut gradient) = query.get_mut(trigger.target()) {
+                                set_center_color(&mut gradient, YELLOW.into());
+                            }
+                        },
+                    );
+            }
+        });
+}
+
+fn set_center_color(gradient: &mut BackgroundGradient, color: Color) {
+    if let Some(Gradient::Radial(radial)) = gradient.0.first_mut() {
+        radial.stops[0].color = color;
+        radial.stops[1].color = color.with_alpha(0.);
+    }
+}
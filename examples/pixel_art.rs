@@ -0,0 +1,73 @@
+//! Example demonstrating gradients quantized to a retro palette with `GradientPalette`.
+//!
+//! The left panel is the original gradient, the middle panel is quantized to the palette
+//! and the right panel is quantized with ordered dithering.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn sunset() -> LinearGradient {
+    LinearGradient::to_bottom(vec![
+        Color::srgb(0.1, 0.05, 0.3).into(),
+        Color::srgb(0.8, 0.2, 0.4).into(),
+        Color::srgb(1., 0.8, 0.3).into(),
+    ])
+}
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.spawn(Camera2d);
+
+    let palette = Palette::from_hex_list(
+        "
+        1a1c2c
+        5d275d
+        b13e53
+        ef7d57
+        ffcd75
+        a7f070
+        38b764
+        257179
+        ",
+    )
+    .unwrap();
+    let palette = images.add(palette.to_image());
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for (label, dither) in [
+                ("Original", None),
+                ("Palette", Some(false)),
+                ("Dithered", Some(true)),
+            ] {
+                let mut panel = commands.spawn((
+                    Node {
+                        width: Val::Px(300.),
+                        height: Val::Px(500.),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundGradient::from(sunset()),
+                    children![Text::new(label)],
+                ));
+                if let Some(dither) = dither {
+                    panel.insert(GradientPalette::new(palette.clone()).with_dither(dither));
+                }
+            }
+        });
+}
@@ -0,0 +1,64 @@
+//! Example of animated plasma gradients, for retro menu backgrounds.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(20.),
+                ..Default::default()
+            },
+            // A slow plasma through the plasma colormap, with a fast, fine plasma faintly layered over it
+            BackgroundGradient(vec![
+                PlasmaGradient::new(Colormap::Plasma.palette(8).color_stops())
+                    .with_scale(400.)
+                    .with_speed(0.5)
+                    .with_spread(SpreadMethod::Reflect)
+                    .into(),
+                PlasmaGradient::new(vec![
+                    Color::NONE.into(),
+                    Color::srgba(1., 1., 1., 0.15).into(),
+                    Color::NONE.into(),
+                ])
+                .with_scale(60.)
+                .with_speed(2.)
+                .into(),
+            ]),
+        ))
+        .with_children(|commands| {
+            for label in ["NEW GAME", "CONTINUE", "OPTIONS", "QUIT"] {
+                commands
+                    .spawn((
+                        Node {
+                            width: Val::Px(260.),
+                            padding: UiRect::all(Val::Px(12.)),
+                            justify_content: JustifyContent::Center,
+                            ..Default::default()
+                        },
+                        BorderRadius::all(Val::Px(8.)),
+                        BackgroundColor(Color::srgba(0., 0., 0., 0.6)),
+                    ))
+                    .with_child((
+                        Text::new(label),
+                        TextFont::from_font_size(28.),
+                        TextColor(Color::WHITE),
+                    ));
+            }
+        });
+}
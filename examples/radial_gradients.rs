@@ -85,9 +85,10 @@ fn setup_grid(mut commands: Commands) {
                                         ..default()
                                     },
                                     BackgroundGradient::from(RadialGradient {
-                                        stops: color_stops.clone(),
+                                        stops: color_stops.clone().into(),
                                         position,
                                         shape,
+                                        ..default()
                                     }),
                                 ));
                             });
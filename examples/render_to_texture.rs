@@ -0,0 +1,81 @@
+//! Example demonstrating gradients on UI rendered to an image by an offscreen camera.
+//! Viewport units and the scale factor are resolved against the image, not the window.
+
+use bevy::color::palettes::css::BLUE;
+use bevy::color::palettes::css::RED;
+use bevy::color::palettes::css::YELLOW;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, rotate)
+        .run();
+}
+
+#[derive(Component)]
+struct Screen;
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let size = Extent3d {
+        width: 512,
+        height: 256,
+        ..default()
+    };
+
+    // This is the texture that the UI will be rendered to.
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    let image_handle = images.add(image);
+
+    let texture_camera = commands
+        .spawn((
+            Camera2d,
+            Camera {
+                order: -1,
+                target: RenderTarget::Image(image_handle.clone().into()),
+                ..default()
+            },
+        ))
+        .id();
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            ..default()
+        },
+        BackgroundGradient(vec![
+            LinearGradient::to_right(vec![RED.into(), BLUE.into()]).into(),
+            RadialGradient::new(
+                Position::CENTER,
+                // `Vh` is resolved against the height of the image
+                RadialGradientShape::Circle(Val::Vh(50.)),
+                vec![YELLOW.into(), YELLOW.with_alpha(0.).into()],
+            )
+            .into(),
+        ]),
+        UiTargetCamera(texture_camera),
+    ));
+
+    commands.spawn(Camera2d);
+    commands.spawn((Sprite::from_image(image_handle), Screen));
+}
+
+fn rotate(time: Res<Time>, mut query: Query<&mut Transform, With<Screen>>) {
+    for mut transform in query.iter_mut() {
+        transform.rotation = Quat::from_rotation_z(0.25 * time.elapsed_secs().sin());
+    }
+}
@@ -0,0 +1,57 @@
+//! Example of `SkyGradient`, a backdrop blended between dawn, noon, dusk and night from the time of day.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+/// In-game hours that pass each second
+const HOURS_PER_SECOND: f32 = 2.;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, advance_time)
+        .run();
+}
+
+#[derive(Component)]
+struct Clock;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands.spawn((
+        gradient_backdrop(BackgroundGradient::default()),
+        SkyGradient::default().with_hour(5.),
+    ));
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_child((
+            Text::default(),
+            TextFont::from_font_size(48.),
+            TextColor(Color::WHITE),
+            Clock,
+        ));
+}
+
+fn advance_time(
+    time: Res<Time>,
+    mut skies: Query<&mut SkyGradient>,
+    mut clocks: Query<&mut Text, With<Clock>>,
+) {
+    for mut sky in skies.iter_mut() {
+        sky.hour = (sky.hour + HOURS_PER_SECOND * time.delta_secs())
+            .rem_euclid(SkyGradient::HOURS_PER_DAY);
+        for mut text in clocks.iter_mut() {
+            let minutes = (sky.hour * 60.) as u32;
+            text.0 = format!("{:02}:{:02}", minutes / 60, minutes % 60);
+        }
+    }
+}
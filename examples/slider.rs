@@ -0,0 +1,77 @@
+//! Example of gradient sliders for picking a color temperature and an intensity.
+//! The swatch shows the colors under the handles multiplied together.
+
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_swatch)
+        .run();
+}
+
+#[derive(Component)]
+struct Swatch;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(30.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands.spawn((
+                gradient_slider(
+                    LinearGradient::to_right(vec![
+                        Color::srgb(1., 0.55, 0.1).into(),
+                        Color::WHITE.into(),
+                        Color::srgb(0.6, 0.75, 1.).into(),
+                    ]),
+                    0.5,
+                    Val::Px(400.),
+                    Val::Px(30.),
+                ),
+                BorderRadius::all(Val::Px(15.)),
+            ));
+            commands.spawn((
+                gradient_slider(
+                    LinearGradient::to_right(vec![Color::BLACK.into(), Color::WHITE.into()]),
+                    1.,
+                    Val::Px(400.),
+                    Val::Px(30.),
+                ),
+                BorderRadius::all(Val::Px(15.)),
+            ));
+            commands.spawn((
+                Node {
+                    width: Val::Px(100.),
+                    height: Val::Px(100.),
+                    ..Default::default()
+                },
+                BorderRadius::all(Val::Px(10.)),
+                BackgroundColor(Color::WHITE),
+                Swatch,
+            ));
+        });
+}
+
+fn update_swatch(
+    sliders: Query<&GradientSlider>,
+    mut swatch_query: Query<&mut BackgroundColor, With<Swatch>>,
+) {
+    let color = sliders.iter().fold(Vec3::ONE, |color, slider| {
+        color * LinearRgba::from(slider.color).to_vec3()
+    });
+    for mut swatch in &mut swatch_query {
+        swatch.0 = LinearRgba::from_vec3(color).into();
+    }
+}
@@ -0,0 +1,53 @@
+//! Example comparing a gradient's stops interpolated linearly and smoothed with splines.
+//! The linear gradient has visible bands at its stops.
+
+use bevy::color::palettes::css::{DARK_SLATE_BLUE, MIDNIGHT_BLUE, ORANGE_RED, PEACHPUFF};
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(20.)),
+            row_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for (label, smoothing) in [
+                ("None", GradientSmoothing::None),
+                ("CatmullRom", GradientSmoothing::CatmullRom),
+                ("BSpline", GradientSmoothing::BSpline),
+            ] {
+                commands
+                    .spawn((
+                        Node {
+                            flex_grow: 1.,
+                            padding: UiRect::all(Val::Px(8.)),
+                            ..Default::default()
+                        },
+                        BackgroundGradient::from(
+                            LinearGradient::to_right(vec![
+                                ColorStop::auto(MIDNIGHT_BLUE),
+                                ColorStop::auto(DARK_SLATE_BLUE),
+                                ColorStop::auto(ORANGE_RED),
+                                ColorStop::auto(PEACHPUFF),
+                            ])
+                            .with_smoothing(smoothing),
+                        ),
+                    ))
+                    .with_child((Text::new(label), TextFont::from_font_size(20.)));
+            }
+        });
+}
@@ -0,0 +1,82 @@
+//! Example demonstrating the pad, repeat and reflect spread methods.
+
+use bevy::color::palettes::css::DEEP_SKY_BLUE;
+use bevy::color::palettes::css::GOLD;
+use bevy::color::palettes::css::MEDIUM_PURPLE;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let stops = vec![
+        ColorStop::new(GOLD, Val::Px(40.)),
+        ColorStop::new(MEDIUM_PURPLE, Val::Px(70.)),
+        ColorStop::new(DEEP_SKY_BLUE, Val::Px(100.)),
+    ];
+    let angular_stops = vec![
+        AngularColorStop::new(GOLD, 0.),
+        AngularColorStop::new(MEDIUM_PURPLE, 0.25),
+        AngularColorStop::new(DEEP_SKY_BLUE, 0.5),
+    ];
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for spread in [
+                SpreadMethod::Pad,
+                SpreadMethod::Repeat,
+                SpreadMethod::Reflect,
+            ] {
+                commands
+                    .spawn(Node {
+                        column_gap: Val::Px(20.),
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    })
+                    .with_children(|commands| {
+                        commands.spawn((
+                            Node {
+                                width: Val::Px(100.),
+                                ..Default::default()
+                            },
+                            Text::new(format!("{spread:?}")),
+                        ));
+                        for gradient in [
+                            Gradient::from(LinearGradient::to_right(stops.clone())),
+                            RadialGradient::new(
+                                Position::CENTER,
+                                RadialGradientShape::FarthestCorner,
+                                stops.clone(),
+                            )
+                            .into(),
+                            ConicGradient::new(angular_stops.clone()).into(),
+                        ] {
+                            commands.spawn((
+                                Node {
+                                    width: Val::Px(200.),
+                                    height: Val::Px(200.),
+                                    ..Default::default()
+                                },
+                                BackgroundGradient::from(gradient.with_spread(spread)),
+                            ));
+                        }
+                    });
+            }
+        });
+}
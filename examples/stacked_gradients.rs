@@ -5,7 +5,6 @@ use bevy::color::palettes::css::RED;
 use bevy::color::palettes::css::YELLOW;
 use bevy::prelude::*;
 use bevy_ui_gradients::*;
-use core::f32::consts::TAU;
 
 fn main() {
     App::new()
@@ -44,42 +43,50 @@ fn setup(mut commands: Commands) {
                     ])
                     .into(),
                     ConicGradient {
-                        start: 0.,
+                        start: Angle::ZERO,
                         position: Position::CENTER,
-                        stops: vec![
+                        stops: [
                             AngularColorStop::auto(YELLOW.with_alpha(0.)),
                             AngularColorStop::auto(YELLOW.with_alpha(0.)),
                             AngularColorStop::auto(YELLOW),
                             AngularColorStop::auto(YELLOW.with_alpha(0.)),
                             AngularColorStop::auto(YELLOW.with_alpha(0.)),
-                        ],
+                        ]
+                        .into(),
+                        ..default()
                     }
                     .into(),
                     RadialGradient {
                         position: Position::TOP.at_x(Val::Percent(5.)),
                         shape: RadialGradientShape::Circle(Val::Vh(30.)),
-                        stops: vec![
+                        stops: [
                             ColorStop::auto(Color::WHITE),
                             ColorStop::auto(YELLOW),
                             ColorStop::auto(YELLOW.with_alpha(0.1)),
                             ColorStop::auto(YELLOW.with_alpha(0.)),
-                        ],
+                        ]
+                        .into(),
+                        ..default()
                     }
                     .into(),
                     LinearGradient {
-                        angle: TAU / 16.,
-                        stops: vec![
+                        angle: Angle::turns(1. / 16.),
+                        stops: [
                             ColorStop::auto(Color::BLACK),
                             ColorStop::auto(Color::BLACK.with_alpha(0.)),
-                        ],
+                        ]
+                        .into(),
+                        ..default()
                     }
                     .into(),
                     LinearGradient {
-                        angle: 15. * TAU / 16.,
-                        stops: vec![
+                        angle: Angle::turns(15. / 16.),
+                        stops: [
                             ColorStop::auto(Color::BLACK),
                             ColorStop::auto(Color::BLACK.with_alpha(0.)),
-                        ],
+                        ]
+                        .into(),
+                        ..default()
                     }
                     .into(),
                 ]),
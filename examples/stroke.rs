@@ -0,0 +1,79 @@
+//! Example demonstrating `GradientStroke`, a gradient-filled stroke along a path.
+
+use bevy::color::palettes::css::DEEP_SKY_BLUE;
+use bevy::color::palettes::css::GOLD;
+use bevy::color::palettes::css::LIME;
+use bevy::color::palettes::css::MEDIUM_PURPLE;
+use bevy::color::palettes::css::ORANGE_RED;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+use std::f32::consts::TAU;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_progress)
+        .run();
+}
+
+#[derive(Component)]
+struct Progress;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(50.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            // A circular progress bar
+            commands.spawn((
+                Node {
+                    width: Val::Px(250.),
+                    height: Val::Px(250.),
+                    ..Default::default()
+                },
+                GradientStroke::arc(
+                    Vec2::splat(125.),
+                    100.,
+                    0.,
+                    0.75 * TAU,
+                    24.,
+                    vec![LIME.into(), GOLD.into(), ORANGE_RED.into()],
+                ),
+                Progress,
+            ));
+
+            // A connection line between two ports in a node graph
+            commands.spawn((
+                Node {
+                    width: Val::Px(300.),
+                    height: Val::Px(250.),
+                    ..Default::default()
+                },
+                GradientStroke::new(
+                    Vec2::new(20., 30.),
+                    6.,
+                    vec![DEEP_SKY_BLUE.into(), MEDIUM_PURPLE.into()],
+                )
+                .line_to(Vec2::new(100., 30.))
+                .arc_to(Vec2::new(100., 80.), 0.5 * TAU)
+                .line_to(Vec2::new(60., 130.))
+                .arc_to(Vec2::new(60., 180.), -0.5 * TAU)
+                .line_to(Vec2::new(280., 230.)),
+            ));
+        });
+}
+
+fn update_progress(time: Res<Time>, mut query: Query<&mut GradientStroke, With<Progress>>) {
+    for mut stroke in &mut query {
+        stroke.progress = 0.5 - 0.5 * ops::cos(time.elapsed_secs());
+    }
+}
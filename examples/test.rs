@@ -25,13 +25,15 @@ fn setup(mut commands: Commands) {
                 },
                 BorderRadius::all(Val::Px(20.)),
                 BackgroundGradient::from(ConicGradient {
-                    start: 0.,
-                    stops: vec![
+                    start: Angle::ZERO,
+                    stops: [
                         AngularColorStop::new(RED, 0.),
                         AngularColorStop::new(RED, 0.),
                         AngularColorStop::new(Color::NONE, 0.),
-                    ],
+                    ]
+                    .into(),
                     position: Position::CENTER,
+                    ..default()
                 }),
                 BorderColor(WHITE.into()),
             ));
@@ -0,0 +1,120 @@
+//! Example demonstrating `GradientTheme`. Press space to switch themes, and D to toggle between light and dark variants.
+//! Every node with a `ThemedGradient` is restyled when the theme resource changes.
+
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .insert_resource(ocean())
+        .add_systems(Startup, setup)
+        .add_systems(Update, switch_theme)
+        .run();
+}
+
+fn ocean() -> GradientTheme {
+    GradientTheme::default()
+        .with(
+            "panel",
+            LinearGradient::to_bottom(vec![MIDNIGHT_BLUE.into(), DARK_SLATE_BLUE.into()]),
+        )
+        .with(
+            "panel.header",
+            LinearGradient::to_right(vec![DEEP_SKY_BLUE.into(), ROYAL_BLUE.into()]),
+        )
+        .with(
+            "panel.border",
+            LinearGradient::to_right(vec![AQUA.into(), DEEP_SKY_BLUE.into()]),
+        )
+        .with_dark(
+            "panel",
+            LinearGradient::to_bottom(vec![BLACK.into(), MIDNIGHT_BLUE.into()]),
+        )
+        .with_dark(
+            "panel.header",
+            LinearGradient::to_right(vec![NAVY.into(), DARK_SLATE_BLUE.into()]),
+        )
+        .with_transition_duration(0.5)
+}
+
+fn sunset() -> GradientTheme {
+    GradientTheme::default()
+        .with(
+            "panel",
+            LinearGradient::to_bottom(vec![MAROON.into(), DARK_ORCHID.into()]),
+        )
+        .with(
+            "panel.header",
+            LinearGradient::to_right(vec![ORANGE_RED.into(), GOLD.into()]),
+        )
+        .with(
+            "panel.border",
+            LinearGradient::to_right(vec![GOLD.into(), HOT_PINK.into()]),
+        )
+        .with_dark(
+            "panel",
+            LinearGradient::to_bottom(vec![BLACK.into(), MAROON.into()]),
+        )
+        .with_dark(
+            "panel.header",
+            LinearGradient::to_right(vec![DARK_RED.into(), DARK_ORANGE.into()]),
+        )
+        .with_transition_duration(0.5)
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for title in ["Inventory", "Quests", "Map"] {
+                commands.spawn((
+                    Node {
+                        width: Val::Px(200.),
+                        height: Val::Px(300.),
+                        flex_direction: FlexDirection::Column,
+                        border: UiRect::all(Val::Px(4.)),
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Px(12.)),
+                    ThemedGradient::new("panel"),
+                    ThemedBorderGradient::new("panel.border"),
+                    children![(
+                        Node {
+                            padding: UiRect::all(Val::Px(10.)),
+                            ..Default::default()
+                        },
+                        ThemedGradient::new("panel.header"),
+                        children![Text::new(title)],
+                    )],
+                ));
+            }
+        });
+}
+
+fn switch_theme(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut theme: ResMut<GradientTheme>,
+    mut is_sunset: Local<bool>,
+) {
+    if keys.just_pressed(KeyCode::Space) {
+        *is_sunset = !*is_sunset;
+        let variant = theme.variant;
+        *theme = if *is_sunset { sunset() } else { ocean() };
+        theme.variant = variant;
+    }
+
+    if keys.just_pressed(KeyCode::KeyD) {
+        theme.toggle_variant(0.5);
+    }
+}
@@ -0,0 +1,95 @@
+//! Example demonstrating tiled gradient layers with each `TileRepeat` mode.
+
+use bevy::color::palettes::css::DARK_SLATE_BLUE;
+use bevy::color::palettes::css::GOLD;
+use bevy::color::palettes::css::MEDIUM_PURPLE;
+use bevy::color::palettes::css::NAVY;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for repeat in [
+                TileRepeat::Repeat,
+                TileRepeat::Space,
+                TileRepeat::Round,
+                TileRepeat::NoRepeat,
+            ] {
+                let tiling = GradientTiling::px(45.).with_repeat(repeat);
+                commands
+                    .spawn(Node {
+                        column_gap: Val::Px(20.),
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    })
+                    .with_children(|commands| {
+                        commands.spawn((
+                            Node {
+                                width: Val::Px(100.),
+                                ..Default::default()
+                            },
+                            Text::new(format!("{repeat:?}")),
+                        ));
+                        commands.spawn((
+                            Node {
+                                width: Val::Px(200.),
+                                height: Val::Px(150.),
+                                ..Default::default()
+                            },
+                            BackgroundGradient(vec![
+                                LinearGradient::to_bottom(vec![NAVY.into()]).into(),
+                                RadialGradient::new(
+                                    Position::CENTER,
+                                    RadialGradientShape::ClosestSide,
+                                    vec![GOLD.into(), MEDIUM_PURPLE.into(), Color::NONE.into()],
+                                )
+                                .with_tiling(tiling)
+                                .into(),
+                            ]),
+                        ));
+                        commands.spawn((
+                            Node {
+                                width: Val::Px(200.),
+                                height: Val::Px(150.),
+                                border: UiRect::all(Val::Px(10.)),
+                                padding: UiRect::all(Val::Px(10.)),
+                                ..Default::default()
+                            },
+                            BorderColor(DARK_SLATE_BLUE.into()),
+                            BackgroundGradient::from(
+                                LinearGradient::new(
+                                    std::f32::consts::FRAC_PI_4,
+                                    vec![
+                                        GOLD.into(),
+                                        GOLD.into(),
+                                        MEDIUM_PURPLE.into(),
+                                        MEDIUM_PURPLE.into(),
+                                    ],
+                                )
+                                .with_tiling(tiling)
+                                .with_origin(GradientBox::ContentBox),
+                            ),
+                        ));
+                    });
+            }
+        });
+}
@@ -0,0 +1,69 @@
+//! Example demonstrating `GradientTimeline`, which animates a node's gradients through keyframes.
+
+use bevy::color::palettes::css::DARK_GREEN;
+use bevy::color::palettes::css::GOLD;
+use bevy::color::palettes::css::LIME;
+use bevy::color::palettes::css::MAROON;
+use bevy::color::palettes::css::ORANGE_RED;
+use bevy::color::palettes::css::RED;
+use bevy::math::curve::EaseFunction;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let idle = LinearGradient::to_right(vec![DARK_GREEN.into(), LIME.into()]);
+    let warning = LinearGradient::to_right(vec![ORANGE_RED.into(), GOLD.into()]);
+    let critical = RadialGradient::new(
+        Position::CENTER,
+        RadialGradientShape::FarthestCorner,
+        vec![RED.into(), MAROON.into()],
+    );
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands.spawn((
+                panel(),
+                GradientTimeline::new(TimelineLoop::PingPong)
+                    .with_eased_keyframe(0., idle.clone(), EaseFunction::CubicInOut)
+                    .with_eased_keyframe(2., warning.clone(), EaseFunction::CubicInOut)
+                    .with_keyframe(3., critical.clone()),
+                children![Text::new("Ping-pong")],
+            ));
+
+            commands.spawn((
+                panel(),
+                GradientTimeline::new(TimelineLoop::Repeat)
+                    .with_keyframe(0., idle)
+                    .with_eased_keyframe(1., warning, EaseFunction::Steps(4, JumpAt::End))
+                    .with_keyframe(2., critical),
+                children![Text::new("Repeat")],
+            ));
+        });
+}
+
+fn panel() -> impl Bundle {
+    Node {
+        width: Val::Px(250.),
+        height: Val::Px(250.),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..Default::default()
+    }
+}
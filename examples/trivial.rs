@@ -16,15 +16,10 @@ fn main() {
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
 
-    commands.spawn((
-        Node {
-            width: Val::Percent(100.),
-            height: Val::Percent(100.),
-
-            ..Default::default()
-        },
-        BackgroundGradient::from(LinearGradient::new(0., vec![RED.into(), YELLOW.into()])),
-    ));
+    commands.spawn(gradient_backdrop(LinearGradient::new(
+        0.,
+        vec![RED.into(), YELLOW.into()],
+    )));
 }
 
 #[derive(Component)]
@@ -34,7 +29,7 @@ fn update(time: Res<Time>, mut query: Query<&mut BackgroundGradient, With<Animat
     for mut gradients in query.iter_mut() {
         for gradient in gradients.0.iter_mut() {
             if let Gradient::Linear(LinearGradient { angle, .. }) = gradient {
-                *angle += 0.5 * time.delta_secs();
+                *angle += Angle::rad(0.5 * time.delta_secs());
             }
         }
     }
@@ -0,0 +1,62 @@
+//! Example demonstrating that gradient stop positions, radii and positions given in logical pixels follow `UiScale`.
+//!
+//! Press up and down to change the `UiScale`.
+
+use bevy::color::palettes::css::BLUE;
+use bevy::color::palettes::css::RED;
+use bevy::color::palettes::css::YELLOW;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_ui_scale)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            padding: UiRect::all(Val::Px(20.)),
+            column_gap: Val::Px(20.),
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            commands.spawn((
+                Node {
+                    width: Val::Px(200.),
+                    height: Val::Px(100.),
+                    ..Default::default()
+                },
+                BackgroundGradient::from(LinearGradient::to_right(vec![
+                    ColorStop::new(RED, Val::Px(50.)),
+                    ColorStop::new(BLUE, Val::Px(150.)),
+                ])),
+            ));
+            commands.spawn((
+                Node {
+                    width: Val::Px(100.),
+                    height: Val::Px(100.),
+                    ..Default::default()
+                },
+                BackgroundGradient::from(RadialGradient::new(
+                    Position::TOP_LEFT.at_px(25., 25.),
+                    RadialGradientShape::Circle(Val::Px(50.)),
+                    vec![YELLOW.into(), BLUE.into()],
+                )),
+            ));
+        });
+}
+
+fn update_ui_scale(keyboard: Res<ButtonInput<KeyCode>>, mut ui_scale: ResMut<UiScale>) {
+    if keyboard.just_pressed(KeyCode::ArrowUp) {
+        ui_scale.0 = (ui_scale.0 + 0.25).min(4.);
+    }
+    if keyboard.just_pressed(KeyCode::ArrowDown) {
+        ui_scale.0 = (ui_scale.0 - 0.25).max(0.25);
+    }
+}
@@ -0,0 +1,73 @@
+//! Example demonstrating that gradients are drawn in the order set by `ZIndex` and `GlobalZIndex`.
+//!
+//! The panels overlap, with the middle panel raised above its siblings by its `ZIndex`.
+//! The tooltip is a child of the first panel, but its `GlobalZIndex` draws it over all of the panels.
+
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+use bevy_ui_gradients::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, UiGradientsPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|commands| {
+            for (i, (start, end, z_index)) in
+                [(RED, ORANGE, 0), (GREEN, YELLOW, 1), (BLUE, PURPLE, 0)]
+                    .into_iter()
+                    .enumerate()
+            {
+                let mut panel = commands.spawn((
+                    Node {
+                        width: Val::Px(250.),
+                        height: Val::Px(250.),
+                        margin: UiRect::left(Val::Px(if i == 0 { 0. } else { -50. })),
+                        border: UiRect::all(Val::Px(10.)),
+                        ..Default::default()
+                    },
+                    ZIndex(z_index),
+                    BorderRadius::all(Val::Px(20.)),
+                    BackgroundGradient::from(LinearGradient::to_bottom_right(vec![
+                        start.into(),
+                        end.into(),
+                    ])),
+                    BorderGradient::from(LinearGradient::to_right(vec![
+                        Color::WHITE.into(),
+                        Color::BLACK.into(),
+                    ])),
+                ));
+                if i == 0 {
+                    panel.with_child((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(150.),
+                            top: Val::Px(180.),
+                            padding: UiRect::all(Val::Px(10.)),
+                            ..Default::default()
+                        },
+                        GlobalZIndex(1),
+                        BorderRadius::all(Val::Px(8.)),
+                        BackgroundGradient::from(LinearGradient::to_bottom(vec![
+                            Color::srgb(0.2, 0.2, 0.25).into(),
+                            Color::srgb(0.05, 0.05, 0.1).into(),
+                        ])),
+                        children![Text::new("Tooltip with GlobalZIndex(1)")],
+                    ));
+                }
+            }
+        });
+}
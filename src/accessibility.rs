@@ -0,0 +1,46 @@
+use crate::*;
+use bevy::{color::LinearRgba, ecs::resource::Resource, prelude::ReflectResource};
+
+/// Accessibility settings honored by the built-in gradient animations and the gradient renderer.
+///
+/// With the default settings gradients are drawn and animated normally.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct GradientAccessibility {
+    /// Freezes gradient motion, for users who prefer reduced motion.
+    ///
+    /// [`GradientAnimation`]s aren't drawn, animated [`GradientGrain`] is static, [`GradientTimeline`]s
+    /// don't advance, and [`InteractionGradients`] and [`GradientTheme`] transitions complete immediately.
+    pub reduced_motion: bool,
+    /// The minimum alpha of the color stops of background, border and stroke gradients, for users who prefer
+    /// reduced transparency. Zero leaves the alpha unchanged.
+    pub min_alpha: f32,
+}
+
+impl GradientAccessibility {
+    /// Settings for users who prefer reduced motion
+    pub fn reduced_motion() -> Self {
+        Self {
+            reduced_motion: true,
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether gradient motion is frozen
+    pub fn with_reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
+    /// Sets the minimum alpha of gradient color stops
+    pub fn with_min_alpha(mut self, min_alpha: f32) -> Self {
+        self.min_alpha = min_alpha;
+        self
+    }
+
+    /// Raises the alpha of `color` to the minimum alpha
+    pub(crate) fn apply_min_alpha(&self, mut color: LinearRgba) -> LinearRgba {
+        color.alpha = color.alpha.max(self.min_alpha);
+        color
+    }
+}
@@ -0,0 +1,77 @@
+use crate::*;
+use bevy::color::{Alpha, Hsla, Hue, Luminance};
+
+impl Gradient {
+    /// Returns a copy of the gradient with `f` applied to the color of each of its stops
+    pub fn map_colors(&self, mut f: impl FnMut(Color) -> Color) -> Self {
+        let mut gradient = self.clone();
+        for color in gradient.colors_mut() {
+            *color = f(*color);
+        }
+        gradient
+    }
+}
+
+impl BackgroundGradient {
+    /// Returns a copy with `f` applied to the color of each stop of each gradient
+    pub fn map_colors(&self, mut f: impl FnMut(Color) -> Color) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|gradient| gradient.map_colors(&mut f))
+                .collect(),
+        )
+    }
+}
+
+impl BorderGradient {
+    /// Returns a copy with `f` applied to the color of each stop of each gradient
+    pub fn map_colors(&self, mut f: impl FnMut(Color) -> Color) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|gradient| gradient.map_colors(&mut f))
+                .collect(),
+        )
+    }
+}
+
+/// Implements color adjustments in terms of `map_colors`, for deriving disabled and hover variants
+/// from a single gradient.
+macro_rules! impl_color_adjustments {
+    ($($t:ty),*) => {$(
+        impl $t {
+            /// Returns a copy with the alpha of every stop set to `alpha`
+            pub fn with_alpha(&self, alpha: f32) -> Self {
+                self.map_colors(|color| color.with_alpha(alpha))
+            }
+
+            /// Returns a copy with every stop lightened by `amount`, see [`Luminance::lighter`]
+            pub fn lighten(&self, amount: f32) -> Self {
+                self.map_colors(|color| color.lighter(amount))
+            }
+
+            /// Returns a copy with every stop darkened by `amount`, see [`Luminance::darker`]
+            pub fn darken(&self, amount: f32) -> Self {
+                self.map_colors(|color| color.darker(amount))
+            }
+
+            /// Returns a copy with the HSL saturation of every stop reduced by `amount`, between `0.` and `1.`
+            pub fn desaturate(&self, amount: f32) -> Self {
+                self.map_colors(|color| {
+                    let hsla = Hsla::from(color);
+                    hsla.with_saturation((hsla.saturation - amount).clamp(0., 1.))
+                        .into()
+                })
+            }
+
+            /// Returns a copy with the hue of every stop rotated by `angle`
+            pub fn hue_rotate(&self, angle: impl Into<Angle>) -> Self {
+                let degrees = angle.into().to_degrees();
+                self.map_colors(|color| color.rotate_hue(degrees))
+            }
+        }
+    )*};
+}
+
+impl_color_adjustments!(Gradient, BackgroundGradient, BorderGradient);
@@ -0,0 +1,137 @@
+use bevy::prelude::ReflectDefault;
+use bevy::reflect::Reflect;
+use core::f32::consts::TAU;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// An angle, stored in radians.
+///
+/// Gradient angles point upward at zero and increase clockwise, like CSS angles.
+/// Bare `f32`s convert into angles as radians.
+///
+/// ```
+/// # use bevy_ui_gradients::Angle;
+/// assert_eq!(Angle::deg(90.), Angle::turns(0.25));
+/// assert_eq!(Angle::deg(-90.).normalized(), Angle::deg(270.));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, PartialOrd, Debug, Reflect)]
+#[reflect(Default, PartialEq, Debug)]
+pub struct Angle(f32);
+
+impl Angle {
+    /// The zero angle, pointing upward
+    pub const ZERO: Self = Self(0.);
+    /// A full turn
+    pub const FULL_TURN: Self = Self(TAU);
+
+    /// An angle in radians
+    pub const fn rad(radians: f32) -> Self {
+        Self(radians)
+    }
+
+    /// An angle in degrees
+    pub const fn deg(degrees: f32) -> Self {
+        Self(degrees * (TAU / 360.))
+    }
+
+    /// An angle in turns, where `1.` is a full turn
+    pub const fn turns(turns: f32) -> Self {
+        Self(turns * TAU)
+    }
+
+    /// The angle in radians
+    pub const fn to_radians(self) -> f32 {
+        self.0
+    }
+
+    /// The angle in degrees
+    pub const fn to_degrees(self) -> f32 {
+        self.0 * (360. / TAU)
+    }
+
+    /// The angle in turns
+    pub const fn to_turns(self) -> f32 {
+        self.0 / TAU
+    }
+
+    /// The equivalent angle between zero and a full turn
+    pub fn normalized(self) -> Self {
+        Self(self.0.rem_euclid(TAU))
+    }
+
+    /// Linearly interpolates between `self` and `other`
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        Self(self.0 + (other.0 - self.0) * t)
+    }
+}
+
+impl From<f32> for Angle {
+    fn from(radians: f32) -> Self {
+        Self(radians)
+    }
+}
+
+impl From<Angle> for f32 {
+    fn from(angle: Angle) -> Self {
+        angle.0
+    }
+}
+
+impl Add for Angle {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for Angle {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Angle {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Angle {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Mul<f32> for Angle {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Mul<Angle> for f32 {
+    type Output = Angle;
+
+    fn mul(self, rhs: Angle) -> Angle {
+        Angle(self * rhs.0)
+    }
+}
+
+impl Div<f32> for Angle {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self(self.0 / rhs)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::*;
+use bevy::math::Vec4;
+
+/// Continuous animation of a node's gradients that runs entirely in the gradient shader,
+/// using the time from bevy's globals uniform.
+///
+/// The animation applies to all of the node's [`BackgroundGradient`] and [`BorderGradient`] layers.
+/// Unlike updating the gradients every frame, the gradient components don't change.
+///
+/// The shader time wraps to zero every hour, so animations will jump then unless they complete a
+/// whole number of cycles in an hour.
+///
+/// Animations aren't applied by [`GradientMaterial`] or to CPU sampling.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct GradientAnimation {
+    /// Rotation of linear and conic gradients in radians per second, clockwise.
+    ///
+    /// Stop lengths of rotating linear gradients are resolved against the gradient line at the
+    /// gradient's angle and scaled with the length of the gradient line as it rotates.
+    /// Linear gradients with [`GradientEndpoints`] don't rotate.
+    pub angular_velocity: f32,
+    /// Speed at which the stops move along the gradient line, in logical pixels per second.
+    /// For conic gradients this is in radians per second.
+    pub scroll_velocity: f32,
+    /// How much the gradient's alpha pulses, between `0.` (no pulse) and `1.` (fades out completely)
+    pub pulse_amplitude: f32,
+    /// Pulses per second
+    pub pulse_frequency: f32,
+}
+
+impl GradientAnimation {
+    /// Rotates gradients at `angular_velocity` radians per second
+    pub fn rotate(angular_velocity: f32) -> Self {
+        Self {
+            angular_velocity,
+            ..Default::default()
+        }
+    }
+
+    /// Scrolls gradients at `scroll_velocity` logical pixels per second
+    pub fn scroll(scroll_velocity: f32) -> Self {
+        Self {
+            scroll_velocity,
+            ..Default::default()
+        }
+    }
+
+    /// Pulses the alpha of the gradients
+    pub fn pulse(amplitude: f32, frequency: f32) -> Self {
+        Self {
+            pulse_amplitude: amplitude,
+            pulse_frequency: frequency,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the angular velocity
+    pub fn with_angular_velocity(mut self, angular_velocity: f32) -> Self {
+        self.angular_velocity = angular_velocity;
+        self
+    }
+
+    /// Sets the scroll velocity
+    pub fn with_scroll_velocity(mut self, scroll_velocity: f32) -> Self {
+        self.scroll_velocity = scroll_velocity;
+        self
+    }
+
+    /// Sets the pulse amplitude and frequency
+    pub fn with_pulse(mut self, amplitude: f32, frequency: f32) -> Self {
+        self.pulse_amplitude = amplitude;
+        self.pulse_frequency = frequency;
+        self
+    }
+
+    /// The parameters sent to the shader, with the scroll velocity in physical units
+    pub(crate) fn resolve(&self, gradient: &Gradient, scale_factor: f32) -> Vec4 {
+        let angular_velocity = match gradient {
+            Gradient::Linear(LinearGradient {
+                endpoints: Some(_), ..
+            }) => 0.,
+            _ => self.angular_velocity,
+        };
+        let scroll_velocity = match gradient {
+            Gradient::Conic(_) => self.scroll_velocity,
+            _ => self.scroll_velocity * scale_factor,
+        };
+        Vec4::new(
+            angular_velocity,
+            scroll_velocity,
+            self.pulse_amplitude.clamp(0., 1.),
+            self.pulse_frequency,
+        )
+    }
+}
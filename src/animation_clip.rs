@@ -0,0 +1,374 @@
+use crate::*;
+use bevy::{
+    asset::{Asset, AssetLoader, Assets, Handle, LoadContext, io::Reader, ron},
+    ecs::{
+        reflect::AppTypeRegistry,
+        system::{Query, Res},
+        world::{FromWorld, World},
+    },
+    math::{
+        FloatExt,
+        curve::{Curve, EaseFunction},
+    },
+    reflect::{FromReflect, TypeRegistry, TypeRegistryArc, serde::TypedReflectDeserializer},
+    time::Time,
+};
+
+/// A keyframe of a [`GradientTrack`]
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct TrackKeyframe<T> {
+    /// Time of the keyframe in seconds from the start of the clip
+    pub time: f32,
+    /// The value of the animated property at this keyframe
+    pub value: T,
+    /// The easing of the transition from this keyframe to the next
+    pub easing: EaseFunction,
+}
+
+impl<T> TrackKeyframe<T> {
+    /// A keyframe with linear easing to the next keyframe
+    pub fn new(time: f32, value: T) -> Self {
+        Self {
+            time,
+            value,
+            easing: EaseFunction::Linear,
+        }
+    }
+
+    /// Sets the easing to the next keyframe
+    pub fn with_easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// The value of a track at `time`, from keyframes sorted by time.
+/// Returns `None` if there are no keyframes.
+fn sample_keyframes<T: Clone>(
+    keyframes: &[TrackKeyframe<T>],
+    time: f32,
+    lerp: impl Fn(&T, &T, f32) -> T,
+) -> Option<T> {
+    let next = keyframes.partition_point(|keyframe| keyframe.time <= time);
+    if next == 0 || next == keyframes.len() {
+        return keyframes
+            .get(next.saturating_sub(1))
+            .map(|keyframe| keyframe.value.clone());
+    }
+    let (from, to) = (&keyframes[next - 1], &keyframes[next]);
+    let t = (time - from.time) / (to.time - from.time);
+    Some(lerp(&from.value, &to.value, from.easing.sample_clamped(t)))
+}
+
+/// An animated property of the gradients of a node with a [`GradientAnimationPlayer`].
+///
+/// `layer` is the index of the animated gradient in the node's [`BackgroundGradient`]. Tracks leave
+/// layers without the property unchanged.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
+pub enum GradientTrack {
+    /// Animates the angle of a linear gradient, or the start angle of a conic gradient
+    Angle {
+        /// The index of the animated gradient
+        layer: usize,
+        /// The keyframes, sorted by time
+        keyframes: Vec<TrackKeyframe<Angle>>,
+    },
+    /// Animates the color stops of linear, radial, inset and plasma gradients.
+    /// Keyframes with the same number of stops are interpolated, otherwise the stops are replaced
+    /// halfway between the keyframes.
+    Stops {
+        /// The index of the animated gradient
+        layer: usize,
+        /// The keyframes, sorted by time
+        keyframes: Vec<TrackKeyframe<Vec<ColorStop>>>,
+    },
+    /// Animates the center of a radial or conic gradient
+    Position {
+        /// The index of the animated gradient
+        layer: usize,
+        /// The keyframes, sorted by time
+        keyframes: Vec<TrackKeyframe<Position>>,
+    },
+    /// Animates the node's [`UiOpacity`]
+    Opacity {
+        /// The keyframes, sorted by time
+        keyframes: Vec<TrackKeyframe<f32>>,
+    },
+}
+
+impl GradientTrack {
+    /// The time of the track's last keyframe
+    pub fn duration(&self) -> f32 {
+        let last = match self {
+            Self::Angle { keyframes, .. } => keyframes.last().map(|keyframe| keyframe.time),
+            Self::Stops { keyframes, .. } => keyframes.last().map(|keyframe| keyframe.time),
+            Self::Position { keyframes, .. } => keyframes.last().map(|keyframe| keyframe.time),
+            Self::Opacity { keyframes } => keyframes.last().map(|keyframe| keyframe.time),
+        };
+        last.unwrap_or(0.)
+    }
+
+    /// Sets the track's property of `gradients` and `opacity` to its value at `time`
+    pub fn apply(&self, time: f32, gradients: &mut [Gradient], opacity: &mut f32) {
+        match self {
+            Self::Angle { layer, keyframes } => {
+                let Some(angle) = sample_keyframes(keyframes, time, |a, b, t| a.lerp(*b, t)) else {
+                    return;
+                };
+                match gradients.get_mut(*layer) {
+                    Some(Gradient::Linear(gradient)) => gradient.angle = angle,
+                    Some(Gradient::Conic(gradient)) => gradient.start = angle,
+                    _ => {}
+                }
+            }
+            Self::Stops { layer, keyframes } => {
+                let Some(stops) = sample_keyframes(keyframes, time, |a, b, t| {
+                    if a.len() == b.len() {
+                        lerp_color_stops(a, b, t).into_vec()
+                    } else if t < 0.5 {
+                        a.clone()
+                    } else {
+                        b.clone()
+                    }
+                }) else {
+                    return;
+                };
+                match gradients.get_mut(*layer) {
+                    Some(Gradient::Linear(gradient)) => gradient.stops = stops.into(),
+                    Some(Gradient::Radial(gradient)) => gradient.stops = stops.into(),
+                    Some(Gradient::Inset(gradient)) => gradient.stops = stops.into(),
+                    Some(Gradient::Plasma(gradient)) => gradient.stops = stops.into(),
+                    _ => {}
+                }
+            }
+            Self::Position { layer, keyframes } => {
+                let Some(position) =
+                    sample_keyframes(keyframes, time, |a, b, t| lerp_position(*a, *b, t))
+                else {
+                    return;
+                };
+                match gradients.get_mut(*layer) {
+                    Some(Gradient::Radial(gradient)) => gradient.position = position,
+                    Some(Gradient::Conic(gradient)) => gradient.position = position,
+                    _ => {}
+                }
+            }
+            Self::Opacity { keyframes } => {
+                if let Some(value) = sample_keyframes(keyframes, time, |a, b, t| a.lerp(*b, t)) {
+                    *opacity = value;
+                }
+            }
+        }
+    }
+}
+
+/// A keyframed animation of a node's gradients, loaded from a `.gradanim.ron` file.
+///
+/// Each track animates one property of one gradient layer, so the same clip can be played on nodes
+/// with different gradients. The file holds the clip in bevy's reflection format, like a [`GradientAsset`],
+/// with the keyframes of each track sorted by time.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::reflect::TypeRegistry;
+/// # use bevy_ui_gradients::*;
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<GradientAnimationClip>();
+///
+/// let clip = GradientAnimationClip::from_ron(
+///     "(
+///         tracks: [
+///             Opacity(keyframes: [
+///                 (time: 0., value: 0., easing: Linear),
+///                 (time: 2., value: 1., easing: Linear),
+///             ]),
+///         ],
+///         loop_mode: Once,
+///     )",
+///     &registry,
+/// )
+/// .unwrap();
+/// assert_eq!(clip.duration(), 2.);
+///
+/// let mut opacity = 0.;
+/// clip.apply(1., &mut [], &mut opacity);
+/// assert_eq!(opacity, 0.5);
+/// ```
+#[derive(Asset, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct GradientAnimationClip {
+    /// The animated properties
+    pub tracks: Vec<GradientTrack>,
+    /// What happens after the last keyframe of the longest track is reached
+    pub loop_mode: TimelineLoop,
+}
+
+impl GradientAnimationClip {
+    /// Create an empty clip
+    pub fn new(loop_mode: TimelineLoop) -> Self {
+        Self {
+            tracks: vec![],
+            loop_mode,
+        }
+    }
+
+    /// Adds a track
+    pub fn with_track(mut self, track: GradientTrack) -> Self {
+        self.tracks.push(track);
+        self
+    }
+
+    /// The time of the last keyframe of the longest track
+    pub fn duration(&self) -> f32 {
+        self.tracks
+            .iter()
+            .map(GradientTrack::duration)
+            .fold(0., f32::max)
+    }
+
+    /// Applies every track at `time` seconds from the start of the clip, after applying the loop mode
+    pub fn apply(&self, time: f32, gradients: &mut [Gradient], opacity: &mut f32) {
+        let duration = self.duration();
+        let time = if 0. < duration {
+            match self.loop_mode {
+                TimelineLoop::Once => time.clamp(0., duration),
+                TimelineLoop::Repeat => time.rem_euclid(duration),
+                TimelineLoop::PingPong => {
+                    duration - (time.rem_euclid(2. * duration) - duration).abs()
+                }
+            }
+        } else {
+            0.
+        };
+        for track in &self.tracks {
+            track.apply(time, gradients, opacity);
+        }
+    }
+
+    /// Parses a clip from the contents of a `.gradanim.ron` file.
+    /// `registry` must contain the clip's types, [`UiGradientsPlugin`] registers them in the `AppTypeRegistry`.
+    pub fn from_ron(text: &str, registry: &TypeRegistry) -> Result<Self, GradientAssetError> {
+        let registration = registry
+            .get(core::any::TypeId::of::<Self>())
+            .ok_or(GradientAssetError::InvalidGradient)?;
+        let value = ron::Options::default()
+            .from_str_seed(text, TypedReflectDeserializer::new(registration, registry))
+            .map_err(GradientAssetError::Parse)?;
+        Self::from_reflect(value.as_ref()).ok_or(GradientAssetError::InvalidGradient)
+    }
+}
+
+/// Loads [`GradientAnimationClip`]s from `.gradanim.ron` files. Added by [`UiGradientsPlugin`].
+pub struct GradientAnimationClipLoader {
+    registry: TypeRegistryArc,
+}
+
+impl FromWorld for GradientAnimationClipLoader {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            registry: world.resource::<AppTypeRegistry>().0.clone(),
+        }
+    }
+}
+
+impl AssetLoader for GradientAnimationClipLoader {
+    type Asset = GradientAnimationClip;
+    type Settings = ();
+    type Error = GradientAssetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<GradientAnimationClip, GradientAssetError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        let text = core::str::from_utf8(&bytes)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+        GradientAnimationClip::from_ron(text, &self.registry.read())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["gradanim.ron"]
+    }
+}
+
+/// Plays a [`GradientAnimationClip`] on the node's [`BackgroundGradient`] and [`UiOpacity`].
+///
+/// Players share their clip, so a clip loaded once can animate any number of nodes.
+#[derive(Component, Clone, Debug, PartialEq)]
+#[require(BackgroundGradient, UiOpacity)]
+pub struct GradientAnimationPlayer {
+    /// The clip being played
+    pub clip: Handle<GradientAnimationClip>,
+    /// Playback rate multiplier
+    pub speed: f32,
+    /// Seconds played since the start of the clip, scaled by `speed`
+    pub elapsed: f32,
+    /// If true, the clip doesn't advance
+    pub paused: bool,
+}
+
+impl Default for GradientAnimationPlayer {
+    fn default() -> Self {
+        Self::new(Handle::default())
+    }
+}
+
+impl GradientAnimationPlayer {
+    /// Play `clip` from the start
+    pub fn new(clip: Handle<GradientAnimationClip>) -> Self {
+        Self {
+            clip,
+            speed: 1.,
+            elapsed: 0.,
+            paused: false,
+        }
+    }
+
+    /// Sets the playback rate
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Restarts the clip from the start
+    pub fn restart(&mut self) {
+        self.elapsed = 0.;
+    }
+}
+
+/// Advances [`GradientAnimationPlayer`]s and applies their clips to their nodes.
+/// Players wait for their clips to load before advancing.
+pub fn update_gradient_animation_players(
+    time: Res<Time>,
+    accessibility: Res<GradientAccessibility>,
+    clips: Res<Assets<GradientAnimationClip>>,
+    mut query: Query<(
+        &mut GradientAnimationPlayer,
+        &mut BackgroundGradient,
+        &mut UiOpacity,
+    )>,
+) {
+    for (mut player, mut background, mut opacity) in query.iter_mut() {
+        let Some(clip) = clips.get(&player.clip) else {
+            continue;
+        };
+        let finished = clip.loop_mode == TimelineLoop::Once && clip.duration() <= player.elapsed;
+        if !player.paused && !finished && !accessibility.reduced_motion {
+            player.elapsed += time.delta_secs() * player.speed;
+        }
+
+        let mut gradients = background.0.clone();
+        let mut value = opacity.0;
+        clip.apply(player.elapsed, &mut gradients, &mut value);
+        if background.0 != gradients {
+            background.0 = gradients;
+        }
+        if opacity.0 != value {
+            opacity.0 = value;
+        }
+    }
+}
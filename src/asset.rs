@@ -0,0 +1,189 @@
+use crate::*;
+use bevy::{
+    asset::{
+        Asset, AssetEvent, AssetLoader, Assets, Handle, LoadContext,
+        io::Reader,
+        ron::{self, ser::PrettyConfig},
+    },
+    ecs::{
+        change_detection::{DetectChanges, DetectChangesMut, Ref},
+        event::EventReader,
+        reflect::AppTypeRegistry,
+        system::{Query, Res},
+        world::{FromWorld, World},
+    },
+    reflect::{
+        FromReflect, TypePath, TypeRegistry, TypeRegistryArc,
+        serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    },
+};
+use core::fmt;
+
+/// A stack of gradient layers loaded from a `.grad.ron` file.
+///
+/// The file holds a [`BackgroundGradient`] written in bevy's reflection format.
+/// Every field has to be written out, so the easiest way to make a new file is to save a gradient with
+/// [`GradientAsset::to_ron`] or from the dev tools. Add a [`GradientSource`] to a node to display the asset.
+#[derive(Asset, TypePath, Clone, Debug, Default, PartialEq)]
+pub struct GradientAsset(pub BackgroundGradient);
+
+impl<T: Into<BackgroundGradient>> From<T> for GradientAsset {
+    fn from(value: T) -> Self {
+        Self(value.into())
+    }
+}
+
+/// An error returned when loading or saving a [`GradientAsset`] fails
+#[derive(Debug)]
+pub enum GradientAssetError {
+    /// The file couldn't be read or written
+    Io(std::io::Error),
+    /// The file isn't valid RON
+    Parse(ron::error::SpannedError),
+    /// The RON doesn't describe a [`BackgroundGradient`], or a [`GradientAnimationClip`] for clip files
+    InvalidGradient,
+    /// The gradient couldn't be serialized
+    Serialize(ron::Error),
+}
+
+impl fmt::Display for GradientAssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to access gradient asset: {error}"),
+            Self::Parse(error) => write!(f, "failed to parse gradient asset: {error}"),
+            Self::InvalidGradient => write!(f, "gradient asset doesn't describe a gradient"),
+            Self::Serialize(error) => write!(f, "failed to serialize gradient asset: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for GradientAssetError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Parse(error) => Some(error),
+            Self::InvalidGradient => None,
+            Self::Serialize(error) => Some(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for GradientAssetError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl GradientAsset {
+    /// Parses a gradient asset from the contents of a `.grad.ron` file.
+    /// `registry` must contain the gradient types, [`UiGradientsPlugin`] registers them in the `AppTypeRegistry`.
+    pub fn from_ron(text: &str, registry: &TypeRegistry) -> Result<Self, GradientAssetError> {
+        let registration = registry
+            .get(core::any::TypeId::of::<BackgroundGradient>())
+            .ok_or(GradientAssetError::InvalidGradient)?;
+        let value = ron::Options::default()
+            .from_str_seed(text, TypedReflectDeserializer::new(registration, registry))
+            .map_err(GradientAssetError::Parse)?;
+        BackgroundGradient::from_reflect(value.as_ref())
+            .map(Self)
+            .ok_or(GradientAssetError::InvalidGradient)
+    }
+
+    /// Formats the gradient asset as the contents of a `.grad.ron` file
+    pub fn to_ron(&self, registry: &TypeRegistry) -> Result<String, GradientAssetError> {
+        ron::ser::to_string_pretty(
+            &TypedReflectSerializer::new(&self.0, registry),
+            PrettyConfig::default(),
+        )
+        .map_err(GradientAssetError::Serialize)
+    }
+
+    /// Writes the gradient asset to a `.grad.ron` file at `path`, replacing its contents.
+    /// Assets being watched by bevy's `file_watcher` are reloaded after saving.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        registry: &TypeRegistry,
+    ) -> Result<(), GradientAssetError> {
+        std::fs::write(path, self.to_ron(registry)?)?;
+        Ok(())
+    }
+}
+
+/// Loads [`GradientAsset`]s from `.grad.ron` files. Added by [`UiGradientsPlugin`].
+pub struct GradientAssetLoader {
+    registry: TypeRegistryArc,
+}
+
+impl FromWorld for GradientAssetLoader {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            registry: world.resource::<AppTypeRegistry>().0.clone(),
+        }
+    }
+}
+
+impl AssetLoader for GradientAssetLoader {
+    type Asset = GradientAsset;
+    type Settings = ();
+    type Error = GradientAssetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<GradientAsset, GradientAssetError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        let text = core::str::from_utf8(&bytes)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+        GradientAsset::from_ron(text, &self.registry.read())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["grad.ron"]
+    }
+}
+
+/// Displays a [`GradientAsset`] in the node's [`BackgroundGradient`].
+///
+/// The node's gradients are replaced when the asset loads and each time it changes, so edits to the
+/// `.grad.ron` file show up immediately with bevy's `file_watcher` feature enabled.
+/// The dev tools save edits to a node with a `GradientSource` back to its file.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+#[require(BackgroundGradient)]
+pub struct GradientSource(pub Handle<GradientAsset>);
+
+impl From<Handle<GradientAsset>> for GradientSource {
+    fn from(handle: Handle<GradientAsset>) -> Self {
+        Self(handle)
+    }
+}
+
+/// Copies loaded and modified [`GradientAsset`]s into the [`BackgroundGradient`]s of their nodes
+pub fn update_gradient_sources(
+    mut events: EventReader<AssetEvent<GradientAsset>>,
+    assets: Res<Assets<GradientAsset>>,
+    mut nodes: Query<(Ref<GradientSource>, &mut BackgroundGradient)>,
+) {
+    let changed = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    for (source, mut background) in nodes.iter_mut() {
+        if !source.is_changed() && !changed.contains(&source.0.id()) {
+            continue;
+        }
+        if let Some(asset) = assets.get(&source.0) {
+            background.set_if_neq(asset.0.clone());
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        query::{Added, Changed, Or, With},
+        system::{Commands, Query},
+    },
+    ui::{GlobalZIndex, Node, PositionType},
+};
+
+/// A full-screen node drawn behind all other UI, with its [`BackgroundGradient`] as the backdrop.
+///
+/// Backdrops are kept at the full size of the viewport, and behind every other node with a
+/// [`GlobalZIndex`] of [`GradientBackdrop::Z_INDEX`], by [`update_gradient_backdrops`].
+/// Spawn backdrops with [`gradient_backdrop`].
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+#[require(Node, BackgroundGradient)]
+pub struct GradientBackdrop;
+
+impl GradientBackdrop {
+    /// The global z-index of backdrops, below all other UI
+    pub const Z_INDEX: i32 = i32::MIN;
+}
+
+/// A full-screen backdrop node with the gradient stack `gradients`
+pub fn gradient_backdrop(gradients: impl Into<BackgroundGradient>) -> impl Bundle {
+    (
+        backdrop_node(),
+        GlobalZIndex(GradientBackdrop::Z_INDEX),
+        gradients.into(),
+        GradientBackdrop,
+    )
+}
+
+fn backdrop_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(0.),
+        top: Val::Px(0.),
+        width: Val::Percent(100.),
+        height: Val::Percent(100.),
+        ..Default::default()
+    }
+}
+
+/// Keeps [`GradientBackdrop`]s full-screen and behind all other UI
+#[expect(
+    clippy::type_complexity,
+    reason = "the query filters changes to several components"
+)]
+pub fn update_gradient_backdrops(
+    mut commands: Commands,
+    mut backdrops: Query<
+        (Entity, &mut Node, Option<&GlobalZIndex>),
+        (
+            With<GradientBackdrop>,
+            Or<(
+                Added<GradientBackdrop>,
+                Changed<Node>,
+                Changed<GlobalZIndex>,
+            )>,
+        ),
+    >,
+) {
+    for (entity, mut node, z_index) in backdrops.iter_mut() {
+        let backdrop = backdrop_node();
+        if node.position_type != backdrop.position_type
+            || node.left != backdrop.left
+            || node.top != backdrop.top
+            || node.width != backdrop.width
+            || node.height != backdrop.height
+        {
+            node.position_type = backdrop.position_type;
+            node.left = backdrop.left;
+            node.top = backdrop.top;
+            node.width = backdrop.width;
+            node.height = backdrop.height;
+        }
+        if z_index.is_none_or(|z_index| z_index.0 != GradientBackdrop::Z_INDEX) {
+            commands
+                .entity(entity)
+                .insert(GlobalZIndex(GradientBackdrop::Z_INDEX));
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::*;
+use bevy::{
+    asset::{Assets, Handle, RenderAssetUsages},
+    image::{Image, ImageSampler},
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+/// A gradient baked at compile time into a one-dimensional lookup texture of `N` sRGB texels,
+/// embedded in the binary.
+///
+/// Baked gradients need no stop resolution at runtime. Register one as an image asset with
+/// [`BakedGradientPlugin`] and draw it with an `ImageNode`, which stretches the texture across the
+/// node from left to right.
+///
+/// [`BakedGradient::bake`] is a `const fn`, so gradients with fixed stops can be baked into a `static`.
+/// More complex gradients can be rasterized by a build script with [`Gradient::to_image`] at a
+/// height of one pixel, and embedded with `include_bytes!` and [`BakedGradient::from_bytes`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ui_gradients::*;
+/// static SUNSET: BakedGradient<64> = BakedGradient::bake(&[
+///     (Srgba::rgb(1., 0.5, 0.), 0.1),
+///     (Srgba::rgb(0.5, 0., 0.5), 0.6),
+///     (Srgba::rgb(0., 0., 0.2), 0.9),
+/// ]);
+///
+/// assert_eq!(SUNSET.texels[0], [255, 128, 0, 255]);
+/// assert_eq!(SUNSET.texels[63], [0, 0, 51, 255]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BakedGradient<const N: usize> {
+    /// The sRGB texels of the texture, from the start of the gradient to its end
+    pub texels: [[u8; 4]; N],
+}
+
+impl<const N: usize> BakedGradient<N> {
+    /// Bakes a gradient from a list of colors and their positions, as fractions of the length of the texture
+    /// from `0.` to `1.`. The positions must be in ascending order.
+    ///
+    /// The colors are mixed linearly in sRGB space, like the colors of gradients drawn by [`UiGradientsPlugin`].
+    /// The texels before the first stop and after the last stop are filled with the first and last colors.
+    pub const fn bake(stops: &[(Srgba, f32)]) -> Self {
+        let mut texels = [[0; 4]; N];
+        if stops.is_empty() {
+            return Self { texels };
+        }
+        let mut i = 0;
+        while i < N {
+            // Sample at the center of each texel
+            let t = (i as f32 + 0.5) / N as f32;
+            let mut next = 0;
+            while next < stops.len() && stops[next].1 < t {
+                next += 1;
+            }
+            let color = if next == 0 {
+                stops[0].0
+            } else if next == stops.len() {
+                stops[stops.len() - 1].0
+            } else {
+                let (a, start) = stops[next - 1];
+                let (b, end) = stops[next];
+                if end <= start {
+                    b
+                } else {
+                    let f = (t - start) / (end - start);
+                    Srgba::new(
+                        a.red + (b.red - a.red) * f,
+                        a.green + (b.green - a.green) * f,
+                        a.blue + (b.blue - a.blue) * f,
+                        a.alpha + (b.alpha - a.alpha) * f,
+                    )
+                }
+            };
+            texels[i] = [
+                to_u8(color.red),
+                to_u8(color.green),
+                to_u8(color.blue),
+                to_u8(color.alpha),
+            ];
+            i += 1;
+        }
+        Self { texels }
+    }
+
+    /// Creates a baked gradient from `4 * N` bytes of RGBA sRGB texels, like the data of an image
+    /// rasterized with [`Gradient::to_image`] and embedded with `include_bytes!`.
+    ///
+    /// Panics if the length of `bytes` isn't `4 * N`.
+    pub const fn from_bytes(bytes: &[u8]) -> Self {
+        assert!(bytes.len() == 4 * N, "expected 4 bytes for each texel");
+        let mut texels = [[0; 4]; N];
+        let mut i = 0;
+        while i < N {
+            texels[i] = [
+                bytes[4 * i],
+                bytes[4 * i + 1],
+                bytes[4 * i + 2],
+                bytes[4 * i + 3],
+            ];
+            i += 1;
+        }
+        Self { texels }
+    }
+
+    /// The texture as an `N` by 1 image with linear filtering
+    pub fn to_image(&self) -> Image {
+        baked_image(&self.texels)
+    }
+}
+
+/// Rounds a color channel to a byte
+const fn to_u8(channel: f32) -> u8 {
+    let channel = if channel < 0. {
+        0.
+    } else if channel > 1. {
+        1.
+    } else {
+        channel
+    };
+    (channel * 255. + 0.5) as u8
+}
+
+fn baked_image(texels: &[[u8; 4]]) -> Image {
+    let mut image = Image::new(
+        Extent3d {
+            width: texels.len() as u32,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        texels.as_flattened().to_vec(),
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    image.sampler = ImageSampler::linear();
+    image
+}
+
+/// Registers a [`BakedGradient`] as an image asset with a fixed handle, so it can be used without loading.
+/// Add a plugin for each baked gradient.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::asset::weak_handle;
+/// # use bevy_ui_gradients::*;
+/// static FADE: BakedGradient<32> = BakedGradient::bake(&[
+///     (Srgba::WHITE, 0.),
+///     (Srgba::NONE, 1.),
+/// ]);
+/// const FADE_HANDLE: Handle<Image> = weak_handle!("3f1b2c7e-5d84-4a96-b0e2-8c7f1a9d4e53");
+///
+/// let plugin = BakedGradientPlugin::new(FADE_HANDLE, &FADE);
+/// // App::new().add_plugins((DefaultPlugins, UiGradientsPlugin, plugin));
+/// // commands.spawn(ImageNode::new(FADE_HANDLE));
+/// ```
+///
+/// Like [`UiGradientsPlugin`], it must be added after `DefaultPlugins`.
+pub struct BakedGradientPlugin {
+    /// The handle the texture is inserted with
+    pub handle: Handle<Image>,
+    /// The sRGB texels of the texture
+    pub texels: &'static [[u8; 4]],
+}
+
+impl BakedGradientPlugin {
+    /// Registers `gradient` with `handle`
+    pub fn new<const N: usize>(handle: Handle<Image>, gradient: &'static BakedGradient<N>) -> Self {
+        Self {
+            handle,
+            texels: &gradient.texels,
+        }
+    }
+}
+
+impl Plugin for BakedGradientPlugin {
+    fn build(&self, app: &mut App) {
+        app.world_mut()
+            .resource_mut::<Assets<Image>>()
+            .insert(&self.handle, baked_image(self.texels));
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,154 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        query::Changed,
+        system::{Commands, Query},
+    },
+    prelude::DetectChangesMut,
+    ui::{AlignItems, BorderRadius, JustifyContent, Node, UiRect, widget::Button},
+};
+
+/// A button drawn with a [`BackgroundGradient`] and a [`BorderGradient`].
+///
+/// The hovered and pressed gradients are derived from `gradient` by lightening and darkening its stops,
+/// and the button cross-fades between them with [`InteractionGradients`].
+/// Spawn buttons with [`gradient_button`].
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+#[require(Button, Node, BackgroundGradient, BorderGradient)]
+pub struct GradientButton {
+    /// The gradients displayed when the button isn't hovered or pressed
+    pub gradient: BackgroundGradient,
+    /// The gradients drawn on the button's border
+    pub border: BorderGradient,
+    /// How much the stops are lightened while the button is hovered, see [`Luminance::lighter`]
+    pub hover_lighten: f32,
+    /// How much the stops are darkened while the button is pressed, see [`Luminance::darker`]
+    pub press_darken: f32,
+    /// The duration of the transition between states in seconds
+    pub duration: f32,
+}
+
+impl Default for GradientButton {
+    fn default() -> Self {
+        Self {
+            gradient: BackgroundGradient::default(),
+            border: BorderGradient::default(),
+            hover_lighten: Self::DEFAULT_HOVER_LIGHTEN,
+            press_darken: Self::DEFAULT_PRESS_DARKEN,
+            duration: InteractionGradients::DEFAULT_DURATION,
+        }
+    }
+}
+
+impl GradientButton {
+    /// The default amount the stops are lightened while hovered
+    pub const DEFAULT_HOVER_LIGHTEN: f32 = 0.1;
+    /// The default amount the stops are darkened while pressed
+    pub const DEFAULT_PRESS_DARKEN: f32 = 0.1;
+
+    /// Create a new `GradientButton` displaying `gradient`, with a border of the same gradient lightened.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_gradients::*;
+    /// let button = GradientButton::new(LinearGradient::to_right(vec![
+    ///     Color::BLACK.into(),
+    ///     Color::WHITE.into(),
+    /// ]));
+    /// assert_eq!(button.border.0.len(), 1);
+    /// ```
+    pub fn new(gradient: impl Into<BackgroundGradient>) -> Self {
+        let gradient = gradient.into();
+        Self {
+            border: BorderGradient(gradient.lighten(0.2).0),
+            gradient,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the gradients drawn on the button's border
+    pub fn with_border(mut self, border: impl Into<BorderGradient>) -> Self {
+        self.border = border.into();
+        self
+    }
+
+    /// Sets how much the stops are lightened while hovered and darkened while pressed
+    pub fn with_adjustments(mut self, hover_lighten: f32, press_darken: f32) -> Self {
+        self.hover_lighten = hover_lighten;
+        self.press_darken = press_darken;
+        self
+    }
+
+    /// Sets the duration of the transition between states in seconds
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// The [`InteractionGradients`] for the button's states
+    pub fn interaction_gradients(&self) -> InteractionGradients {
+        InteractionGradients::new(
+            self.gradient.clone(),
+            self.gradient.lighten(self.hover_lighten),
+            self.gradient.darken(self.press_darken),
+        )
+        .with_duration(self.duration)
+    }
+}
+
+/// A button node `width` wide and `height` high with a 2 pixel border and rounded corners,
+/// displaying `gradient` and centering its children
+pub fn gradient_button(
+    gradient: impl Into<BackgroundGradient>,
+    width: Val,
+    height: Val,
+) -> impl Bundle {
+    (
+        Node {
+            width,
+            height,
+            border: UiRect::all(Val::Px(2.)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        },
+        BorderRadius::all(Val::Px(8.)),
+        GradientButton::new(gradient),
+    )
+}
+
+/// Keeps the [`InteractionGradients`] and [`BorderGradient`] of each [`GradientButton`] in sync with the button
+pub fn update_gradient_buttons(
+    mut commands: Commands,
+    mut buttons: Query<
+        (
+            Entity,
+            &GradientButton,
+            Option<&mut InteractionGradients>,
+            &mut BorderGradient,
+        ),
+        Changed<GradientButton>,
+    >,
+) {
+    for (entity, button, interaction_gradients, mut border) in buttons.iter_mut() {
+        border.set_if_neq(button.border.clone());
+        let target = button.interaction_gradients();
+        match interaction_gradients {
+            // Update the existing gradients in place so a running transition isn't interrupted
+            Some(mut gradients) => {
+                gradients.none = target.none;
+                gradients.hovered = target.hovered;
+                gradients.pressed = target.pressed;
+                gradients.duration = target.duration;
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .insert((button.gradient.clone(), target));
+            }
+        }
+    }
+}
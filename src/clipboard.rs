@@ -0,0 +1,171 @@
+use crate::*;
+use bevy::{
+    ecs::resource::Resource,
+    tasks::{IoTaskPool, Task, futures::check_ready},
+};
+use core::task::Poll;
+
+/// A clipboard for copying and pasting gradients as CSS, so they can be moved between a running game,
+/// browsers and design tools.
+///
+/// On native targets copies are also written to the system clipboard and pastes read from it, using the
+/// platform's clipboard commands: `pbcopy` and `pbpaste` on macOS, `clip` and PowerShell on Windows,
+/// and `wl-copy` and `wl-paste`, `xclip` or `xsel` on Linux and the BSDs.
+/// If the system clipboard isn't available the text is only kept in the resource.
+///
+/// The commands can take a while to run, or hang waiting for a clipboard owner, so they're run on the
+/// [`IoTaskPool`] instead of blocking the frame. Pastes are requested with [`GradientClipboard::request_paste`]
+/// and their text is returned by [`GradientClipboard::poll_paste`] once it has been read.
+///
+/// Added by [`GradientDevToolsPlugin`].
+#[derive(Resource, Debug, Default)]
+pub struct GradientClipboard {
+    contents: Option<String>,
+    paste: Option<Task<Option<String>>>,
+}
+
+impl GradientClipboard {
+    /// Copies `text` to the clipboard. The system clipboard is written in the background.
+    pub fn copy(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        let system_text = text.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                system::set(&system_text);
+            })
+            .detach();
+        self.contents = Some(text);
+    }
+
+    /// Starts reading the system clipboard in the background, replacing any paste still in progress.
+    /// The text is returned by [`GradientClipboard::poll_paste`].
+    pub fn request_paste(&mut self) {
+        self.paste = Some(IoTaskPool::get().spawn(async { system::get() }));
+    }
+
+    /// Returns [`Poll::Ready`] once the paste started by [`GradientClipboard::request_paste`] has finished,
+    /// with the text on the system clipboard, or the last text copied with this clipboard if the system
+    /// clipboard isn't available. Returns [`Poll::Pending`] while the paste is in progress, or if no paste
+    /// was requested.
+    pub fn poll_paste(&mut self) -> Poll<Option<String>> {
+        let Some(text) = self.paste.as_mut().and_then(check_ready) else {
+            return Poll::Pending;
+        };
+        self.paste = None;
+        Poll::Ready(text.or_else(|| self.contents.clone()))
+    }
+
+    /// Copies the gradient to the clipboard as CSS, returning the copied text.
+    /// Returns `None` without copying for gradients that have no CSS equivalent.
+    pub fn copy_gradient(&mut self, gradient: &Gradient) -> Option<String> {
+        let css = gradient.to_css()?;
+        self.copy(css.clone());
+        Some(css)
+    }
+
+    /// Polls the paste started by [`GradientClipboard::request_paste`] like [`GradientClipboard::poll_paste`],
+    /// parsing the pasted text as a CSS `background` or `background-image` value or a single gradient.
+    /// The result is `None` if the clipboard is empty.
+    pub fn poll_paste_gradients(
+        &mut self,
+    ) -> Poll<Option<Result<BackgroundGradient, ParseGradientError>>> {
+        self.poll_paste().map(|text| {
+            text.map(|text| {
+                text.parse::<Gradient>()
+                    .map(BackgroundGradient::from)
+                    .or_else(|_| text.parse())
+            })
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod system {
+    use std::{
+        io::Write,
+        process::{Command, Stdio},
+    };
+
+    /// The commands that write stdin to the system clipboard, in order of preference
+    fn set_commands() -> &'static [&'static [&'static str]] {
+        if cfg!(target_os = "macos") {
+            &[&["pbcopy"]]
+        } else if cfg!(target_os = "windows") {
+            &[&["clip"]]
+        } else if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            &[&["wl-copy"]]
+        } else {
+            &[
+                &["xclip", "-selection", "clipboard"],
+                &["xsel", "--clipboard", "--input"],
+            ]
+        }
+    }
+
+    /// The commands that write the system clipboard to stdout, in order of preference
+    fn get_commands() -> &'static [&'static [&'static str]] {
+        if cfg!(target_os = "macos") {
+            &[&["pbpaste"]]
+        } else if cfg!(target_os = "windows") {
+            &[&["powershell", "-NoProfile", "-Command", "Get-Clipboard"]]
+        } else if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            &[&["wl-paste", "--no-newline"]]
+        } else {
+            &[
+                &["xclip", "-selection", "clipboard", "-o"],
+                &["xsel", "--clipboard", "--output"],
+            ]
+        }
+    }
+
+    pub fn set(text: &str) -> bool {
+        set_commands().iter().any(|command| {
+            let [program, args @ ..] = command else {
+                return false;
+            };
+            let Ok(mut child) = Command::new(program)
+                .args(args)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+            else {
+                return false;
+            };
+            let written = child
+                .stdin
+                .take()
+                .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+            child.wait().is_ok_and(|status| status.success()) && written
+        })
+    }
+
+    pub fn get() -> Option<String> {
+        get_commands().iter().find_map(|command| {
+            let [program, args @ ..] = command else {
+                return None;
+            };
+            let output = Command::new(program)
+                .args(args)
+                .stderr(Stdio::null())
+                .output()
+                .ok()?;
+            output
+                .status
+                .success()
+                .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+                .filter(|text| !text.is_empty())
+        })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod system {
+    pub fn set(_text: &str) -> bool {
+        false
+    }
+
+    pub fn get() -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,118 @@
+use crate::*;
+use bevy::{
+    color::{Hsva, Hue},
+    ecs::{bundle::Bundle, query::Changed, system::Query},
+    math::ops::atan2,
+    ui::{BorderRadius, Node},
+};
+
+/// Marks a hue wheel node of a color picker, showing a conic gradient through every hue.
+/// Red is at the top and the hue increases clockwise.
+///
+/// Spawn hue wheels with [`hue_wheel`].
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+#[require(BackgroundGradient)]
+pub struct HueWheel;
+
+impl HueWheel {
+    /// A conic gradient through every hue at full saturation and value
+    pub fn gradient() -> ConicGradient {
+        ConicGradient::new((0..=6).map(|i| {
+            let hue = 60. * i as f32;
+            AngularColorStop::turns(Hsva::hsv(hue % 360., 1., 1.), hue / 360.)
+        }))
+    }
+
+    /// The hue in degrees at `point`, relative to the center of a hue wheel
+    pub fn hue_at(point: Vec2) -> f32 {
+        // Conic gradients start at the top and turn clockwise
+        atan2(point.x, -point.y).to_degrees().rem_euclid(360.)
+    }
+
+    /// The color at `point`, relative to the center of a hue wheel
+    pub fn color_at(point: Vec2) -> Color {
+        Hsva::hsv(Self::hue_at(point), 1., 1.).into()
+    }
+}
+
+/// A saturation and value square of a color picker for the hue `hue`.
+/// Saturation increases from left to right and value from bottom to top.
+///
+/// The square is drawn with two linear gradients, white to the hue from left to right under
+/// transparent to black from top to bottom. Its [`BackgroundGradient`] is updated when the hue changes.
+///
+/// Spawn saturation and value squares with [`saturation_value_box`].
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+#[require(BackgroundGradient)]
+pub struct SaturationValueBox {
+    /// The hue in degrees
+    pub hue: f32,
+}
+
+impl SaturationValueBox {
+    /// The square's gradients, white to the hue under transparent to black
+    pub fn gradients(&self) -> BackgroundGradient {
+        BackgroundGradient(vec![
+            LinearGradient::to_right(vec![
+                Color::WHITE.into(),
+                Color::from(Hsva::hsv(self.hue, 1., 1.)).into(),
+            ])
+            .into(),
+            LinearGradient::to_bottom(vec![Color::NONE.into(), Color::BLACK.into()]).into(),
+        ])
+    }
+
+    /// The color at `point`, relative to the center of a square with the given `size`
+    pub fn color_at(&self, point: Vec2, size: Vec2) -> Color {
+        let uv = (point / size + 0.5).clamp(Vec2::ZERO, Vec2::ONE);
+        Hsva::hsv(self.hue, uv.x, 1. - uv.y).into()
+    }
+
+    /// Sets the hue from a color
+    pub fn with_hue_of(mut self, color: impl Into<Color>) -> Self {
+        self.hue = Hsva::from(color.into()).hue();
+        self
+    }
+}
+
+/// A round hue wheel node with a diameter of `size`
+pub fn hue_wheel(size: Val) -> impl Bundle {
+    (
+        Node {
+            width: size,
+            height: size,
+            ..Default::default()
+        },
+        BorderRadius::MAX,
+        BackgroundGradient::from(HueWheel::gradient()),
+        HueWheel,
+    )
+}
+
+/// A saturation and value square node for `hue` with sides of length `size`
+pub fn saturation_value_box(hue: f32, size: Val) -> impl Bundle {
+    let square = SaturationValueBox { hue };
+    (
+        Node {
+            width: size,
+            height: size,
+            ..Default::default()
+        },
+        square.gradients(),
+        square,
+    )
+}
+
+/// Updates the gradients of [`SaturationValueBox`]es when their hues change
+pub fn update_saturation_value_boxes(
+    mut query: Query<(&SaturationValueBox, &mut BackgroundGradient), Changed<SaturationValueBox>>,
+) {
+    for (square, mut background) in query.iter_mut() {
+        let gradients = square.gradients();
+        if *background != gradients {
+            *background = gradients;
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use crate::*;
+
+/// Scientific colormaps for data visualization, like heat bars and legends.
+///
+/// Viridis, magma, inferno and plasma are the perceptually uniform colormaps from matplotlib.
+/// Turbo is Google's improved rainbow colormap.
+/// The colormaps are evaluated from polynomial fits, which are within a few percent of the reference tables.
+///
+/// ```
+/// # use bevy_ui_gradients::{Colormap, LinearGradient};
+/// let heat_bar = Colormap::Inferno.linear_gradient(LinearGradient::TO_RIGHT, 16);
+/// assert_eq!(heat_bar.stops.len(), 16);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(PartialEq, Debug)]
+pub enum Colormap {
+    Viridis,
+    Magma,
+    Inferno,
+    Plasma,
+    Turbo,
+}
+
+impl Colormap {
+    /// All the colormaps
+    pub const ALL: [Self; 5] = [
+        Self::Viridis,
+        Self::Magma,
+        Self::Inferno,
+        Self::Plasma,
+        Self::Turbo,
+    ];
+
+    /// The color of the colormap at `t`, between `0.` and `1.`
+    pub fn sample(self, t: f32) -> Color {
+        let t = t.clamp(0., 1.);
+        let polynomial = |coefficients: &[f32]| {
+            coefficients
+                .iter()
+                .rev()
+                .fold(0., |sum, coefficient| sum * t + coefficient)
+                .clamp(0., 1.)
+        };
+        let [red, green, blue] = self.coefficients();
+        Srgba::new(polynomial(red), polynomial(green), polynomial(blue), 1.).into()
+    }
+
+    /// `resolution` colors sampled evenly from the colormap, at least two
+    pub fn palette(self, resolution: usize) -> Palette {
+        let last = resolution.max(2) - 1;
+        Palette(
+            (0..=last)
+                .map(|i| self.sample(i as f32 / last as f32))
+                .collect(),
+        )
+    }
+
+    /// A linear gradient through the colormap with `resolution` evenly spaced color stops
+    pub fn linear_gradient(self, angle: impl Into<Angle>, resolution: usize) -> LinearGradient {
+        self.palette(resolution).linear_gradient(angle)
+    }
+
+    /// Polynomial coefficients of the red, green and blue channels in sRGB space, lowest degree first
+    fn coefficients(self) -> [&'static [f32]; 3] {
+        match self {
+            // https://www.shadertoy.com/view/WlfXRN
+            Self::Viridis => [
+                &[
+                    0.277_727_3,
+                    0.105_093_04,
+                    -0.330_861_83,
+                    -4.634_230_5,
+                    6.228_27,
+                    4.776_385,
+                    -5.435_456,
+                ],
+                &[
+                    0.005_407_344_5,
+                    1.404_613_5,
+                    0.214_847_56,
+                    -5.799_101,
+                    14.179_933,
+                    -13.745_145,
+                    4.645_852_6,
+                ],
+                &[
+                    0.334_099_8,
+                    1.384_590_2,
+                    0.095_095_16,
+                    -19.332_441,
+                    56.690_55,
+                    -65.353_03,
+                    26.312_435,
+                ],
+            ],
+            Self::Magma => [
+                &[
+                    -0.002_136_485,
+                    0.251_660_54,
+                    8.353_717,
+                    -27.668_733,
+                    52.176_14,
+                    -50.768_525,
+                    18.655_705,
+                ],
+                &[
+                    -0.000_749_655_05,
+                    0.677_523_24,
+                    -3.577_719_5,
+                    14.264_731,
+                    -27.943_607,
+                    29.046_583,
+                    -11.489_773,
+                ],
+                &[
+                    -0.005_386_128,
+                    2.494_026_6,
+                    0.314_467_9,
+                    -13.649_213,
+                    12.944_169,
+                    4.234_153,
+                    -5.601_961_5,
+                ],
+            ],
+            Self::Inferno => [
+                &[
+                    0.000_218_940_37,
+                    0.106_513_42,
+                    11.602_493,
+                    -41.703_995,
+                    77.162_94,
+                    -71.319_43,
+                    25.131_126,
+                ],
+                &[
+                    0.001_651_004_6,
+                    0.563_956_44,
+                    -3.972_854,
+                    17.436_4,
+                    -33.402_36,
+                    32.626_064,
+                    -12.242_669,
+                ],
+                &[
+                    -0.019_480_899,
+                    3.932_712_3,
+                    -15.942_394,
+                    44.354_145,
+                    -81.807_31,
+                    73.209_52,
+                    -23.070_325,
+                ],
+            ],
+            Self::Plasma => [
+                &[
+                    0.058_732_344,
+                    2.176_514_6,
+                    -2.689_460_5,
+                    6.130_348,
+                    -11.107_436,
+                    10.023_066,
+                    -3.658_713_8,
+                ],
+                &[
+                    0.023_336_709,
+                    0.238_383_42,
+                    -7.455_851,
+                    42.346_19,
+                    -82.666_31,
+                    71.413_62,
+                    -22.931_535,
+                ],
+                &[
+                    0.543_340_2,
+                    0.753_960_46,
+                    3.110_8,
+                    -28.518_854,
+                    60.139_847,
+                    -54.072_19,
+                    18.191_908,
+                ],
+            ],
+            // https://ai.googleblog.com/2019/08/turbo-improved-rainbow-colormap-for.html
+            Self::Turbo => [
+                &[
+                    0.135_721_38,
+                    4.615_392_6,
+                    -42.660_324,
+                    132.131_08,
+                    -152.942_4,
+                    59.286_38,
+                ],
+                &[
+                    0.091_402_61,
+                    2.194_188_4,
+                    4.842_966_6,
+                    -14.185_033,
+                    4.277_298_5,
+                    2.829_566,
+                ],
+                &[
+                    0.106_673_3,
+                    12.641_946,
+                    -60.582_05,
+                    110.362_77,
+                    -89.903_11,
+                    27.348_25,
+                ],
+            ],
+        }
+    }
+}
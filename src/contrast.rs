@@ -0,0 +1,133 @@
+use crate::*;
+use bevy::{color::Alpha, ecs::entity::Entity, math::UVec2};
+
+/// The WCAG 2 minimum contrast ratio for normal text at level AA
+pub const WCAG_AA_CONTRAST: f32 = 4.5;
+/// The WCAG 2 minimum contrast ratio for large text at level AA
+pub const WCAG_AA_LARGE_TEXT_CONTRAST: f32 = 3.;
+/// The WCAG 2 minimum contrast ratio for normal text at level AAA
+pub const WCAG_AAA_CONTRAST: f32 = 7.;
+
+/// The WCAG 2 contrast ratio between two colors, from `1.` for identical luminances to `21.` for black and white.
+/// Alpha is ignored.
+///
+/// <https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio>
+pub fn contrast_ratio(a: impl Into<Color>, b: impl Into<Color>) -> f32 {
+    let luminance = |color: Color| {
+        let color = LinearRgba::from(color);
+        0.2126 * color.red + 0.7152 * color.green + 0.0722 * color.blue
+    };
+    let (a, b) = (luminance(a.into()), luminance(b.into()));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The lowest and highest contrast ratios of a text color against the sampled points of a gradient
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastRange {
+    /// The lowest contrast ratio
+    pub min: f32,
+    /// The highest contrast ratio
+    pub max: f32,
+}
+
+impl ContrastRange {
+    /// Returns true if the contrast is at least `ratio` at every sampled point
+    pub fn meets(&self, ratio: f32) -> bool {
+        ratio <= self.min
+    }
+
+    /// The contrast range of `text_color` against `samples` composited over an opaque `backdrop`
+    fn from_samples(
+        text_color: Color,
+        backdrop: Color,
+        samples: impl IntoIterator<Item = LinearRgba>,
+    ) -> Self {
+        let backdrop = LinearRgba::from(backdrop).with_alpha(1.);
+        samples.into_iter().fold(
+            Self {
+                min: f32::INFINITY,
+                max: 0.,
+            },
+            |range, sample| {
+                let ratio = contrast_ratio(text_color, blend_over(backdrop, sample));
+                Self {
+                    min: range.min.min(ratio),
+                    max: range.max.max(ratio),
+                }
+            },
+        )
+    }
+}
+
+/// Points at the centers of the cells of a `samples.x` by `samples.y` grid across a rect of `size`,
+/// relative to its center
+fn sample_grid(size: Vec2, samples: UVec2) -> impl Iterator<Item = Vec2> {
+    let samples = samples.max(UVec2::ONE);
+    (0..samples.y).flat_map(move |y| {
+        (0..samples.x).map(move |x| {
+            (Vec2::new(x as f32 + 0.5, y as f32 + 0.5) / samples.as_vec2() - 0.5) * size
+        })
+    })
+}
+
+impl Gradient {
+    /// The range of contrast ratios of `text_color` against the gradient drawn over `backdrop`,
+    /// sampled on a `samples.x` by `samples.y` grid across a node with a size of `physical_size`.
+    /// `backdrop` is the color behind the gradient and is treated as opaque.
+    pub fn contrast_range(
+        &self,
+        text_color: impl Into<Color>,
+        backdrop: impl Into<Color>,
+        physical_size: Vec2,
+        samples: UVec2,
+    ) -> ContrastRange {
+        let gradient = PhysicalGradient::new(self, 1., physical_size, physical_size);
+        ContrastRange::from_samples(
+            text_color.into(),
+            backdrop.into(),
+            sample_grid(physical_size, samples).map(|point| gradient.color_at(point)),
+        )
+    }
+
+    /// Returns the color from `candidates` with the highest minimum contrast against the gradient drawn over `backdrop`,
+    /// for choosing a legible text color. Returns `None` if there are no candidates.
+    pub fn best_text_color(
+        &self,
+        candidates: &[Color],
+        backdrop: impl Into<Color>,
+        physical_size: Vec2,
+        samples: UVec2,
+    ) -> Option<Color> {
+        let backdrop = backdrop.into();
+        candidates.iter().copied().max_by(|a, b| {
+            let a = self.contrast_range(*a, backdrop, physical_size, samples);
+            let b = self.contrast_range(*b, backdrop, physical_size, samples);
+            a.min.total_cmp(&b.min)
+        })
+    }
+}
+
+impl GradientSampler<'_, '_> {
+    /// The range of contrast ratios of `text_color` against the node's composited gradients drawn over `backdrop`,
+    /// sampled on a `samples.x` by `samples.y` grid across the node.
+    /// `backdrop` is the color behind the node's gradients and is treated as opaque.
+    ///
+    /// Returns `None` if `entity` isn't a UI node.
+    pub fn contrast_range(
+        &self,
+        entity: Entity,
+        text_color: impl Into<Color>,
+        backdrop: impl Into<Color>,
+        samples: UVec2,
+    ) -> Option<ContrastRange> {
+        let size = self.node_size(entity)?;
+        let samples = sample_grid(size, samples)
+            .map(|point| self.sample_node(entity, point).map(LinearRgba::from))
+            .collect::<Option<Vec<_>>>()?;
+        Some(ContrastRange::from_samples(
+            text_color.into(),
+            backdrop.into(),
+            samples,
+        ))
+    }
+}
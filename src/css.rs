@@ -0,0 +1,1233 @@
+use crate::*;
+use bevy::{
+    color::{
+        Hsla,
+        palettes::{basic, css},
+    },
+    math::FloatExt,
+};
+use core::{fmt, str::FromStr};
+
+/// An error returned when parsing a gradient from CSS fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGradientError {
+    /// The input didn't contain a gradient
+    Empty,
+    /// The parentheses in the input weren't balanced
+    UnbalancedParentheses,
+    /// A function that isn't a supported gradient, like `url(..)`
+    UnsupportedFunction(String),
+    /// A value that couldn't be parsed or isn't supported
+    InvalidValue(String),
+    /// A color that couldn't be parsed
+    InvalidColor(String),
+    /// A gradient without any color stops
+    MissingColorStops,
+}
+
+impl fmt::Display for ParseGradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no gradient found"),
+            Self::UnbalancedParentheses => write!(f, "unbalanced parentheses"),
+            Self::UnsupportedFunction(name) => write!(f, "unsupported function `{name}`"),
+            Self::InvalidValue(value) => write!(f, "invalid value `{value}`"),
+            Self::InvalidColor(color) => write!(f, "invalid color `{color}`"),
+            Self::MissingColorStops => write!(f, "gradient has no color stops"),
+        }
+    }
+}
+
+impl core::error::Error for ParseGradientError {}
+
+/// Parses a CSS linear gradient direction: an angle with a `deg`, `rad`, `grad` or `turn` unit,
+/// or side and corner keywords like `to top right`.
+///
+/// ```
+/// # use bevy_ui_gradients::{Angle, LinearGradient};
+/// assert_eq!("to top right".parse::<Angle>(), Ok(LinearGradient::TO_TOP_RIGHT));
+/// assert_eq!("0.5turn".parse::<Angle>(), Ok(Angle::turns(0.5)));
+/// ```
+impl FromStr for Angle {
+    type Err = ParseGradientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseGradientError::Empty);
+        }
+        parse_linear_direction(&split_top_level(s, char::is_whitespace)?)
+            .ok_or_else(|| ParseGradientError::InvalidValue(s.to_string()))
+    }
+}
+
+/// Parses a CSS `<position>` with one to four values, like `center bottom 10px`.
+/// The position can follow `at`, as it does in gradient functions.
+///
+/// ```
+/// # use bevy_ui_gradients::Position;
+/// # use bevy::ui::Val;
+/// assert_eq!("at center".parse::<Position>(), Ok(Position::CENTER));
+/// assert_eq!(
+///     "at center bottom 10px".parse::<Position>(),
+///     Ok(Position::BOTTOM.at_y(Val::Px(10.)))
+/// );
+/// ```
+impl FromStr for Position {
+    type Err = ParseGradientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let tokens = split_top_level(s, char::is_whitespace)?;
+        let tokens = match tokens.as_slice() {
+            [at, position @ ..] if at.eq_ignore_ascii_case("at") => position,
+            position => position,
+        };
+        if tokens.is_empty() {
+            return Err(ParseGradientError::Empty);
+        }
+        parse_position(tokens).ok_or_else(|| ParseGradientError::InvalidValue(s.to_string()))
+    }
+}
+
+/// Parses a single CSS `linear-gradient(..)`, `radial-gradient(..)` or `conic-gradient(..)`.
+/// The `repeating-` variants are parsed as gradients with [`SpreadMethod::Repeat`].
+///
+/// Radial gradient shape and size keywords map onto [`RadialGradientShape::Extent`], with the CSS defaults
+/// of `ellipse` and `farthest-corner`. Explicit lengths map onto [`RadialGradientShape::Circle`] and
+/// [`RadialGradientShape::Ellipse`].
+impl FromStr for Gradient {
+    type Err = ParseGradientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().trim_end_matches(';').trim_end();
+        if s.is_empty() {
+            return Err(ParseGradientError::Empty);
+        }
+        let (name, args) =
+            parse_function(s)?.ok_or_else(|| ParseGradientError::InvalidValue(s.to_string()))?;
+        parse_gradient_function(&name, args)
+    }
+}
+
+/// Parses the value of a CSS `background` or `background-image` property with one or more
+/// comma-separated gradient layers.
+///
+/// In CSS the first layer is drawn on top, so the layers are reversed to preserve the stacking
+/// order, as later gradients in a [`BackgroundGradient`] are drawn over earlier ones.
+/// A color in the final layer is added as a single-stop gradient beneath the other layers.
+/// `none` layers are skipped. Layer `border-box`, `padding-box` and `content-box` values set the
+/// gradient's origin and clip boxes, like the `background` shorthand. Layer sizes after a `/` and
+/// repeat keywords set the gradient's [`GradientTiling`], and `fixed` sets its [`GradientAttachment`].
+/// Layer position values are ignored.
+///
+/// ```
+/// # use bevy_ui_gradients::BackgroundGradient;
+/// let background: BackgroundGradient = "background: \
+///     linear-gradient(to right, rgba(255, 0, 0, 0.5), transparent), \
+///     radial-gradient(circle at 25% 75%, #ff0 10%, blue 20px, white), \
+///     conic-gradient(from 90deg, red, yellow 120deg, lime, red);"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(background.0.len(), 3);
+/// ```
+impl FromStr for BackgroundGradient {
+    type Err = ParseGradientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut s = s.trim().trim_end_matches(';').trim_end();
+        for property in ["background-image", "background"] {
+            if let Some(value) = strip_prefix_ignore_case(s, property)
+                .and_then(|value| value.trim_start().strip_prefix(':'))
+            {
+                s = value.trim();
+                break;
+            }
+        }
+
+        if s.is_empty() {
+            return Err(ParseGradientError::Empty);
+        }
+
+        let layers = split_top_level(s, |c| c == ',')?;
+        let last = layers.len() - 1;
+        let mut gradients = vec![];
+        let mut color = None;
+        let mut color_clip = None;
+        for (index, layer) in layers.into_iter().enumerate() {
+            if layer.is_empty() {
+                return Err(ParseGradientError::InvalidValue(s.to_string()));
+            }
+            let mut found = false;
+            let mut layer_gradient = None;
+            let mut boxes = vec![];
+            let mut repeats = vec![];
+            let mut sizes = vec![];
+            let mut after_slash = false;
+            let mut attachment = None;
+            for token in split_top_level(layer, char::is_whitespace)? {
+                // The size follows the position, separated by a slash
+                let token = match token.split_once('/') {
+                    Some((position, size)) => {
+                        if after_slash || !(position.is_empty() || is_ignored_layer_value(position))
+                        {
+                            return Err(ParseGradientError::InvalidValue(layer.to_string()));
+                        }
+                        after_slash = true;
+                        if size.is_empty() {
+                            continue;
+                        }
+                        size
+                    }
+                    None => token,
+                };
+                if after_slash && sizes.len() < 2 {
+                    if token.eq_ignore_ascii_case("auto") {
+                        sizes.push(Val::Auto);
+                        continue;
+                    }
+                    if let Some(val) = parse_val(token) {
+                        sizes.push(val);
+                        continue;
+                    }
+                }
+                if let Some((name, args)) = parse_function(token)? {
+                    if name.ends_with("gradient") {
+                        if found {
+                            return Err(ParseGradientError::InvalidValue(layer.to_string()));
+                        }
+                        layer_gradient = Some(parse_gradient_function(&name, args)?);
+                        found = true;
+                        continue;
+                    }
+                    if index != last || color.is_some() {
+                        return Err(ParseGradientError::UnsupportedFunction(name));
+                    }
+                    color = Some(parse_color(token)?);
+                } else if token.eq_ignore_ascii_case("none") {
+                    found = true;
+                } else if let Some(b) = parse_gradient_box(token) {
+                    if boxes.len() == 2 {
+                        return Err(ParseGradientError::InvalidValue(layer.to_string()));
+                    }
+                    boxes.push(b);
+                } else if let Some(a) = parse_attachment(token) {
+                    if attachment.replace(a).is_some() {
+                        return Err(ParseGradientError::InvalidValue(layer.to_string()));
+                    }
+                } else if let Some(repeat) = parse_tile_repeat(token) {
+                    if !repeats.is_empty() && (repeats.len() == 2 || repeat.len() == 2) {
+                        return Err(ParseGradientError::InvalidValue(layer.to_string()));
+                    }
+                    repeats.extend(repeat);
+                } else if is_ignored_layer_value(token) {
+                    continue;
+                } else if index == last && color.is_none() {
+                    color = Some(parse_color(token)?);
+                } else {
+                    return Err(ParseGradientError::InvalidValue(token.to_string()));
+                }
+            }
+            // A single box sets both the origin and the clip, two boxes set the origin then the clip
+            let (origin, clip) = match boxes[..] {
+                [b] => (Some(b), Some(b)),
+                [origin, clip] => (Some(origin), Some(clip)),
+                _ => (None, None),
+            };
+            if let Some(mut gradient) = layer_gradient {
+                if !sizes.is_empty() || !repeats.is_empty() {
+                    let size = |i: usize| sizes.get(i).copied().unwrap_or(Val::Auto);
+                    let repeat = |i: usize| {
+                        repeats
+                            .get(i)
+                            .or(repeats.first())
+                            .copied()
+                            .unwrap_or_default()
+                    };
+                    gradient = gradient.with_tiling(
+                        GradientTiling::new(size(0), size(1)).with_repeat_xy(repeat(0), repeat(1)),
+                    );
+                }
+                if let Some(origin) = origin {
+                    gradient = gradient.with_origin(origin);
+                }
+                if let Some(attachment) = attachment {
+                    gradient = gradient.with_attachment(attachment);
+                }
+                if let Some(clip) = clip {
+                    gradient = gradient.with_clip(clip);
+                }
+                gradients.push(gradient);
+            }
+            if index == last {
+                color_clip = clip;
+            }
+        }
+
+        // CSS draws the background color beneath all of the layers
+        if let Some(color) = color {
+            // The background color is clipped by the final layer's clip box
+            let mut gradient = LinearGradient::to_bottom(vec![color.into()]);
+            if let Some(clip) = color_clip {
+                gradient = gradient.with_clip(clip);
+            }
+            gradients.push(gradient.into());
+        }
+        gradients.reverse();
+        Ok(BackgroundGradient(gradients))
+    }
+}
+
+impl FromStr for BorderGradient {
+    type Err = ParseGradientError;
+
+    /// Parses the value of a CSS `border-image` or `border-image-source` property, with or without the
+    /// property name. The slice, width, outset and repeat values of `border-image` are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut s = s.trim().trim_end_matches(';').trim_end();
+        for property in ["border-image-source", "border-image"] {
+            if let Some(value) = strip_prefix_ignore_case(s, property)
+                .and_then(|value| value.trim_start().strip_prefix(':'))
+            {
+                s = value.trim();
+                break;
+            }
+        }
+
+        let mut gradient = None;
+        for token in split_top_level(s, char::is_whitespace)? {
+            if token.eq_ignore_ascii_case("none") {
+                return Ok(BorderGradient::default());
+            }
+            if let Some((name, args)) = parse_function(token)? {
+                if !name.ends_with("gradient") {
+                    return Err(ParseGradientError::UnsupportedFunction(name));
+                }
+                if gradient.is_some() {
+                    return Err(ParseGradientError::InvalidValue(s.to_string()));
+                }
+                gradient = Some(parse_gradient_function(&name, args)?);
+            }
+        }
+        gradient
+            .map(BorderGradient::from)
+            .ok_or(ParseGradientError::Empty)
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    s.get(..prefix.len())
+        .filter(|start| start.eq_ignore_ascii_case(prefix))
+        .map(|_| &s[prefix.len()..])
+}
+
+fn parse_gradient_box(token: &str) -> Option<GradientBox> {
+    [
+        ("border-box", GradientBox::BorderBox),
+        ("padding-box", GradientBox::PaddingBox),
+        ("content-box", GradientBox::ContentBox),
+    ]
+    .into_iter()
+    .find(|(keyword, _)| token.eq_ignore_ascii_case(keyword))
+    .map(|(_, b)| b)
+}
+
+/// Parses a `background-attachment` keyword. `local` is treated as `scroll`.
+fn parse_attachment(token: &str) -> Option<GradientAttachment> {
+    [
+        ("scroll", GradientAttachment::Scroll),
+        ("local", GradientAttachment::Scroll),
+        ("fixed", GradientAttachment::Fixed),
+    ]
+    .into_iter()
+    .find(|(keyword, _)| token.eq_ignore_ascii_case(keyword))
+    .map(|(_, a)| a)
+}
+
+/// Parses a `background-repeat` keyword into horizontal and vertical repeats.
+/// Single axis keywords return one repeat, which applies to both axes unless followed by a second keyword.
+fn parse_tile_repeat(token: &str) -> Option<Vec<TileRepeat>> {
+    let token = token.to_ascii_lowercase();
+    Some(match token.as_str() {
+        "repeat-x" => vec![TileRepeat::Repeat, TileRepeat::NoRepeat],
+        "repeat-y" => vec![TileRepeat::NoRepeat, TileRepeat::Repeat],
+        "repeat" => vec![TileRepeat::Repeat],
+        "space" => vec![TileRepeat::Space],
+        "round" => vec![TileRepeat::Round],
+        "no-repeat" => vec![TileRepeat::NoRepeat],
+        _ => return None,
+    })
+}
+
+/// Layer values that don't affect gradients
+fn is_ignored_layer_value(token: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "top", "bottom", "left", "right", "center", "auto", "cover", "contain",
+    ];
+    parse_val(token).is_some() || KEYWORDS.iter().any(|k| token.eq_ignore_ascii_case(k))
+}
+
+fn parse_gradient_function(name: &str, args: &str) -> Result<Gradient, ParseGradientError> {
+    let items = split_top_level(args, |c| c == ',')?;
+    let (spread, function) = match name.strip_prefix("repeating-") {
+        Some(function) => (SpreadMethod::Repeat, function),
+        None => (SpreadMethod::Pad, name),
+    };
+    let gradient = match function {
+        "linear-gradient" => parse_linear_gradient(&items).map(Gradient::from),
+        "radial-gradient" => parse_radial_gradient(&items).map(Gradient::from),
+        "conic-gradient" => parse_conic_gradient(&items).map(Gradient::from),
+        _ => Err(ParseGradientError::UnsupportedFunction(name.to_string())),
+    }?;
+    Ok(gradient.with_spread(spread))
+}
+
+/// Splits `s` at each character matching `separator` that isn't inside parentheses.
+/// Segments are trimmed. Empty segments are dropped when splitting on whitespace, as runs of
+/// whitespace are a single separator.
+pub(crate) fn split_top_level(
+    s: &str,
+    separator: impl Fn(char) -> bool,
+) -> Result<Vec<&str>, ParseGradientError> {
+    let mut segments = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or(ParseGradientError::UnbalancedParentheses)?
+            }
+            c if depth == 0 && separator(c) => {
+                segments.push(s[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(ParseGradientError::UnbalancedParentheses);
+    }
+    segments.push(s[start..].trim());
+    if separator(' ') {
+        segments.retain(|segment| !segment.is_empty());
+    }
+    Ok(segments)
+}
+
+/// Splits a function token like `rgb(1, 2, 3)` into its lowercase name and arguments.
+fn parse_function(token: &str) -> Result<Option<(String, &str)>, ParseGradientError> {
+    let Some(open) = token.find('(') else {
+        return Ok(None);
+    };
+    let Some(args) = token[open + 1..].strip_suffix(')') else {
+        return Err(ParseGradientError::UnbalancedParentheses);
+    };
+    Ok(Some((token[..open].trim().to_ascii_lowercase(), args)))
+}
+
+/// Splits a dimension like `12.5px` into its value and lowercase unit
+fn parse_dimension(token: &str) -> Option<(f32, String)> {
+    let end = token
+        .char_indices()
+        .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && (c == '-' || c == '+'))))
+        .map(|(i, _)| i)
+        .unwrap_or(token.len());
+    let value = token[..end].parse().ok()?;
+    Some((value, token[end..].to_ascii_lowercase()))
+}
+
+fn parse_val(token: &str) -> Option<Val> {
+    let (value, unit) = parse_dimension(token)?;
+    match unit.as_str() {
+        "px" => Some(Val::Px(value)),
+        "%" => Some(Val::Percent(value)),
+        "vw" => Some(Val::Vw(value)),
+        "vh" => Some(Val::Vh(value)),
+        "vmin" => Some(Val::VMin(value)),
+        "vmax" => Some(Val::VMax(value)),
+        "" if value == 0. => Some(Val::ZERO),
+        _ => None,
+    }
+}
+
+/// Parses a CSS angle into radians
+fn parse_angle(token: &str) -> Option<f32> {
+    let (value, unit) = parse_dimension(token)?;
+    match unit.as_str() {
+        "deg" => Some(value.to_radians()),
+        "rad" => Some(value),
+        "grad" => Some(value * TAU / 400.),
+        "turn" => Some(value * TAU),
+        "" if value == 0. => Some(0.),
+        _ => None,
+    }
+}
+
+/// Parses a CSS hex color, named color, `transparent`, or `rgb(..)`, `rgba(..)`, `hsl(..)` or `hsla(..)` function.
+fn parse_color(token: &str) -> Result<Color, ParseGradientError> {
+    let invalid = || ParseGradientError::InvalidColor(token.to_string());
+    if let Some(hex) = token.strip_prefix('#') {
+        return Srgba::hex(hex).map(Color::from).map_err(|_| invalid());
+    }
+
+    if let Some((name, args)) = parse_function(token)? {
+        let args = split_top_level(args, |c| c == ',' || c == '/' || c.is_whitespace())?;
+        if !(args.len() == 3 || args.len() == 4) {
+            return Err(invalid());
+        }
+        let alpha = match args.get(3) {
+            Some(alpha) => parse_fraction(alpha, 1.).ok_or_else(invalid)?,
+            None => 1.,
+        };
+        return match name.as_str() {
+            "rgb" | "rgba" => {
+                let channel = |arg: &str| parse_fraction(arg, 255.).ok_or_else(invalid);
+                Ok(Srgba::new(
+                    channel(args[0])?,
+                    channel(args[1])?,
+                    channel(args[2])?,
+                    alpha,
+                )
+                .into())
+            }
+            "hsl" | "hsla" => {
+                let hue = parse_angle(args[0])
+                    .map(f32::to_degrees)
+                    .or_else(|| args[0].parse().ok())
+                    .ok_or_else(invalid)?;
+                let percentage = |arg: &str| {
+                    parse_dimension(arg)
+                        .filter(|(_, unit)| unit == "%" || unit.is_empty())
+                        .map(|(value, _)| value / 100.)
+                        .ok_or_else(invalid)
+                };
+                Ok(Hsla::new(
+                    hue.rem_euclid(360.),
+                    percentage(args[1])?,
+                    percentage(args[2])?,
+                    alpha,
+                )
+                .into())
+            }
+            _ => Err(ParseGradientError::UnsupportedFunction(name)),
+        };
+    }
+
+    let name = token.to_ascii_lowercase();
+    if name == "transparent" {
+        return Ok(Color::NONE);
+    }
+    NAMED_COLORS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, color)| (*color).into())
+        .ok_or_else(invalid)
+}
+
+/// Parses a number, or a percentage of `max`, into the range `0..=1`
+fn parse_fraction(token: &str, max: f32) -> Option<f32> {
+    let (value, unit) = parse_dimension(token)?;
+    let fraction = match unit.as_str() {
+        "%" => value / 100.,
+        "" => value / max,
+        _ => return None,
+    };
+    Some(fraction.clamp(0., 1.))
+}
+
+/// Parses the color stop list of a linear or radial gradient
+fn parse_color_stops(items: &[&str]) -> Result<Vec<ColorStop>, ParseGradientError> {
+    let mut stops: Vec<ColorStop> = vec![];
+    let mut hints = vec![];
+    for item in items {
+        let tokens = split_top_level(item, char::is_whitespace)?;
+        let invalid = || ParseGradientError::InvalidValue(item.to_string());
+        if let [hint] = tokens.as_slice() {
+            if let Some(hint) = parse_val(hint) {
+                let index = stops.len().checked_sub(1).ok_or_else(invalid)?;
+                hints.push((index, hint));
+                continue;
+            }
+        }
+        match tokens.as_slice() {
+            [color, points @ ..] if points.len() <= 2 => {
+                let color = parse_color(color)?;
+                if points.is_empty() {
+                    stops.push(ColorStop::auto(color));
+                }
+                for point in points {
+                    stops.push(ColorStop::new(color, parse_val(point).ok_or_else(invalid)?));
+                }
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    for (index, hint) in hints {
+        // A hint must be between two stops
+        if stops.len() - 1 <= index {
+            return Err(ParseGradientError::InvalidValue(format!("{hint:?}")));
+        }
+        stops[index].hint_point = Some(hint);
+    }
+
+    if stops.is_empty() {
+        return Err(ParseGradientError::MissingColorStops);
+    }
+    Ok(stops)
+}
+
+/// Parses the color stop list of a conic gradient.
+/// Percentages are fractions of a full turn.
+fn parse_angular_color_stops(items: &[&str]) -> Result<Vec<AngularColorStop>, ParseGradientError> {
+    let angle = |token: &str| match parse_dimension(token) {
+        Some((value, unit)) if unit == "%" => Some(AngularPosition::Percent(value)),
+        Some((value, unit)) if unit == "turn" => Some(AngularPosition::Turns(value)),
+        _ => parse_angle(token).map(AngularPosition::Radians),
+    };
+    let mut stops: Vec<AngularColorStop> = vec![];
+    let mut hints = vec![];
+    for item in items {
+        let tokens = split_top_level(item, char::is_whitespace)?;
+        let invalid = || ParseGradientError::InvalidValue(item.to_string());
+        if let [hint] = tokens.as_slice() {
+            if let Some(hint) = angle(hint).and_then(AngularPosition::resolve) {
+                let index = stops.len().checked_sub(1).ok_or_else(invalid)?;
+                hints.push((index, hint));
+                continue;
+            }
+        }
+        match tokens.as_slice() {
+            [color, angles @ ..] if angles.len() <= 2 => {
+                let color = parse_color(color)?;
+                if angles.is_empty() {
+                    stops.push(AngularColorStop::auto(color));
+                }
+                for a in angles {
+                    stops.push(AngularColorStop::at(color, angle(a).ok_or_else(invalid)?));
+                }
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    for (index, hint) in hints {
+        let invalid = || ParseGradientError::InvalidValue(format!("{hint}rad"));
+        let last = stops.len() - 1;
+        if last <= index {
+            return Err(invalid());
+        }
+        let start = stops[index].angle.resolve().or((index == 0).then_some(0.));
+        let end = stops[index + 1]
+            .angle
+            .resolve()
+            .or((index + 1 == last).then_some(TAU));
+        let (Some(start), Some(end)) = (start, end) else {
+            return Err(invalid());
+        };
+        stops[index].hint = normalize_hint(start, hint, end);
+    }
+
+    if stops.is_empty() {
+        return Err(ParseGradientError::MissingColorStops);
+    }
+    Ok(stops)
+}
+
+/// Converts the position of a CSS color hint into the normalized position between two stops
+fn normalize_hint(start: f32, hint: f32, end: f32) -> f32 {
+    if end <= start {
+        return 0.5;
+    }
+    ((hint - start) / (end - start)).clamp(0., 1.)
+}
+
+/// Returns true if the first item in a gradient's arguments is a color stop and not a configuration item
+fn starts_with_color(items: &[&str]) -> Result<bool, ParseGradientError> {
+    let Some(first) = items.first() else {
+        return Err(ParseGradientError::MissingColorStops);
+    };
+    Ok(split_top_level(first, char::is_whitespace)?
+        .first()
+        .is_some_and(|token| parse_color(token).is_ok()))
+}
+
+fn parse_linear_gradient(items: &[&str]) -> Result<LinearGradient, ParseGradientError> {
+    if starts_with_color(items)? {
+        return Ok(LinearGradient::to_bottom(parse_color_stops(items)?));
+    }
+    let angle = parse_linear_direction(&split_top_level(items[0], char::is_whitespace)?)
+        .ok_or_else(|| ParseGradientError::InvalidValue(items[0].to_string()))?;
+    Ok(LinearGradient::new(angle, parse_color_stops(&items[1..])?))
+}
+
+/// Parses the direction of a linear gradient, an angle or side or corner keywords following `to`
+fn parse_linear_direction(tokens: &[&str]) -> Option<Angle> {
+    match tokens {
+        [angle] => parse_angle(angle).map(Angle::rad),
+        [to, sides @ ..] if to.eq_ignore_ascii_case("to") => {
+            let mut x = 0;
+            let mut y = 0;
+            for side in sides {
+                match side.to_ascii_lowercase().as_str() {
+                    "left" if x == 0 => x = -1,
+                    "right" if x == 0 => x = 1,
+                    "top" if y == 0 => y = -1,
+                    "bottom" if y == 0 => y = 1,
+                    _ => return None,
+                }
+            }
+            match (x, y) {
+                (0, -1) => Some(LinearGradient::TO_TOP),
+                (1, -1) => Some(LinearGradient::TO_TOP_RIGHT),
+                (1, 0) => Some(LinearGradient::TO_RIGHT),
+                (1, 1) => Some(LinearGradient::TO_BOTTOM_RIGHT),
+                (0, 1) => Some(LinearGradient::TO_BOTTOM),
+                (-1, 1) => Some(LinearGradient::TO_BOTTOM_LEFT),
+                (-1, 0) => Some(LinearGradient::TO_LEFT),
+                (-1, -1) => Some(LinearGradient::TO_TOP_LEFT),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn parse_radial_gradient(items: &[&str]) -> Result<RadialGradient, ParseGradientError> {
+    if starts_with_color(items)? {
+        return Ok(RadialGradient::new(
+            Position::CENTER,
+            RadialGradientShape::extent(EndShape::Ellipse, RadialExtent::FarthestCorner),
+            parse_color_stops(items)?,
+        ));
+    }
+    let tokens = split_top_level(items[0], char::is_whitespace)?;
+    let invalid = || ParseGradientError::InvalidValue(items[0].to_string());
+    let at = tokens
+        .iter()
+        .position(|token| token.eq_ignore_ascii_case("at"))
+        .unwrap_or(tokens.len());
+    let position = match tokens.get(at + 1..) {
+        Some(position) => parse_position(position).ok_or_else(invalid)?,
+        None => Position::CENTER,
+    };
+
+    let mut end_shape = None;
+    let mut extent = None;
+    let mut lengths = vec![];
+    for token in &tokens[..at] {
+        match token.to_ascii_lowercase().as_str() {
+            "circle" if end_shape.is_none() => end_shape = Some(EndShape::Circle),
+            "ellipse" if end_shape.is_none() => end_shape = Some(EndShape::Ellipse),
+            "closest-side" if extent.is_none() => extent = Some(RadialExtent::ClosestSide),
+            "farthest-side" if extent.is_none() => extent = Some(RadialExtent::FarthestSide),
+            "closest-corner" if extent.is_none() => extent = Some(RadialExtent::ClosestCorner),
+            "farthest-corner" if extent.is_none() => extent = Some(RadialExtent::FarthestCorner),
+            _ => lengths.push(parse_val(token).ok_or_else(invalid)?),
+        }
+    }
+    let shape = match (end_shape, extent, lengths.as_slice()) {
+        (end_shape, extent, []) => {
+            RadialGradientShape::extent(end_shape.unwrap_or_default(), extent.unwrap_or_default())
+        }
+        (None | Some(EndShape::Circle), None, [radius]) => RadialGradientShape::Circle(*radius),
+        (None | Some(EndShape::Ellipse), None, [x, y]) => RadialGradientShape::Ellipse(*x, *y),
+        _ => return Err(invalid()),
+    };
+    Ok(RadialGradient::new(
+        position,
+        shape,
+        parse_color_stops(&items[1..])?,
+    ))
+}
+
+fn parse_conic_gradient(items: &[&str]) -> Result<ConicGradient, ParseGradientError> {
+    if starts_with_color(items)? {
+        return Ok(ConicGradient::new(parse_angular_color_stops(items)?));
+    }
+    let tokens = split_top_level(items[0], char::is_whitespace)?;
+    let invalid = || ParseGradientError::InvalidValue(items[0].to_string());
+    let at = tokens
+        .iter()
+        .position(|token| token.eq_ignore_ascii_case("at"))
+        .unwrap_or(tokens.len());
+    let start = match &tokens[..at] {
+        [] => Angle::ZERO,
+        [from, angle] if from.eq_ignore_ascii_case("from") => {
+            Angle::rad(parse_angle(angle).ok_or_else(invalid)?)
+        }
+        _ => return Err(invalid()),
+    };
+    let position = match tokens.get(at + 1..) {
+        Some(position) => parse_position(position).ok_or_else(invalid)?,
+        None => Position::CENTER,
+    };
+    Ok(ConicGradient::new(parse_angular_color_stops(&items[1..])?)
+        .with_start(start)
+        .with_position(position))
+}
+
+/// Parses a CSS `<position>` with one to four values
+fn parse_position(tokens: &[&str]) -> Option<Position> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Keyword {
+        Left,
+        Right,
+        Top,
+        Bottom,
+        Center,
+    }
+
+    let keyword = |token: &str| match token.to_ascii_lowercase().as_str() {
+        "left" => Some(Keyword::Left),
+        "right" => Some(Keyword::Right),
+        "top" => Some(Keyword::Top),
+        "bottom" => Some(Keyword::Bottom),
+        "center" => Some(Keyword::Center),
+        _ => None,
+    };
+    let anchor = |keyword| match keyword {
+        Keyword::Left | Keyword::Top => -0.5,
+        Keyword::Right | Keyword::Bottom => 0.5,
+        Keyword::Center => 0.,
+    };
+    let is_vertical = |keyword| matches!(keyword, Keyword::Top | Keyword::Bottom);
+    let is_horizontal = |keyword| matches!(keyword, Keyword::Left | Keyword::Right);
+
+    // Each axis is an anchor and an offset from the anchor towards the center
+    let (x, y) = match *tokens {
+        [a] => match (keyword(a), parse_val(a)) {
+            (Some(k), _) if is_vertical(k) => ((0., Val::ZERO), (anchor(k), Val::ZERO)),
+            (Some(k), _) => ((anchor(k), Val::ZERO), (0., Val::ZERO)),
+            (None, Some(v)) => ((-0.5, v), (0., Val::ZERO)),
+            _ => return None,
+        },
+        [a, b] => {
+            let axis = |token: &str| match (keyword(token), parse_val(token)) {
+                (Some(k), _) => Some((anchor(k), Val::ZERO, Some(k))),
+                (None, Some(v)) => Some((-0.5, v, None)),
+                _ => None,
+            };
+            let (ax, ox, ka) = axis(a)?;
+            let (ay, oy, kb) = axis(b)?;
+            match (ka, kb) {
+                // `top left` is written vertical first
+                (Some(ka), Some(kb)) if is_vertical(ka) || is_horizontal(kb) => {
+                    if is_vertical(kb) || is_horizontal(ka) {
+                        return None;
+                    }
+                    ((ay, oy), (ax, ox))
+                }
+                (Some(ka), _) if is_vertical(ka) => return None,
+                (_, Some(kb)) if is_horizontal(kb) => return None,
+                _ => ((ax, ox), (ay, oy)),
+            }
+        }
+        [_, _, _] | [_, _, _, _] => {
+            // Keywords, each optionally followed by an offset
+            let mut x = None;
+            let mut y = None;
+            let mut i = 0;
+            while i < tokens.len() {
+                let k = keyword(tokens[i])?;
+                let offset = tokens.get(i + 1).and_then(|token| parse_val(token));
+                i += 1 + offset.is_some() as usize;
+                if k == Keyword::Center && offset.is_some() {
+                    return None;
+                }
+                let axis = (anchor(k), offset.unwrap_or(Val::ZERO));
+                let slot = if is_vertical(k) || (k == Keyword::Center && x.is_some()) {
+                    &mut y
+                } else {
+                    &mut x
+                };
+                if slot.replace(axis).is_some() {
+                    return None;
+                }
+            }
+            (x.unwrap_or((0., Val::ZERO)), y.unwrap_or((0., Val::ZERO)))
+        }
+        _ => return None,
+    };
+    Some(Position::new(Vec2::new(x.0, y.0), x.1, y.1))
+}
+
+impl Gradient {
+    /// Formats the gradient as a CSS `linear-gradient(..)`, `radial-gradient(..)` or `conic-gradient(..)`,
+    /// or their `repeating-` variants, that parses back into an equivalent gradient.
+    ///
+    /// Returns `None` for inset and plasma gradients, which have no CSS equivalent.
+    /// Colors are written as hex in sRGB. Properties without a CSS equivalent, like
+    /// [`SpreadMethod::Reflect`], grain, filters, easing and the gradient's boxes, are dropped, and normalized
+    /// hints are converted into color hints only when both neighboring stops have positions in the same unit.
+    ///
+    /// ```
+    /// # use bevy_ui_gradients::Gradient;
+    /// let gradient: Gradient = "linear-gradient(90deg, #ff0000, #0000ff 80%)".parse().unwrap();
+    /// let css = gradient.to_css().unwrap();
+    /// assert_eq!(css, "linear-gradient(90deg, #FF0000, #0000FF 80%)");
+    /// assert_eq!(css.parse::<Gradient>(), Ok(gradient));
+    /// ```
+    pub fn to_css(&self) -> Option<String> {
+        let (function, spread) = match self {
+            Gradient::Linear(gradient) => (
+                format!(
+                    "linear-gradient({}deg, {})",
+                    format_number(gradient.angle.to_degrees()),
+                    format_color_stops(&gradient.stops)
+                ),
+                gradient.layer.spread,
+            ),
+            Gradient::Radial(gradient) => (
+                format!(
+                    "radial-gradient({}{}, {})",
+                    format_radial_shape(gradient.shape),
+                    format_at_position(gradient.position),
+                    format_color_stops(&gradient.stops)
+                ),
+                gradient.layer.spread,
+            ),
+            Gradient::Conic(gradient) => (
+                format!(
+                    "conic-gradient(from {}deg{}, {})",
+                    format_number(gradient.start.to_degrees()),
+                    format_at_position(gradient.position),
+                    format_angular_color_stops(&gradient.stops)
+                ),
+                gradient.layer.spread,
+            ),
+            Gradient::Inset(_) | Gradient::Plasma(_) => return None,
+        };
+        Some(match spread {
+            SpreadMethod::Repeat => format!("repeating-{function}"),
+            SpreadMethod::Pad | SpreadMethod::Reflect => function,
+        })
+    }
+}
+
+impl BackgroundGradient {
+    /// Formats the gradients as the value of a CSS `background-image` property, with the top layer first.
+    ///
+    /// Returns `None` if any of the gradients has no CSS equivalent. See [`Gradient::to_css`].
+    pub fn to_css(&self) -> Option<String> {
+        let layers = self
+            .0
+            .iter()
+            .rev()
+            .map(Gradient::to_css)
+            .collect::<Option<Vec<_>>>()?;
+        Some(layers.join(", "))
+    }
+}
+
+/// Formats a number without a trailing `.0`, rounded to four decimal places
+fn format_number(value: f32) -> String {
+    let value = (value * 10_000.).round() / 10_000.;
+    // Avoid writing `-0`
+    format!("{}", value + 0.)
+}
+
+fn format_val(val: Val) -> Option<String> {
+    let (value, unit) = match val {
+        Val::Auto => return None,
+        Val::Px(value) => (value, "px"),
+        Val::Percent(value) => (value, "%"),
+        Val::Vw(value) => (value, "vw"),
+        Val::Vh(value) => (value, "vh"),
+        Val::VMin(value) => (value, "vmin"),
+        Val::VMax(value) => (value, "vmax"),
+    };
+    Some(format!("{}{unit}", format_number(value)))
+}
+
+fn format_color(color: Color) -> String {
+    Srgba::from(color).to_hex()
+}
+
+/// Interpolates between two `Val`s with the same unit
+fn lerp_same_unit(a: Val, b: Val, t: f32) -> Option<Val> {
+    Some(match (a, b) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(a.lerp(b, t)),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(a.lerp(b, t)),
+        (Val::Vw(a), Val::Vw(b)) => Val::Vw(a.lerp(b, t)),
+        (Val::Vh(a), Val::Vh(b)) => Val::Vh(a.lerp(b, t)),
+        (Val::VMin(a), Val::VMin(b)) => Val::VMin(a.lerp(b, t)),
+        (Val::VMax(a), Val::VMax(b)) => Val::VMax(a.lerp(b, t)),
+        _ => return None,
+    })
+}
+
+fn format_color_stops(stops: &[ColorStop]) -> String {
+    let mut items = vec![];
+    for (i, stop) in stops.iter().enumerate() {
+        let mut item = format_color(stop.color);
+        if let Some(point) = format_val(stop.point) {
+            item = format!("{item} {point}");
+            if let Some(end_point) = stop.end_point.and_then(format_val) {
+                item = format!("{item} {end_point}");
+            }
+        }
+        items.push(item);
+
+        let Some(next) = stops.get(i + 1) else {
+            continue;
+        };
+        let hint = match stop.hint_point {
+            Some(hint_point) => Some(hint_point),
+            None if stop.hint != 0.5 => {
+                lerp_same_unit(stop.end_point.unwrap_or(stop.point), next.point, stop.hint)
+            }
+            None => None,
+        };
+        items.extend(hint.and_then(format_val));
+    }
+    items.join(", ")
+}
+
+fn format_angular_color_stops(stops: &[AngularColorStop]) -> String {
+    let mut items = vec![];
+    for (i, stop) in stops.iter().enumerate() {
+        let angle = match stop.angle {
+            AngularPosition::Auto => None,
+            AngularPosition::Radians(radians) => {
+                Some(format!("{}deg", format_number(radians.to_degrees())))
+            }
+            AngularPosition::Percent(percent) => Some(format!("{}%", format_number(percent))),
+            AngularPosition::Turns(turns) => Some(format!("{}turn", format_number(turns))),
+        };
+        let color = format_color(stop.color);
+        items.push(match angle {
+            Some(angle) => format!("{color} {angle}"),
+            None => color,
+        });
+
+        if stop.hint != 0.5 {
+            let end = stops.get(i + 1).and_then(|next| next.angle.resolve());
+            if let (Some(start), Some(end)) = (stop.angle.resolve(), end) {
+                let hint = start.lerp(end, stop.hint);
+                items.push(format!("{}deg", format_number(hint.to_degrees())));
+            }
+        }
+    }
+    items.join(", ")
+}
+
+fn format_radial_shape(shape: RadialGradientShape) -> String {
+    let extent = |shape: &str, extent: &str| format!("{shape} {extent}");
+    match shape {
+        RadialGradientShape::ClosestSide => extent("circle", "closest-side"),
+        RadialGradientShape::FarthestSide => extent("circle", "farthest-side"),
+        // The corner variants are ellipses reaching the sides on each axis
+        RadialGradientShape::ClosestCorner => extent("ellipse", "closest-side"),
+        RadialGradientShape::FarthestCorner => extent("ellipse", "farthest-side"),
+        RadialGradientShape::Circle(radius) => match format_val(radius) {
+            Some(radius) => format!("circle {radius}"),
+            None => extent("circle", "farthest-corner"),
+        },
+        RadialGradientShape::Ellipse(x, y) => match (format_val(x), format_val(y)) {
+            (Some(x), Some(y)) => format!("{x} {y}"),
+            _ => extent("ellipse", "farthest-side"),
+        },
+        RadialGradientShape::Extent { shape, extent } => format!(
+            "{} {}",
+            match shape {
+                EndShape::Circle => "circle",
+                EndShape::Ellipse => "ellipse",
+            },
+            match extent {
+                RadialExtent::ClosestSide => "closest-side",
+                RadialExtent::FarthestSide => "farthest-side",
+                RadialExtent::ClosestCorner => "closest-corner",
+                RadialExtent::FarthestCorner => "farthest-corner",
+            }
+        ),
+    }
+}
+
+/// Formats a position following `at`, or nothing for the center.
+/// Positions are written as keywords, each followed by an offset towards the center.
+/// Anchors between the edges and the center are converted to percentages from the left or top.
+fn format_at_position(position: Position) -> String {
+    if position == Position::CENTER {
+        return String::new();
+    }
+    let axis = |anchor: f32, offset: Val, start: &str, end: &str| -> Vec<String> {
+        let zero = matches!(offset, Val::Auto) || offset == Val::ZERO || offset == Val::Percent(0.);
+        if anchor == -0.5 || anchor == 0.5 {
+            let keyword = if anchor < 0. { start } else { end };
+            return match format_val(offset).filter(|_| !zero) {
+                Some(offset) => vec![keyword.to_string(), offset],
+                None => vec![keyword.to_string()],
+            };
+        }
+        if anchor == 0. && zero {
+            return vec!["center".to_string()];
+        }
+        // Percentage offsets can be added to the anchor, other offsets are dropped
+        let percent = match offset {
+            Val::Percent(percent) => percent,
+            _ => 0.,
+        };
+        vec![
+            start.to_string(),
+            format!("{}%", format_number(100. * (anchor + 0.5) + percent)),
+        ]
+    };
+    let mut tokens = axis(position.anchor.x, position.x, "left", "right");
+    tokens.extend(axis(position.anchor.y, position.y, "top", "bottom"));
+    format!(" at {}", tokens.join(" "))
+}
+
+const NAMED_COLORS: &[(&str, Srgba)] = &[
+    ("aliceblue", css::ALICE_BLUE),
+    ("antiquewhite", css::ANTIQUE_WHITE),
+    ("aqua", css::AQUA),
+    ("aquamarine", css::AQUAMARINE),
+    ("azure", css::AZURE),
+    ("beige", css::BEIGE),
+    ("bisque", css::BISQUE),
+    ("black", basic::BLACK),
+    ("blanchedalmond", css::BLANCHED_ALMOND),
+    ("blue", basic::BLUE),
+    ("blueviolet", css::BLUE_VIOLET),
+    ("brown", css::BROWN),
+    ("burlywood", css::BURLYWOOD),
+    ("cadetblue", css::CADET_BLUE),
+    ("chartreuse", css::CHARTREUSE),
+    ("chocolate", css::CHOCOLATE),
+    ("coral", css::CORAL),
+    ("cornflowerblue", css::CORNFLOWER_BLUE),
+    ("cornsilk", css::CORNSILK),
+    ("crimson", css::CRIMSON),
+    ("cyan", css::AQUA),
+    ("darkblue", css::DARK_BLUE),
+    ("darkcyan", css::DARK_CYAN),
+    ("darkgoldenrod", css::DARK_GOLDENROD),
+    ("darkgray", css::DARK_GRAY),
+    ("darkgreen", css::DARK_GREEN),
+    ("darkgrey", css::DARK_GREY),
+    ("darkkhaki", css::DARK_KHAKI),
+    ("darkmagenta", css::DARK_MAGENTA),
+    ("darkolivegreen", css::DARK_OLIVEGREEN),
+    ("darkorange", css::DARK_ORANGE),
+    ("darkorchid", css::DARK_ORCHID),
+    ("darkred", css::DARK_RED),
+    ("darksalmon", css::DARK_SALMON),
+    ("darkseagreen", css::DARK_SEA_GREEN),
+    ("darkslateblue", css::DARK_SLATE_BLUE),
+    ("darkslategray", css::DARK_SLATE_GRAY),
+    ("darkslategrey", css::DARK_SLATE_GREY),
+    ("darkturquoise", css::DARK_TURQUOISE),
+    ("darkviolet", css::DARK_VIOLET),
+    ("deeppink", css::DEEP_PINK),
+    ("deepskyblue", css::DEEP_SKY_BLUE),
+    ("dimgray", css::DIM_GRAY),
+    ("dimgrey", css::DIM_GREY),
+    ("dodgerblue", css::DODGER_BLUE),
+    ("firebrick", css::FIRE_BRICK),
+    ("floralwhite", css::FLORAL_WHITE),
+    ("forestgreen", css::FOREST_GREEN),
+    ("fuchsia", basic::FUCHSIA),
+    ("gainsboro", css::GAINSBORO),
+    ("ghostwhite", css::GHOST_WHITE),
+    ("gold", css::GOLD),
+    ("goldenrod", css::GOLDENROD),
+    ("gray", basic::GRAY),
+    ("green", basic::GREEN),
+    ("greenyellow", css::GREEN_YELLOW),
+    ("grey", css::GREY),
+    ("honeydew", css::HONEYDEW),
+    ("hotpink", css::HOT_PINK),
+    ("indianred", css::INDIAN_RED),
+    ("indigo", css::INDIGO),
+    ("ivory", css::IVORY),
+    ("khaki", css::KHAKI),
+    ("lavender", css::LAVENDER),
+    ("lavenderblush", css::LAVENDER_BLUSH),
+    ("lawngreen", css::LAWN_GREEN),
+    ("lemonchiffon", css::LEMON_CHIFFON),
+    ("lightblue", css::LIGHT_BLUE),
+    ("lightcoral", css::LIGHT_CORAL),
+    ("lightcyan", css::LIGHT_CYAN),
+    ("lightgoldenrodyellow", css::LIGHT_GOLDENROD_YELLOW),
+    ("lightgray", css::LIGHT_GRAY),
+    ("lightgreen", css::LIGHT_GREEN),
+    ("lightgrey", css::LIGHT_GREY),
+    ("lightpink", css::LIGHT_PINK),
+    ("lightsalmon", css::LIGHT_SALMON),
+    ("lightseagreen", css::LIGHT_SEA_GREEN),
+    ("lightskyblue", css::LIGHT_SKY_BLUE),
+    ("lightslategray", css::LIGHT_SLATE_GRAY),
+    ("lightslategrey", css::LIGHT_SLATE_GREY),
+    ("lightsteelblue", css::LIGHT_STEEL_BLUE),
+    ("lightyellow", css::LIGHT_YELLOW),
+    ("lime", basic::LIME),
+    ("limegreen", css::LIMEGREEN),
+    ("linen", css::LINEN),
+    ("magenta", css::MAGENTA),
+    ("maroon", basic::MAROON),
+    ("mediumaquamarine", css::MEDIUM_AQUAMARINE),
+    ("mediumblue", css::MEDIUM_BLUE),
+    ("mediumorchid", css::MEDIUM_ORCHID),
+    ("mediumpurple", css::MEDIUM_PURPLE),
+    ("mediumseagreen", css::MEDIUM_SEA_GREEN),
+    ("mediumslateblue", css::MEDIUM_SLATE_BLUE),
+    ("mediumspringgreen", css::MEDIUM_SPRING_GREEN),
+    ("mediumturquoise", css::MEDIUM_TURQUOISE),
+    ("mediumvioletred", css::MEDIUM_VIOLET_RED),
+    ("midnightblue", css::MIDNIGHT_BLUE),
+    ("mintcream", css::MINT_CREAM),
+    ("mistyrose", css::MISTY_ROSE),
+    ("moccasin", css::MOCCASIN),
+    ("navajowhite", css::NAVAJO_WHITE),
+    ("navy", basic::NAVY),
+    ("oldlace", css::OLD_LACE),
+    ("olive", basic::OLIVE),
+    ("olivedrab", css::OLIVE_DRAB),
+    ("orange", css::ORANGE),
+    ("orangered", css::ORANGE_RED),
+    ("orchid", css::ORCHID),
+    ("palegoldenrod", css::PALE_GOLDENROD),
+    ("palegreen", css::PALE_GREEN),
+    ("paleturquoise", css::PALE_TURQUOISE),
+    ("palevioletred", css::PALE_VIOLETRED),
+    ("papayawhip", css::PAPAYA_WHIP),
+    ("peachpuff", css::PEACHPUFF),
+    ("peru", css::PERU),
+    ("pink", css::PINK),
+    ("plum", css::PLUM),
+    ("powderblue", css::POWDER_BLUE),
+    ("purple", basic::PURPLE),
+    ("rebeccapurple", css::REBECCA_PURPLE),
+    ("red", basic::RED),
+    ("rosybrown", css::ROSY_BROWN),
+    ("royalblue", css::ROYAL_BLUE),
+    ("saddlebrown", css::SADDLE_BROWN),
+    ("salmon", css::SALMON),
+    ("sandybrown", css::SANDY_BROWN),
+    ("seagreen", css::SEA_GREEN),
+    ("seashell", css::SEASHELL),
+    ("sienna", css::SIENNA),
+    ("silver", basic::SILVER),
+    ("skyblue", css::SKY_BLUE),
+    ("slateblue", css::SLATE_BLUE),
+    ("slategray", css::SLATE_GRAY),
+    ("slategrey", css::SLATE_GREY),
+    ("snow", css::SNOW),
+    ("springgreen", css::SPRING_GREEN),
+    ("steelblue", css::STEEL_BLUE),
+    ("tan", css::TAN),
+    ("teal", basic::TEAL),
+    ("thistle", css::THISTLE),
+    ("tomato", css::TOMATO),
+    ("turquoise", css::TURQUOISE),
+    ("violet", css::VIOLET),
+    ("wheat", css::WHEAT),
+    ("white", basic::WHITE),
+    ("whitesmoke", css::WHITE_SMOKE),
+    ("yellow", basic::YELLOW),
+    ("yellowgreen", css::YELLOW_GREEN),
+];
@@ -0,0 +1,62 @@
+use crate::*;
+use bevy::{
+    asset::{Assets, Handle, weak_handle},
+    ecs::resource::Resource,
+    render::{RenderApp, render_resource::Shader},
+};
+
+/// The shader of the custom gradient function, with the import path `bevy_ui_gradients::custom_gradient`
+pub const CUSTOM_GRADIENT_FUNCTION_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("e3a9c1d2-6b4f-4f7e-9a0c-2d8b5e7f1c64");
+
+/// Registers a WGSL function that remaps the distances of gradients, for spirals, waves and other
+/// exotic gradients that can't be described by the linear, radial and conic shapes.
+///
+/// The WGSL source must define a function with the signature:
+///
+/// ```wgsl
+/// fn custom_gradient(id: u32, uv: vec2<f32>, t: f32) -> f32
+/// ```
+///
+/// It's called for gradients with a `custom_function` id set, after the gradient's distance `t` is
+/// computed and before the color stops are interpolated, so custom gradients keep their color stops,
+/// spread method and batching. `id` is the gradient's id, so one function can implement several
+/// gradients. `uv` is the position in the gradient's box, from `(0., 0.)` at the top left to
+/// `(1., 1.)` at the bottom right. `t` is the distance along the gradient line in physical pixels,
+/// or the angle in radians for conic gradients, and the function returns the remapped distance.
+///
+/// Ids must be less than `4096`. Only one custom gradient function can be registered, and it isn't
+/// evaluated by the CPU sampling API.
+///
+/// Like [`UiGradientsPlugin`], it must be added after `DefaultPlugins`.
+pub struct CustomGradientFunctionPlugin {
+    /// The WGSL source of the `custom_gradient` function and any functions it calls
+    pub wgsl: String,
+}
+
+impl CustomGradientFunctionPlugin {
+    /// Registers the WGSL source of the `custom_gradient` function
+    pub fn new(wgsl: impl Into<String>) -> Self {
+        Self { wgsl: wgsl.into() }
+    }
+}
+
+impl Plugin for CustomGradientFunctionPlugin {
+    fn build(&self, app: &mut App) {
+        let source = format!(
+            "#define_import_path bevy_ui_gradients::custom_gradient\n\n{}",
+            self.wgsl
+        );
+        app.world_mut().resource_mut::<Assets<Shader>>().insert(
+            &CUSTOM_GRADIENT_FUNCTION_SHADER_HANDLE,
+            Shader::from_wgsl(source, "bevy_ui_gradients/custom_gradient.wgsl"),
+        );
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.insert_resource(CustomGradientFunction);
+        }
+    }
+}
+
+/// Present in the render world if a custom gradient function is registered
+#[derive(Resource)]
+pub struct CustomGradientFunction;
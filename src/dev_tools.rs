@@ -0,0 +1,461 @@
+use crate::*;
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::asset::io::file::FileAssetReader;
+use bevy::{
+    asset::Assets,
+    color::Alpha,
+    ecs::{
+        entity::Entity,
+        hierarchy::ChildOf,
+        query::With,
+        reflect::AppTypeRegistry,
+        resource::Resource,
+        system::{Commands, Query, Res, ResMut, Single},
+    },
+    input::{ButtonInput, keyboard::KeyCode, mouse::MouseButton},
+    log::{info, warn},
+    picking::{Pickable, hover::HoverMap, pointer::PointerId},
+    prelude::ReflectResource,
+    text::{TextColor, TextFont},
+    ui::{BackgroundColor, GlobalZIndex, Node, PositionType, UiRect, widget::Text},
+};
+use core::{fmt::Write, task::Poll};
+use std::path::PathBuf;
+
+/// Adds developer tools for picking gradient nodes at runtime and nudging their gradients,
+/// for polishing screens without recompiling.
+///
+/// Pressing [`GradientDevTools::toggle_key`] turns the tools on and off. While they're on, clicking a node
+/// selects the nearest node with a [`BackgroundGradient`] under the cursor, or one of its ancestors.
+/// An overlay shows the selected node's stack of gradients and the stop table of the selected layer.
+///
+/// With a node selected:
+/// * `[` and `]` select the previous and next gradient layer.
+/// * Up and Down select the previous and next stop.
+/// * Left and Right move the selected stop. Stops with automatic positions can't be moved.
+/// * `,` and `.` turn linear gradients and the start of conic gradients anticlockwise and clockwise.
+/// * Holding Shift makes the steps larger.
+/// * Ctrl+C copies the selected gradient to the [`GradientClipboard`] as CSS.
+/// * Ctrl+V pastes CSS from the [`GradientClipboard`]. A single gradient replaces the selected layer,
+///   a `background` value with several layers replaces the whole stack.
+/// * Ctrl+S writes the node's gradients back to the `.grad.ron` file of its [`GradientSource`], on native targets.
+/// * Escape clears the selection.
+///
+/// Clicks still reach the UI under the cursor while the tools are on.
+/// Like [`UiGradientsPlugin`], it must be added after `DefaultPlugins`.
+#[derive(Default)]
+pub struct GradientDevToolsPlugin;
+
+impl Plugin for GradientDevToolsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GradientDevTools>()
+            .init_resource::<GradientClipboard>()
+            .add_systems(
+                Update,
+                (
+                    toggle_gradient_dev_tools,
+                    pick_gradient_dev_tools_target,
+                    nudge_gradient_dev_tools_target,
+                    copy_paste_gradient_dev_tools_target,
+                    #[cfg(not(target_arch = "wasm32"))]
+                    save_gradient_dev_tools_target,
+                    update_gradient_dev_tools_overlay,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// The state of the gradient developer tools added by [`GradientDevToolsPlugin`]
+#[derive(Resource, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct GradientDevTools {
+    /// If true, clicks select gradient nodes and the overlay is shown
+    pub enabled: bool,
+    /// The key that turns the tools on and off
+    pub toggle_key: KeyCode,
+    /// The selected node
+    pub target: Option<Entity>,
+    /// The index of the selected gradient in the node's [`BackgroundGradient`]
+    pub layer: usize,
+    /// The index of the selected stop
+    pub stop: usize,
+    /// The distance a stop is moved by a key press, in its own units.
+    /// Angular stops are moved by this many degrees.
+    pub stop_step: f32,
+    /// The angle gradients are turned by a key press, in degrees
+    pub angle_step: f32,
+    /// The steps are multiplied by this while Shift is held
+    pub shift_multiplier: f32,
+    /// The folder [`GradientSource`] asset paths are relative to when saving.
+    /// Defaults to the `assets` folder that bevy's `AssetPlugin` reads from.
+    pub asset_folder: PathBuf,
+}
+
+impl Default for GradientDevTools {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_key: KeyCode::F2,
+            target: None,
+            layer: 0,
+            stop: 0,
+            stop_step: 1.,
+            angle_step: 1.,
+            shift_multiplier: 10.,
+            #[cfg(not(target_arch = "wasm32"))]
+            asset_folder: FileAssetReader::get_base_path().join("assets"),
+            #[cfg(target_arch = "wasm32")]
+            asset_folder: PathBuf::from("assets"),
+        }
+    }
+}
+
+/// Marks the overlay text of the gradient developer tools
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct GradientDevToolsOverlay;
+
+/// Turns the gradient developer tools on and off when the toggle key is pressed
+pub fn toggle_gradient_dev_tools(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut dev_tools: ResMut<GradientDevTools>,
+) {
+    if keys.just_pressed(dev_tools.toggle_key) {
+        dev_tools.enabled = !dev_tools.enabled;
+        if !dev_tools.enabled {
+            dev_tools.target = None;
+        }
+    }
+}
+
+/// Selects the nearest gradient node under the mouse when it's clicked while the tools are on
+pub fn pick_gradient_dev_tools_target(
+    mouse: Res<ButtonInput<MouseButton>>,
+    hover_map: Option<Res<HoverMap>>,
+    mut dev_tools: ResMut<GradientDevTools>,
+    parents: Query<&ChildOf>,
+    gradients: Query<(&BackgroundGradient, &ComputedNode)>,
+) {
+    if !dev_tools.enabled || !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(hovered) = hover_map
+        .as_ref()
+        .and_then(|hover_map| hover_map.get(&PointerId::Mouse))
+    else {
+        return;
+    };
+
+    let target = hovered.keys().find_map(|&entity| {
+        core::iter::once(entity)
+            .chain(parents.iter_ancestors(entity))
+            .find(|&entity| gradients.contains(entity))
+    });
+    let Some(target) = target else {
+        return;
+    };
+
+    dev_tools.target = Some(target);
+    dev_tools.layer = 0;
+    dev_tools.stop = 0;
+    if let Ok((background, node)) = gradients.get(target) {
+        for (i, gradient) in background.0.iter().enumerate() {
+            info!(
+                "Gradient layer {i} of {target}:\n{}",
+                gradient.stop_table(node.size())
+            );
+        }
+    }
+}
+
+/// Applies the selection and nudge keys to the selected gradient node
+pub fn nudge_gradient_dev_tools_target(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut dev_tools: ResMut<GradientDevTools>,
+    mut gradients: Query<&mut BackgroundGradient>,
+) {
+    let Some(target) = dev_tools.target.filter(|_| dev_tools.enabled) else {
+        return;
+    };
+    let Ok(mut background) = gradients.get_mut(target) else {
+        dev_tools.target = None;
+        return;
+    };
+    if keys.just_pressed(KeyCode::Escape) {
+        dev_tools.target = None;
+        return;
+    }
+
+    let layers = background.0.len();
+    if keys.just_pressed(KeyCode::BracketLeft) {
+        dev_tools.layer = dev_tools.layer.saturating_sub(1);
+        dev_tools.stop = 0;
+    }
+    if keys.just_pressed(KeyCode::BracketRight) {
+        dev_tools.layer = (dev_tools.layer + 1).min(layers.saturating_sub(1));
+        dev_tools.stop = 0;
+    }
+    let Some(stops) = background
+        .0
+        .get(dev_tools.layer)
+        .map(Gradient::authored_stops_len)
+    else {
+        return;
+    };
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        dev_tools.stop = dev_tools.stop.saturating_sub(1);
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        dev_tools.stop = (dev_tools.stop + 1).min(stops.saturating_sub(1));
+    }
+
+    let multiplier = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        dev_tools.shift_multiplier
+    } else {
+        1.
+    };
+    let direction = |decrease: KeyCode, increase: KeyCode| {
+        f32::from(keys.just_pressed(increase)) - f32::from(keys.just_pressed(decrease))
+    };
+
+    let stop_delta =
+        multiplier * dev_tools.stop_step * direction(KeyCode::ArrowLeft, KeyCode::ArrowRight);
+    if stop_delta != 0. {
+        nudge_stop(
+            &mut background.0[dev_tools.layer],
+            dev_tools.stop,
+            stop_delta,
+        );
+    }
+
+    let angle_delta =
+        Angle::deg(multiplier * dev_tools.angle_step * direction(KeyCode::Comma, KeyCode::Period));
+    if angle_delta != Angle::ZERO {
+        match &mut background.0[dev_tools.layer] {
+            Gradient::Linear(gradient) => gradient.angle += angle_delta,
+            Gradient::Conic(gradient) => gradient.start += angle_delta,
+            _ => {}
+        }
+    }
+}
+
+/// Copies the selected gradient to the [`GradientClipboard`] as CSS on Ctrl+C, and pastes CSS over it on Ctrl+V.
+/// Pasted text is applied once it has been read from the system clipboard, usually a frame or two later.
+pub fn copy_paste_gradient_dev_tools_target(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut dev_tools: ResMut<GradientDevTools>,
+    mut clipboard: ResMut<GradientClipboard>,
+    mut gradients: Query<&mut BackgroundGradient>,
+) {
+    let Some(target) = dev_tools.target.filter(|_| dev_tools.enabled) else {
+        return;
+    };
+    let Ok(mut background) = gradients.get_mut(target) else {
+        return;
+    };
+
+    if let Poll::Ready(pasted) = clipboard.poll_paste_gradients() {
+        match pasted {
+            Some(Ok(pasted)) => {
+                if pasted.0.len() == 1 && dev_tools.layer < background.0.len() {
+                    background.0[dev_tools.layer] = pasted.0[0].clone();
+                } else {
+                    *background = pasted;
+                    dev_tools.layer = 0;
+                }
+                dev_tools.stop = 0;
+            }
+            Some(Err(error)) => warn!("Failed to paste gradient: {error}"),
+            None => warn!("Failed to paste gradient: the clipboard is empty"),
+        }
+    }
+
+    // Command on macOS
+    if !keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]) {
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::KeyC) {
+        if let Some(gradient) = background.0.get(dev_tools.layer) {
+            match clipboard.copy_gradient(gradient) {
+                Some(css) => info!(
+                    "Copied gradient layer {} of {target}: {css}",
+                    dev_tools.layer
+                ),
+                None => warn!(
+                    "Gradient layer {} of {target} has no CSS equivalent",
+                    dev_tools.layer
+                ),
+            }
+        }
+    }
+
+    if keys.just_pressed(KeyCode::KeyV) {
+        clipboard.request_paste();
+    }
+}
+
+/// Writes the selected node's gradients back to the file of its [`GradientSource`] on Ctrl+S.
+/// The loaded asset is updated too, so other nodes displaying it pick up the edits.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_gradient_dev_tools_target(
+    keys: Res<ButtonInput<KeyCode>>,
+    dev_tools: Res<GradientDevTools>,
+    registry: Res<AppTypeRegistry>,
+    mut assets: ResMut<Assets<GradientAsset>>,
+    sources: Query<(&GradientSource, &BackgroundGradient)>,
+) {
+    let Some(target) = dev_tools.target.filter(|_| dev_tools.enabled) else {
+        return;
+    };
+    // Command on macOS
+    if !keys.just_pressed(KeyCode::KeyS)
+        || !keys.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ])
+    {
+        return;
+    }
+    let Ok((source, background)) = sources.get(target) else {
+        warn!("Can't save {target}, its gradients weren't loaded from a file");
+        return;
+    };
+    let Some(asset_path) = source.0.path() else {
+        warn!("Can't save {target}, its gradient asset has no path");
+        return;
+    };
+
+    let path = dev_tools.asset_folder.join(asset_path.path());
+    let asset = GradientAsset(background.clone());
+    match asset.save(&path, &registry.read()) {
+        Ok(()) => {
+            info!("Saved the gradients of {target} to {}", path.display());
+            if let Some(loaded) = assets.get_mut(&source.0) {
+                *loaded = asset;
+            }
+        }
+        Err(error) => warn!("Failed to save the gradients of {target}: {error}"),
+    }
+}
+
+/// Moves a stop by `delta` in its own units, or by `delta` degrees for angular stops
+fn nudge_stop(gradient: &mut Gradient, stop: usize, delta: f32) {
+    match gradient {
+        Gradient::Linear(LinearGradient { stops, .. })
+        | Gradient::Radial(RadialGradient { stops, .. })
+        | Gradient::Inset(InsetGradient { stops, .. })
+        | Gradient::Plasma(PlasmaGradient { stops, .. }) => {
+            let Some(stop) = stops.get_mut(stop) else {
+                return;
+            };
+            stop.point = nudge_val(stop.point, delta);
+            stop.end_point = stop.end_point.map(|end_point| nudge_val(end_point, delta));
+        }
+        Gradient::Conic(gradient) => {
+            if let Some(stop) = gradient.stops.get_mut(stop) {
+                if let Some(angle) = stop.angle.resolve() {
+                    stop.angle = AngularPosition::Radians(angle + delta.to_radians());
+                }
+            }
+        }
+    }
+}
+
+fn nudge_val(val: Val, delta: f32) -> Val {
+    match val {
+        Val::Px(value) => Val::Px(value + delta),
+        Val::Percent(value) => Val::Percent(value + delta),
+        Val::Vw(value) => Val::Vw(value + delta),
+        Val::Vh(value) => Val::Vh(value + delta),
+        Val::VMin(value) => Val::VMin(value + delta),
+        Val::VMax(value) => Val::VMax(value + delta),
+        Val::Auto => Val::Auto,
+    }
+}
+
+/// Spawns, updates and despawns the overlay text of the gradient developer tools
+pub fn update_gradient_dev_tools_overlay(
+    mut commands: Commands,
+    dev_tools: Res<GradientDevTools>,
+    overlay: Option<Single<(Entity, &mut Text), With<GradientDevToolsOverlay>>>,
+    gradients: Query<(&BackgroundGradient, &ComputedNode)>,
+) {
+    if !dev_tools.enabled {
+        if let Some(overlay) = overlay {
+            commands.entity(overlay.0).despawn();
+        }
+        return;
+    }
+
+    let mut text = String::new();
+    match dev_tools
+        .target
+        .and_then(|target| Some((target, gradients.get(target).ok()?)))
+    {
+        Some((target, (background, node))) => {
+            let _ = writeln!(text, "Gradient dev tools: {target}");
+            let _ = writeln!(
+                text,
+                "[ ] layer, up/down stop, left/right move stop, , . turn, shift for larger steps, esc deselect"
+            );
+            for (i, gradient) in background.0.iter().enumerate() {
+                let marker = if i == dev_tools.layer { ">" } else { " " };
+                let _ = match gradient {
+                    Gradient::Linear(gradient) => writeln!(
+                        text,
+                        "{marker} {i}: linear {:.1}deg",
+                        gradient.angle.to_degrees()
+                    ),
+                    Gradient::Conic(gradient) => writeln!(
+                        text,
+                        "{marker} {i}: conic from {:.1}deg",
+                        gradient.start.to_degrees()
+                    ),
+                    Gradient::Radial(_) => writeln!(text, "{marker} {i}: radial"),
+                    Gradient::Inset(_) => writeln!(text, "{marker} {i}: inset"),
+                    Gradient::Plasma(_) => writeln!(text, "{marker} {i}: plasma"),
+                };
+            }
+            if let Some(gradient) = background.0.get(dev_tools.layer) {
+                let _ = writeln!(text, "\nselected stop: {}", dev_tools.stop);
+                let _ = write!(text, "{}", gradient.stop_table(node.size()));
+            }
+        }
+        None => text.push_str("Gradient dev tools: click a gradient node to select it"),
+    }
+
+    match overlay {
+        Some(mut overlay) => {
+            if overlay.1.0 != text {
+                overlay.1.0 = text;
+            }
+        }
+        None => {
+            commands.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.),
+                    right: Val::Px(8.),
+                    padding: UiRect::all(Val::Px(8.)),
+                    ..Default::default()
+                },
+                Text(text),
+                TextFont::from_font_size(14.),
+                TextColor(Color::WHITE),
+                BackgroundColor(Color::BLACK.with_alpha(0.75)),
+                GlobalZIndex(i32::MAX),
+                Pickable::IGNORE,
+                GradientDevToolsOverlay,
+            ));
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::*;
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    ecs::{resource::Resource, system::Res},
+    render::RenderApp,
+};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+/// Adds [`Diagnostic`]s measuring the gradient renderer, so performance regressions in gradient heavy UIs
+/// can be tracked in game, for example with `LogDiagnosticsPlugin`.
+///
+/// The measurements are recorded by the render world as the gradients are prepared, and added to the
+/// diagnostics in `Update`, so they lag the frame being rendered by a frame or two with pipelined rendering.
+///
+/// Like [`UiGradientsPlugin`], it must be added after `DefaultPlugins`.
+#[derive(Default)]
+pub struct GradientDiagnosticsPlugin;
+
+impl GradientDiagnosticsPlugin {
+    /// The number of extracted gradient items, one for each gradient layer, mask, lookup texture, stroke and glow
+    pub const EXTRACTED_GRADIENTS: DiagnosticPath =
+        DiagnosticPath::const_new("ui_gradients/extracted_gradients");
+    /// The number of resolved color stops uploaded to the GPU
+    pub const COLOR_STOPS: DiagnosticPath = DiagnosticPath::const_new("ui_gradients/color_stops");
+    /// The number of gradient batches drawn
+    pub const BATCHES: DiagnosticPath = DiagnosticPath::const_new("ui_gradients/batches");
+    /// The size of the prepared vertex and index buffers in bytes
+    pub const BUFFER_BYTES: DiagnosticPath = DiagnosticPath::const_new("ui_gradients/buffer_bytes");
+}
+
+impl Plugin for GradientDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let stats = GradientRenderStats::default();
+        app.register_diagnostic(Diagnostic::new(Self::EXTRACTED_GRADIENTS))
+            .register_diagnostic(Diagnostic::new(Self::COLOR_STOPS))
+            .register_diagnostic(Diagnostic::new(Self::BATCHES))
+            .register_diagnostic(Diagnostic::new(Self::BUFFER_BYTES).with_suffix("B"))
+            .insert_resource(stats.clone())
+            .add_systems(Update, update_gradient_diagnostics);
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.insert_resource(stats);
+        }
+    }
+}
+
+/// The measurements of the last frame prepared by the gradient renderer, shared between the main world
+/// and the render world by [`GradientDiagnosticsPlugin`].
+#[derive(Resource, Clone, Default, Debug)]
+pub struct GradientRenderStats(Arc<GradientRenderCounters>);
+
+#[derive(Default, Debug)]
+struct GradientRenderCounters {
+    extracted_gradients: AtomicUsize,
+    color_stops: AtomicUsize,
+    batches: AtomicUsize,
+    buffer_bytes: AtomicUsize,
+}
+
+impl GradientRenderStats {
+    /// Records the measurements of a prepared frame
+    pub fn record(
+        &self,
+        extracted_gradients: usize,
+        color_stops: usize,
+        batches: usize,
+        buffer_bytes: usize,
+    ) {
+        self.0
+            .extracted_gradients
+            .store(extracted_gradients, Ordering::Relaxed);
+        self.0.color_stops.store(color_stops, Ordering::Relaxed);
+        self.0.batches.store(batches, Ordering::Relaxed);
+        self.0.buffer_bytes.store(buffer_bytes, Ordering::Relaxed);
+    }
+
+    /// The number of extracted gradient items
+    pub fn extracted_gradients(&self) -> usize {
+        self.0.extracted_gradients.load(Ordering::Relaxed)
+    }
+
+    /// The number of resolved color stops
+    pub fn color_stops(&self) -> usize {
+        self.0.color_stops.load(Ordering::Relaxed)
+    }
+
+    /// The number of gradient batches
+    pub fn batches(&self) -> usize {
+        self.0.batches.load(Ordering::Relaxed)
+    }
+
+    /// The size of the vertex and index buffers in bytes
+    pub fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes.load(Ordering::Relaxed)
+    }
+}
+
+/// Adds the render world's latest [`GradientRenderStats`] to the diagnostics
+pub fn update_gradient_diagnostics(mut diagnostics: Diagnostics, stats: Res<GradientRenderStats>) {
+    diagnostics.add_measurement(&GradientDiagnosticsPlugin::EXTRACTED_GRADIENTS, || {
+        stats.extracted_gradients() as f64
+    });
+    diagnostics.add_measurement(&GradientDiagnosticsPlugin::COLOR_STOPS, || {
+        stats.color_stops() as f64
+    });
+    diagnostics.add_measurement(&GradientDiagnosticsPlugin::BATCHES, || {
+        stats.batches() as f64
+    });
+    diagnostics.add_measurement(&GradientDiagnosticsPlugin::BUFFER_BYTES, || {
+        stats.buffer_bytes() as f64
+    });
+}
@@ -0,0 +1,528 @@
+use crate::*;
+use bevy::{
+    color::palettes::css,
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        hierarchy::{ChildOf, Children},
+        query::{Added, Changed, Without},
+        system::{Commands, Query},
+    },
+    math::ops::atan2,
+    prelude::{ChildSpawnerCommands, DetectChangesMut},
+    ui::{
+        BackgroundColor, BorderColor, BorderRadius, ComputedNode, FlexDirection, FlexWrap,
+        Interaction, Node, PositionType, RelativeCursorPosition, UiRect,
+    },
+};
+use core::f32::consts::TAU;
+
+/// Adds the systems of the [`GradientEditor`] widget.
+///
+/// Only available with the `editor` feature. Like [`UiGradientsPlugin`], it must be added after `DefaultPlugins`.
+#[derive(Default)]
+pub struct GradientEditorPlugin;
+
+impl Plugin for GradientEditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_gradient_editor_parts,
+                edit_gradient_editor_targets,
+                update_gradient_editor_parts,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// A panel of UI nodes that edits one of the gradients of a target entity's [`BackgroundGradient`] live,
+/// for tweaking gradients in tool builds.
+///
+/// The panel has a track showing the gradient's stops as handles, a row of color swatches and an angle dial:
+/// * Pressing a handle selects its stop, dragging it moves the stop between its neighbors.
+/// * Pressing a swatch sets the color of the selected stop.
+/// * Pressing or dragging on the dial sets the angle of a linear gradient or the start angle of a conic gradient.
+///
+/// Moved stops are stored as percentages. Stops with automatic positions or in other units are
+/// shown spaced evenly between their neighbors until they're moved.
+///
+/// The parts of the panel are spawned as children of the editor. Spawn editors with [`gradient_editor`].
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+#[require(Node)]
+pub struct GradientEditor {
+    /// The entity whose [`BackgroundGradient`] is edited
+    pub target: Entity,
+    /// The index of the edited gradient in the target's [`BackgroundGradient`]
+    pub layer: usize,
+    /// The index of the selected stop
+    pub selected: usize,
+    /// The colors of the swatches
+    pub swatches: Vec<Color>,
+}
+
+impl GradientEditor {
+    /// The swatch colors of new editors
+    pub const DEFAULT_SWATCHES: [Color; 12] = [
+        Color::WHITE,
+        Color::BLACK,
+        Color::NONE,
+        Color::Srgba(css::RED),
+        Color::Srgba(css::ORANGE),
+        Color::Srgba(css::YELLOW),
+        Color::Srgba(css::LIME),
+        Color::Srgba(css::TEAL),
+        Color::Srgba(css::AQUA),
+        Color::Srgba(css::BLUE),
+        Color::Srgba(css::PURPLE),
+        Color::Srgba(css::FUCHSIA),
+    ];
+
+    /// An editor for the first gradient of `target`
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            layer: 0,
+            selected: 0,
+            swatches: Self::DEFAULT_SWATCHES.to_vec(),
+        }
+    }
+
+    /// Sets the index of the edited gradient in the target's [`BackgroundGradient`]
+    pub fn with_layer(mut self, layer: usize) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Sets the colors of the swatches
+    pub fn with_swatches(mut self, swatches: impl IntoIterator<Item = impl Into<Color>>) -> Self {
+        self.swatches = swatches.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// The track of a [`GradientEditor`], showing the edited gradient's stops from left to right
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+#[require(Node, BackgroundGradient, Interaction, RelativeCursorPosition)]
+pub struct GradientEditorTrack {
+    /// The editor the track belongs to
+    pub editor: Entity,
+}
+
+/// A handle on a [`GradientEditorTrack`], spawned as a child of the track for each stop
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+#[require(Node, BackgroundColor, BorderColor, Interaction)]
+pub struct GradientEditorHandle {
+    /// The index of the handle's stop
+    pub stop: usize,
+}
+
+/// A color swatch of a [`GradientEditor`]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+#[require(Node, BackgroundColor, BorderColor, Interaction)]
+pub struct GradientEditorSwatch {
+    /// The editor the swatch belongs to
+    pub editor: Entity,
+    /// The color set by pressing the swatch
+    pub color: Color,
+}
+
+/// The angle dial of a [`GradientEditor`], with a needle drawn by a conic gradient
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+#[require(Node, BackgroundGradient, Interaction, RelativeCursorPosition)]
+pub struct GradientEditorDial {
+    /// The editor the dial belongs to
+    pub editor: Entity,
+}
+
+/// A gradient editor panel `width` wide, editing the first gradient of `target`
+pub fn gradient_editor(target: Entity, width: Val) -> impl Bundle {
+    (
+        Node {
+            width,
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.),
+            padding: UiRect::all(Val::Px(8.)),
+            ..Default::default()
+        },
+        BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+        GradientEditor::new(target),
+    )
+}
+
+const HANDLE_WIDTH: f32 = 10.;
+const SWATCH_SIZE: f32 = 20.;
+const DIAL_SIZE: f32 = 64.;
+const SELECTED_COLOR: Color = Color::WHITE;
+const UNSELECTED_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+
+/// Spawns the track, swatches and dial of new [`GradientEditor`]s
+pub fn spawn_gradient_editor_parts(
+    mut commands: Commands,
+    added: Query<(Entity, &GradientEditor), Added<GradientEditor>>,
+) {
+    for (entity, editor) in added.iter() {
+        commands.entity(entity).with_children(|commands| {
+            commands.spawn((
+                Node {
+                    width: Val::Percent(100.),
+                    height: Val::Px(24.),
+                    ..Default::default()
+                },
+                GradientEditorTrack { editor: entity },
+            ));
+            commands
+                .spawn(Node {
+                    flex_wrap: FlexWrap::Wrap,
+                    column_gap: Val::Px(4.),
+                    row_gap: Val::Px(4.),
+                    ..Default::default()
+                })
+                .with_children(|commands| {
+                    for &color in &editor.swatches {
+                        commands.spawn((
+                            Node {
+                                width: Val::Px(SWATCH_SIZE),
+                                height: Val::Px(SWATCH_SIZE),
+                                border: UiRect::all(Val::Px(2.)),
+                                ..Default::default()
+                            },
+                            BackgroundColor(color),
+                            GradientEditorSwatch {
+                                editor: entity,
+                                color,
+                            },
+                        ));
+                    }
+                });
+            commands.spawn((
+                Node {
+                    width: Val::Px(DIAL_SIZE),
+                    height: Val::Px(DIAL_SIZE),
+                    ..Default::default()
+                },
+                BorderRadius::MAX,
+                GradientEditorDial { editor: entity },
+            ));
+        });
+    }
+}
+
+/// Applies the presses and drags on the parts of [`GradientEditor`]s to their targets' gradients
+pub fn edit_gradient_editor_targets(
+    mut editors: Query<&mut GradientEditor>,
+    tracks: Query<(&GradientEditorTrack, &RelativeCursorPosition)>,
+    handles: Query<(&GradientEditorHandle, &Interaction, &ChildOf)>,
+    swatches: Query<(&GradientEditorSwatch, &Interaction), Changed<Interaction>>,
+    dials: Query<(
+        &GradientEditorDial,
+        &Interaction,
+        &RelativeCursorPosition,
+        &ComputedNode,
+    )>,
+    mut targets: Query<
+        &mut BackgroundGradient,
+        (Without<GradientEditorTrack>, Without<GradientEditorDial>),
+    >,
+) {
+    for (handle, interaction, child_of) in handles.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((track, cursor)) = tracks.get(child_of.parent()) else {
+            continue;
+        };
+        let Ok(mut editor) = editors.get_mut(track.editor) else {
+            continue;
+        };
+        if editor.selected != handle.stop {
+            editor.selected = handle.stop;
+        }
+        let Some(position) = cursor.normalized else {
+            continue;
+        };
+        let Ok(mut background) = targets.get_mut(editor.target) else {
+            continue;
+        };
+        let Some(gradient) = background.0.get(editor.layer) else {
+            continue;
+        };
+        let fractions = stop_fractions(gradient);
+        // Keep the stops in order by clamping between the neighbors
+        let min = handle
+            .stop
+            .checked_sub(1)
+            .and_then(|i| fractions.get(i))
+            .copied()
+            .unwrap_or(0.);
+        let max = fractions.get(handle.stop + 1).copied().unwrap_or(1.);
+        let fraction = position.x.clamp(min, max.max(min));
+        if fractions.get(handle.stop) != Some(&fraction) {
+            set_stop_fraction(&mut background.0[editor.layer], handle.stop, fraction);
+        }
+    }
+
+    for (swatch, interaction) in swatches.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(editor) = editors.get(swatch.editor) else {
+            continue;
+        };
+        if let Ok(mut background) = targets.get_mut(editor.target) {
+            if let Some(color) = background
+                .0
+                .get_mut(editor.layer)
+                .and_then(|gradient| gradient.colors_mut().nth(editor.selected))
+            {
+                *color = swatch.color;
+            }
+        }
+    }
+
+    for (dial, interaction, cursor, node) in dials.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(position) = cursor.normalized else {
+            continue;
+        };
+        let Ok(editor) = editors.get(dial.editor) else {
+            continue;
+        };
+        let Ok(mut background) = targets.get_mut(editor.target) else {
+            continue;
+        };
+        let point = (position - 0.5) * node.size();
+        // Gradient angles start at the top and turn clockwise
+        let angle = Angle::rad(atan2(point.x, -point.y)).normalized();
+        match background.0.get(editor.layer) {
+            Some(Gradient::Linear(gradient)) if gradient.angle != angle => {
+                if let Gradient::Linear(gradient) = &mut background.0[editor.layer] {
+                    gradient.angle = angle;
+                }
+            }
+            Some(Gradient::Conic(gradient)) if gradient.start != angle => {
+                if let Gradient::Conic(gradient) = &mut background.0[editor.layer] {
+                    gradient.start = angle;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Updates the tracks, handles, swatches and dials of [`GradientEditor`]s to match their targets' gradients
+pub fn update_gradient_editor_parts(
+    mut commands: Commands,
+    editors: Query<&GradientEditor>,
+    targets: Query<
+        &BackgroundGradient,
+        (Without<GradientEditorTrack>, Without<GradientEditorDial>),
+    >,
+    mut tracks: Query<
+        (
+            Entity,
+            &GradientEditorTrack,
+            &mut BackgroundGradient,
+            Option<&Children>,
+        ),
+        Without<GradientEditorDial>,
+    >,
+    mut handles: Query<
+        (
+            &GradientEditorHandle,
+            &mut Node,
+            &mut BackgroundColor,
+            &mut BorderColor,
+        ),
+        Without<GradientEditorSwatch>,
+    >,
+    mut swatches: Query<(&GradientEditorSwatch, &mut BorderColor)>,
+    mut dials: Query<(&GradientEditorDial, &mut BackgroundGradient), Without<GradientEditorTrack>>,
+) {
+    let edited_gradient = |editor: Entity| {
+        let editor = editors.get(editor).ok()?;
+        let gradient = targets.get(editor.target).ok()?.0.get(editor.layer)?;
+        Some((editor, gradient))
+    };
+
+    for (entity, track, mut background, children) in tracks.iter_mut() {
+        let Some((editor, gradient)) = edited_gradient(track.editor) else {
+            continue;
+        };
+        let fractions = stop_fractions(gradient);
+        let colors = stop_colors(gradient);
+
+        let preview = BackgroundGradient::from(LinearGradient::to_right(
+            colors
+                .iter()
+                .zip(&fractions)
+                .map(|(&color, &fraction)| ColorStop::percent(color, 100. * fraction)),
+        ));
+        if *background != preview {
+            *background = preview;
+        }
+
+        let children = children.map(|children| &children[..]).unwrap_or_default();
+        if children.len() != colors.len() {
+            let mut track_commands = commands.entity(entity);
+            track_commands.despawn_related::<Children>();
+            track_commands.with_children(|commands| {
+                for stop in 0..colors.len() {
+                    spawn_handle(commands, stop);
+                }
+            });
+            continue;
+        }
+
+        for &child in children {
+            let Ok((handle, mut node, mut color, mut border)) = handles.get_mut(child) else {
+                continue;
+            };
+            let left = Val::Percent(100. * fractions[handle.stop]);
+            if node.left != left {
+                node.left = left;
+            }
+            color.set_if_neq(BackgroundColor(colors[handle.stop]));
+            border.set_if_neq(BorderColor(if handle.stop == editor.selected {
+                SELECTED_COLOR
+            } else {
+                UNSELECTED_COLOR
+            }));
+        }
+    }
+
+    for (swatch, mut border) in swatches.iter_mut() {
+        let selected = edited_gradient(swatch.editor).is_some_and(|(editor, gradient)| {
+            stop_colors(gradient).get(editor.selected) == Some(&swatch.color)
+        });
+        border.set_if_neq(BorderColor(if selected {
+            SELECTED_COLOR
+        } else {
+            UNSELECTED_COLOR
+        }));
+    }
+
+    for (dial, mut background) in dials.iter_mut() {
+        let angle = match edited_gradient(dial.editor) {
+            Some((_, Gradient::Linear(gradient))) => Some(gradient.angle),
+            Some((_, Gradient::Conic(gradient))) => Some(gradient.start),
+            _ => None,
+        };
+        let needle = angle.map_or(Color::NONE, |_| SELECTED_COLOR);
+        // A thin wedge centered on the angle
+        let gradient = BackgroundGradient::from(
+            ConicGradient::new(vec![
+                AngularColorStop::turns(needle, 0.),
+                AngularColorStop::turns(needle, 0.01),
+                AngularColorStop::turns(UNSELECTED_COLOR, 0.01),
+                AngularColorStop::turns(UNSELECTED_COLOR, 0.99),
+                AngularColorStop::turns(needle, 0.99),
+            ])
+            .with_start(angle.unwrap_or_default()),
+        );
+        if *background != gradient {
+            *background = gradient;
+        }
+    }
+}
+
+fn spawn_handle(commands: &mut ChildSpawnerCommands, stop: usize) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(HANDLE_WIDTH),
+            height: Val::Percent(100.),
+            // Center the handle on the stop
+            margin: UiRect::left(Val::Px(-0.5 * HANDLE_WIDTH)),
+            border: UiRect::all(Val::Px(2.)),
+            ..Default::default()
+        },
+        GradientEditorHandle { stop },
+    ));
+}
+
+fn color_stops(gradient: &Gradient) -> &[ColorStop] {
+    match gradient {
+        Gradient::Linear(gradient) => &gradient.stops,
+        Gradient::Radial(gradient) => &gradient.stops,
+        Gradient::Conic(_) => &[],
+        Gradient::Inset(gradient) => &gradient.stops,
+        Gradient::Plasma(gradient) => &gradient.stops,
+    }
+}
+
+fn stop_colors(gradient: &Gradient) -> Vec<Color> {
+    match gradient {
+        Gradient::Conic(gradient) => gradient.stops.iter().map(|stop| stop.color).collect(),
+        gradient => color_stops(gradient)
+            .iter()
+            .map(|stop| stop.color)
+            .collect(),
+    }
+}
+
+/// The positions of the stops as fractions of the track. Automatic stops and stops
+/// in units other than percentages are spaced evenly between their neighbors.
+fn stop_fractions(gradient: &Gradient) -> Vec<f32> {
+    let positions: Vec<Option<f32>> = match gradient {
+        Gradient::Conic(gradient) => gradient
+            .stops
+            .iter()
+            .map(|stop| stop.angle.resolve().map(|angle| angle / TAU))
+            .collect(),
+        gradient => color_stops(gradient)
+            .iter()
+            .map(|stop| match stop.point {
+                Val::Percent(percent) => Some(percent / 100.),
+                _ => None,
+            })
+            .collect(),
+    };
+
+    let mut fractions = Vec::with_capacity(positions.len());
+    let mut previous = (0, 0.);
+    for (i, position) in positions.iter().enumerate() {
+        let Some(position) = position.or_else(|| {
+            (i == 0)
+                .then_some(0.)
+                .or((i + 1 == positions.len()).then_some(1.))
+        }) else {
+            continue;
+        };
+        let (start, start_fraction) = previous;
+        // Space the skipped stops evenly
+        for j in fractions.len()..i {
+            let t = (j - start) as f32 / (i - start) as f32;
+            fractions.push(start_fraction + t * (position - start_fraction));
+        }
+        fractions.push(position);
+        previous = (i, position);
+    }
+    fractions
+}
+
+fn set_stop_fraction(gradient: &mut Gradient, stop: usize, fraction: f32) {
+    let stops = match gradient {
+        Gradient::Linear(gradient) => &mut gradient.stops,
+        Gradient::Radial(gradient) => &mut gradient.stops,
+        Gradient::Conic(gradient) => {
+            if let Some(stop) = gradient.stops.get_mut(stop) {
+                stop.angle = AngularPosition::Percent(100. * fraction);
+            }
+            return;
+        }
+        Gradient::Inset(gradient) => &mut gradient.stops,
+        Gradient::Plasma(gradient) => &mut gradient.stops,
+    };
+    if let Some(stop) = stops.get_mut(stop) {
+        stop.point = Val::Percent(100. * fraction);
+    }
+}
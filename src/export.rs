@@ -0,0 +1,85 @@
+use crate::*;
+use bevy::{
+    asset::RenderAssetUsages,
+    color::ColorToPacked,
+    image::{Image, IntoDynamicImageError},
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use core::fmt;
+use std::path::Path;
+
+/// An error returned when exporting a gradient to a PNG fails
+#[derive(Debug)]
+pub enum ExportGradientError {
+    /// The rasterized gradient couldn't be converted for encoding
+    Convert(IntoDynamicImageError),
+    /// The PNG couldn't be encoded or written
+    Save(image::ImageError),
+}
+
+impl fmt::Display for ExportGradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Convert(error) => write!(f, "failed to convert gradient image: {error}"),
+            Self::Save(error) => write!(f, "failed to save gradient image: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for ExportGradientError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Convert(error) => Some(error),
+            Self::Save(error) => Some(error),
+        }
+    }
+}
+
+impl Gradient {
+    /// Rasterizes the gradient into an sRGB image, as it would be drawn on a node with a size of
+    /// `width` by `height` physical pixels.
+    ///
+    /// Viewport-relative values are resolved against the size of the image.
+    pub fn to_image(&self, width: u32, height: u32) -> Image {
+        let size = Vec2::new(width as f32, height as f32);
+        let gradient = PhysicalGradient::new(self, 1., size, size);
+        let mut data = Vec::with_capacity(4 * width as usize * height as usize);
+        for y in 0..height {
+            for x in 0..width {
+                // Sample at the center of each pixel
+                let point = Vec2::new(x as f32, y as f32) + 0.5 - 0.5 * size;
+                data.extend(Srgba::from(gradient.color_at(point)).to_u8_array());
+            }
+        }
+        Image::new(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    /// Rasterizes the gradient with [`Gradient::to_image`] and saves it as a PNG.
+    pub fn export_png(
+        &self,
+        path: impl AsRef<Path>,
+        width: u32,
+        height: u32,
+    ) -> Result<(), ExportGradientError> {
+        save_png(self.to_image(width, height), path)
+    }
+}
+
+/// Saves an image, such as a gradient rasterized with [`Gradient::to_image`], as a PNG.
+pub fn save_png(image: Image, path: impl AsRef<Path>) -> Result<(), ExportGradientError> {
+    image
+        .try_into_dynamic()
+        .map_err(ExportGradientError::Convert)?
+        .save_with_format(path, image::ImageFormat::Png)
+        .map_err(ExportGradientError::Save)
+}
@@ -0,0 +1,137 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        entity::Entity,
+        system::{Commands, Query, Res},
+    },
+    math::curve::{Curve, EaseFunction},
+    time::Time,
+};
+
+/// Whether a [`GradientFade`] fades its node in or out
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum FadeDirection {
+    /// Fade from transparent to opaque
+    #[default]
+    In,
+    /// Fade from opaque to transparent
+    Out,
+}
+
+/// Fades a node's gradients, and the gradients of its descendants, in or out by animating its [`UiOpacity`].
+///
+/// Insert a fade-in when spawning toasts and tooltips, and a fade-out with `despawn` set to
+/// remove them once they've faded. The component is removed when the fade completes.
+/// With [`GradientAccessibility::reduced_motion`] fades complete immediately.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
+#[require(UiOpacity)]
+pub struct GradientFade {
+    /// Whether the node is faded in or out
+    pub direction: FadeDirection,
+    /// The length of the fade in seconds
+    pub duration: f32,
+    /// The easing applied to the opacity
+    pub easing: EaseFunction,
+    /// Seconds since the fade started
+    pub elapsed: f32,
+    /// If true, the node and its descendants are despawned when a fade-out completes
+    pub despawn: bool,
+}
+
+impl GradientFade {
+    /// Fade the node in over `duration` seconds
+    pub fn fade_in(duration: f32) -> Self {
+        Self {
+            direction: FadeDirection::In,
+            duration,
+            easing: EaseFunction::Linear,
+            elapsed: 0.,
+            despawn: false,
+        }
+    }
+
+    /// Fade the node out over `duration` seconds
+    pub fn fade_out(duration: f32) -> Self {
+        Self {
+            direction: FadeDirection::Out,
+            ..Self::fade_in(duration)
+        }
+    }
+
+    /// Fade the node out over `duration` seconds, then despawn it
+    pub fn fade_out_and_despawn(duration: f32) -> Self {
+        Self {
+            despawn: true,
+            ..Self::fade_out(duration)
+        }
+    }
+
+    /// Sets the easing
+    pub fn with_easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// The progress of the fade, from `0.` at the start to `1.` when it completes
+    pub fn progress(&self) -> f32 {
+        if 0. < self.duration {
+            (self.elapsed / self.duration).clamp(0., 1.)
+        } else {
+            1.
+        }
+    }
+
+    /// True once the fade has completed
+    pub fn is_finished(&self) -> bool {
+        self.progress() == 1.
+    }
+
+    /// The opacity of the node at the current point of the fade
+    pub fn opacity(&self) -> f32 {
+        let t = self.easing.sample_clamped(self.progress());
+        match self.direction {
+            FadeDirection::In => t,
+            FadeDirection::Out => 1. - t,
+        }
+    }
+
+    /// Reverses the direction of the fade, continuing from the current progress so an interrupted
+    /// fade-in turns into a fade-out without jumping.
+    pub fn reverse(&mut self) {
+        self.direction = match self.direction {
+            FadeDirection::In => FadeDirection::Out,
+            FadeDirection::Out => FadeDirection::In,
+        };
+        self.elapsed = self.duration * (1. - self.progress());
+    }
+}
+
+/// Advances [`GradientFade`]s and sets the [`UiOpacity`] of their nodes.
+/// Runs in `PostUpdate`, so fades inserted in `Update` apply before their nodes are first drawn.
+pub fn update_gradient_fades(
+    mut commands: Commands,
+    time: Res<Time>,
+    accessibility: Res<GradientAccessibility>,
+    mut query: Query<(Entity, &mut GradientFade, &mut UiOpacity)>,
+) {
+    for (entity, mut fade, mut opacity) in query.iter_mut() {
+        if accessibility.reduced_motion {
+            fade.elapsed = fade.duration;
+        } else {
+            fade.elapsed += time.delta_secs();
+        }
+        let value = fade.opacity();
+        if opacity.0 != value {
+            opacity.0 = value;
+        }
+        if fade.is_finished() {
+            if fade.despawn && fade.direction == FadeDirection::Out {
+                commands.entity(entity).despawn();
+            } else {
+                commands.entity(entity).remove::<GradientFade>();
+            }
+        }
+    }
+}
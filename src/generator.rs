@@ -0,0 +1,149 @@
+use crate::*;
+use bevy::{color::Hsva, math::FloatExt};
+use core::ops::RangeInclusive;
+
+/// Generates random but pleasing gradients from a seed, for placeholder art, procedural colors
+/// such as item rarities, and telling entities apart in debug views.
+///
+/// The hues of a generated gradient are spread over a bounded arc of the color wheel, and its values
+/// run from dark to light or light to dark, so the colors are related and the gradient reads smoothly.
+/// The same seed always generates the same gradient.
+///
+/// ```
+/// # use bevy_ui_gradients::GradientGenerator;
+/// let generator = GradientGenerator::default().with_stop_count(3..=5);
+/// let palette = generator.palette(42);
+/// assert!((3..=5).contains(&palette.0.len()));
+/// assert_eq!(palette, generator.palette(42));
+/// ```
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct GradientGenerator {
+    /// The maximum difference in hue between the first and last stops, in degrees
+    pub hue_spread: f32,
+    /// The range of the saturations of the stops, between `0.` and `1.`
+    pub saturation: RangeInclusive<f32>,
+    /// The range of the HSV values of the stops, between `0.` and `1.`
+    pub value: RangeInclusive<f32>,
+    /// The range of the number of stops
+    pub stop_count: RangeInclusive<usize>,
+}
+
+impl Default for GradientGenerator {
+    fn default() -> Self {
+        Self {
+            hue_spread: 90.,
+            saturation: 0.45..=0.85,
+            value: 0.3..=0.95,
+            stop_count: 2..=4,
+        }
+    }
+}
+
+impl GradientGenerator {
+    /// Sets the maximum difference in hue between the first and last stops, in degrees
+    pub fn with_hue_spread(mut self, hue_spread: f32) -> Self {
+        self.hue_spread = hue_spread;
+        self
+    }
+
+    /// Sets the range of the saturations of the stops
+    pub fn with_saturation(mut self, saturation: RangeInclusive<f32>) -> Self {
+        self.saturation = saturation;
+        self
+    }
+
+    /// Sets the range of the HSV values of the stops
+    pub fn with_value(mut self, value: RangeInclusive<f32>) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the range of the number of stops
+    pub fn with_stop_count(mut self, stop_count: RangeInclusive<usize>) -> Self {
+        self.stop_count = stop_count;
+        self
+    }
+
+    /// The colors of the gradient generated from `seed`, in order
+    pub fn palette(&self, seed: u64) -> Palette {
+        let mut rng = SplitMix64(seed);
+        let count = rng
+            .range_usize(*self.stop_count.start(), *self.stop_count.end())
+            .max(1);
+        let start_hue = rng.range_f32(0., 360.);
+        let hue_spread = rng.range_f32(0.5, 1.) * self.hue_spread;
+        let hue_direction = if rng.next_u64() & 1 == 0 { 1. } else { -1. };
+        let (dark, light) = (
+            rng.range_f32(*self.value.start(), *self.value.end()),
+            rng.range_f32(*self.value.start(), *self.value.end()),
+        );
+        let (dark, light) = (dark.min(light), dark.max(light));
+        let ascending = rng.next_u64() & 1 == 0;
+
+        Palette(
+            (0..count)
+                .map(|i| {
+                    let t = if count == 1 {
+                        0.5
+                    } else {
+                        i as f32 / (count - 1) as f32
+                    };
+                    let hue = (start_hue + hue_direction * hue_spread * t).rem_euclid(360.);
+                    let saturation =
+                        rng.range_f32(*self.saturation.start(), *self.saturation.end());
+                    let value = dark.lerp(light, if ascending { t } else { 1. - t });
+                    Hsva::new(hue, saturation.clamp(0., 1.), value.clamp(0., 1.), 1.).into()
+                })
+                .collect(),
+        )
+    }
+
+    /// A linear gradient generated from `seed`, at a random multiple of 45 degrees
+    pub fn linear_gradient(&self, seed: u64) -> LinearGradient {
+        let angle = Angle::turns(SplitMix64(!seed).range_usize(0, 7) as f32 / 8.);
+        self.palette(seed).linear_gradient(angle)
+    }
+
+    /// A radial gradient generated from `seed`, from the center of the node to its farthest corner
+    pub fn radial_gradient(&self, seed: u64) -> RadialGradient {
+        self.palette(seed)
+            .radial_gradient(Position::CENTER, RadialGradientShape::FarthestCorner)
+    }
+
+    /// A conic gradient generated from `seed`. The first color is repeated at the end, so there's no seam.
+    pub fn conic_gradient(&self, seed: u64) -> ConicGradient {
+        let mut palette = self.palette(seed);
+        if let Some(&first) = palette.0.first() {
+            palette.0.push(first);
+        }
+        palette.conic_gradient()
+    }
+}
+
+/// The SplitMix64 generator, a small, fast generator that's good enough for picking colors
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A random number between `min` and `max`
+    fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        let t = (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32;
+        min + (max - min) * t
+    }
+
+    /// A random integer from `min` to `max` inclusive
+    fn range_usize(&mut self, min: usize, max: usize) -> usize {
+        if max <= min {
+            return min;
+        }
+        min + (self.next_u64() % (max - min + 1) as u64) as usize
+    }
+}
@@ -0,0 +1,70 @@
+use crate::*;
+use bevy::{color::Alpha, math::ops::exp, ui::Node};
+
+/// The number of color stops generated for a [`GlowFalloff`]
+pub const GLOW_FALLOFF_STOPS: usize = 8;
+
+/// How the opacity of a [`GradientGlow`] falls off from the node's border to the outer edge of the glow
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum GlowFalloff {
+    /// The opacity falls off linearly
+    Linear,
+    /// The opacity falls off with a smoothstep curve, with soft edges at both ends
+    #[default]
+    Smooth,
+    /// The opacity falls off quickly near the border, with a long faint tail
+    Exponential,
+}
+
+impl GlowFalloff {
+    /// The opacity at `t`, from `1.` at the node's border at `t = 0.` to `0.` at the outer edge of the glow at `t = 1.`
+    pub fn sample(self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            GlowFalloff::Linear => 1. - t,
+            GlowFalloff::Smooth => 1. - t * t * (3. - 2. * t),
+            GlowFalloff::Exponential => (exp(-4. * t) - exp(-4.)) / (1. - exp(-4.)),
+        }
+    }
+}
+
+/// A soft gradient halo around a node, extending beyond its border, for highlighting selected items.
+///
+/// The glow follows the node's rounded border outward. Its color stops run from the node's border at `0%`
+/// to the outer edge of the glow at `100%`, `spread` logical pixels out. It's drawn behind the node, and
+/// nothing is drawn inside the node's border.
+///
+/// Glows are ignored by gradient sampling and picking.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
+#[require(Node)]
+pub struct GradientGlow {
+    /// The color stops, from the node's border to the outer edge of the glow
+    pub stops: Vec<ColorStop>,
+    /// How far the glow extends beyond the node's border in logical pixels
+    pub spread: f32,
+}
+
+impl GradientGlow {
+    /// A glow of `color` extending `spread` logical pixels beyond the node's border, fading out with `falloff`
+    pub fn new(color: impl Into<Color>, spread: f32, falloff: GlowFalloff) -> Self {
+        let color = color.into();
+        let last = GLOW_FALLOFF_STOPS - 1;
+        let stops = (0..=last)
+            .map(|i| {
+                let t = i as f32 / last as f32;
+                ColorStop::percent(
+                    color.with_alpha(color.alpha() * falloff.sample(t)),
+                    100. * t,
+                )
+            })
+            .collect();
+        Self::from_stops(stops, spread)
+    }
+
+    /// A glow with the given color stops, extending `spread` logical pixels beyond the node's border
+    pub fn from_stops(stops: Vec<ColorStop>, spread: f32) -> Self {
+        Self { stops, spread }
+    }
+}
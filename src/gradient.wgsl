@@ -1,4 +1,9 @@
 #import bevy_render::view::View
+#import bevy_render::globals::Globals
+
+#ifdef CUSTOM_GRADIENT_FUNCTION
+#import bevy_ui_gradients::custom_gradient::custom_gradient
+#endif
 
 const PI: f32 = 3.14159265358979323846;
 const TAU: f32 = 2. * PI;
@@ -11,98 +16,322 @@ const RADIAL: u32 = 16u;
 const FILL_START: u32 = 32u;
 const FILL_END: u32 = 64u;
 const CONIC: u32 = 128u;
+const MASK: u32 = 256u;
+const SPREAD_REPEAT: u32 = 512u;
+const SPREAD_REFLECT: u32 = 1024u;
+const TILED: u32 = 2048u;
+const FILTER: u32 = 4096u;
+const CUSTOM_FUNCTION: u32 = 8192u;
+const LUT: u32 = 16384u;
+const INSET: u32 = 32768u;
+const PLASMA: u32 = 65536u;
+const GLOW: u32 = 131072u;
+const OSCILLATE: u32 = 262144u;
+const PALETTE: u32 = 524288u;
+
+// The flags stored in the third component of the grain
+const GRAIN_ANIMATED: u32 = 1u;
+const PALETTE_DITHER: u32 = 2u;
+const BAYER_4: u32 = 4u;
+const BAYER_8: u32 = 8u;
+const TEXTURE_ALPHA: u32 = 16u;
+const TEXTURE_DISPLACE: u32 = 32u;
+const TEXTURE_MULTIPLY: u32 = 64u;
+// The segment's colors are interpolated between its vertices, with `VERTEX_COLORS`
+const VERTEX_COLOR: u32 = 128u;
 
 fn enabled(flags: u32, mask: u32) -> bool {
     return (flags & mask) != 0u;
 }
 
 @group(0) @binding(0) var<uniform> view: View;
+@group(0) @binding(1) var<uniform> globals: Globals;
+
+@group(1) @binding(0) var sprite_texture: texture_2d<f32>;
+@group(1) @binding(1) var sprite_sampler: sampler;
+@group(1) @binding(2) var palette_texture: texture_2d<f32>;
 
 struct GradientVertexOutput {
     @location(0) uv: vec2<f32>,
-    @location(1) @interpolate(flat) size: vec2<f32>,
+    // xy: size of the node, zw: size of a tile
+    @location(1) @interpolate(flat) size: vec4<f32>,
     @location(2) @interpolate(flat) flags: u32,
     @location(3) @interpolate(flat) radius: vec4<f32>,    
     @location(4) @interpolate(flat) border: vec4<f32>,    
 
-    // Position relative to the center of the rectangle.
-    @location(5) point: vec2<f32>,
-    @location(6) @interpolate(flat) g_start: vec2<f32>,
-    @location(7) @interpolate(flat) dir: vec2<f32>,
+    // xy: position relative to the center of the rectangle, zw: position relative to the first tile
+    @location(5) point: vec4<f32>,
+    // xy: start point or center, zw: direction or shape parameters
+    @location(6) @interpolate(flat) g_line: vec4<f32>,
+    // Radial: y: falloff. Conic: x: sweep, y: inner radius, z: outer radius, zero if there's no outer radius.
+    // w: the modulation amount of the texture
+    @location(7) @interpolate(flat) shape: vec4<f32>,
+#ifdef VERTEX_COLORS
+    // Constant over segments without vertex colors
+    @location(8) start_color: vec4<f32>,
+#else
     @location(8) @interpolate(flat) start_color: vec4<f32>,
-    @location(9) @interpolate(flat) start_len: f32,
-    @location(10) @interpolate(flat) end_len: f32,
-    @location(11) @interpolate(flat) end_color: vec4<f32>,
-    @location(12) @interpolate(flat) hint: f32,
+#endif
+    // x: start_len, y: end_len, z: hint, w: interpolation, see `segment_interpolation`
+    @location(9) @interpolate(flat) segment: vec4<f32>,
+    @location(10) @interpolate(flat) end_color: vec4<f32>,
+    // With MASK, the bits of the color of the background color or image masked by the gradient.
+    // With OSCILLATE, the oscillations of the start and end stops packed into pairs of half floats,
+    // x: position and brightness amplitudes, y: frequency and phase of the start stop, zw: the same for the end stop
+    @location(11) @interpolate(flat) mask_or_oscillation: vec4<u32>,
+    // x: angular velocity, y: scroll velocity, z: pulse amplitude, w: pulse frequency
+    @location(12) @interpolate(flat) animation: vec4<f32>,
+    // x: intensity, y: scale, z: the grain flags, w: interpolation parameter
+    @location(13) @interpolate(flat) grain: vec4<f32>,
+    // xy: the distances of the first and last stops, the period of repeated and reflected gradients
+    // zw: the distance between the starts of adjacent tiles, zero on axes that don't repeat
+    @location(14) @interpolate(flat) spread: vec4<f32>,
+    // x: hue rotation, y: saturation, z: grayscale, w: brightness
+    @location(15) @interpolate(flat) color_filter: vec4<f32>,
     @builtin(position) position: vec4<f32>,
 };
 
 @vertex
 fn vertex(
-    @location(0) vertex_position: vec3<f32>,
-    @location(1) vertex_uv: vec2<f32>,
-    @location(2) flags: u32,
+    // xyz: position, w: the outer radius of a conic gradient
+    @location(0) vertex_position: vec4<f32>,
+    // xy: uv, z: the sweep of a conic gradient, w: the falloff of a radial gradient or the inner radius of a conic gradient
+    @location(1) vertex_uv: vec4<f32>,
+    // x: flags, y: the bits of the modulation amount of the texture
+    @location(2) flags: vec2<u32>,
 
     // x: top left, y: top right, z: bottom right, w: bottom left.
     @location(3) radius: vec4<f32>,
 
     // x: left, y: top, z: right, w: bottom.
     @location(4) border: vec4<f32>,
-    @location(5) size: vec2<f32>,
-    @location(6) point: vec2<f32>,
-    @location(7) @interpolate(flat) g_start: vec2<f32>,
-    @location(8) @interpolate(flat) dir: vec2<f32>,
-    @location(9) @interpolate(flat) start_color: vec4<f32>,
-    @location(10) @interpolate(flat) start_len: f32,
-    @location(11) @interpolate(flat) end_len: f32,
-    @location(12) @interpolate(flat) end_color: vec4<f32>,
-    @location(13) @interpolate(flat) hint: f32
+    @location(5) size: vec4<f32>,
+    @location(6) point: vec4<f32>,
+    // xy: start point or center, zw: direction or shape parameters
+    @location(7) @interpolate(flat) g_line: vec4<f32>,
+    @location(8) @interpolate(flat) start_color: vec4<f32>,
+    @location(9) @interpolate(flat) segment: vec4<f32>,
+    @location(10) @interpolate(flat) end_color: vec4<f32>,
+    @location(11) @interpolate(flat) mask_or_oscillation: vec4<u32>,
+    @location(12) @interpolate(flat) animation: vec4<f32>,
+    @location(13) @interpolate(flat) grain: vec4<f32>,
+    @location(14) @interpolate(flat) spread: vec4<f32>,
+    @location(15) @interpolate(flat) color_filter: vec4<f32>,
 ) -> GradientVertexOutput {
     var out: GradientVertexOutput;
-    out.position = view.clip_from_world * vec4(vertex_position, 1.0);
-    out.uv = vertex_uv;
+    out.position = view.clip_from_world * vec4(vertex_position.xyz, 1.0);
+    out.uv = vertex_uv.xy;
     out.size = size;
-    out.flags = flags;
+    out.flags = flags.x;
     out.radius = radius;
     out.border = border;
     out.point = point;
-    out.dir = dir;
     out.start_color = start_color;
-    out.start_len = start_len;
-    out.end_len = end_len;
+    out.segment = segment;
     out.end_color = end_color;
-    out.g_start = g_start;
-    out.hint = hint;
+    out.g_line = g_line;
+    out.shape = vec4(vertex_uv.zw, vertex_position.w, bitcast<f32>(flags.y));
+    out.mask_or_oscillation = mask_or_oscillation;
+    out.animation = animation;
+    out.grain = grain;
+    out.spread = spread;
+    out.color_filter = color_filter;
 
     return out;
 }
 
 @fragment
 fn fragment(in: GradientVertexOutput) -> @location(0) vec4<f32> {
+    let rotation = in.animation.x * animation_time();
+    let scroll = in.animation.y * animation_time();
+
+    // The point and size the gradient is resolved against
+    var g_point = in.point.xy;
+    var g_size = in.size.xy;
+    var tile_alpha = 1.;
+    // The coverage of the glow outside the node's edge
+    var glow_coverage = 1.;
+    if enabled(in.flags, TILED) {
+        let tile = tile_point(in.point.zw, in.size.zw, in.spread.zw);
+        g_point = tile.xy;
+        g_size = in.size.zw;
+        tile_alpha = tile.z;
+    }
+
     var g_distance: f32;
-    if enabled(in.flags, RADIAL) {
-        g_distance = radial_distance(in.point, in.g_start, in.dir.x);
+    if enabled(in.flags, LUT) {
+        // The texture holds the position along the gradient line as a fraction of its length
+        let t = textureSampleLevel(sprite_texture, sprite_sampler, g_point / g_size + 0.5, 0.).r;
+        g_distance = t * in.g_line.z - scroll;
+    } else if enabled(in.flags, RADIAL) {
+        g_distance = radial_falloff(radial_distance(g_point, in.g_line.xy, in.g_line.z), in.g_line.w, in.shape.y) - scroll;
     } else if enabled(in.flags, CONIC) {
-        g_distance = conic_distance(in.dir.x, in.point, in.g_start);
+        g_distance = sweep_distance(conic_distance(in.g_line.z + rotation + scroll, g_point, in.g_line.xy, in.g_line.w), in.shape.x);
+    } else if enabled(in.flags, PLASMA) {
+        // x: scale, y: speed
+        g_distance = plasma(g_point, in.g_line.x, in.g_line.y * animation_time()) * in.g_line.x - scroll;
+    } else if enabled(in.flags, GLOW) {
+        // The quad is the node expanded by the glow's spread, in x, with its corner radii expanded by the spread.
+        // Offsetting the distance from the quad's edge by the spread gives the distance from the node's border.
+        let border_distance = sd_rounded_box(in.point.xy, in.size.xy, in.radius) + in.g_line.x;
+#ifdef ANTI_ALIAS
+        // Nothing is drawn inside the node, the glow fades in over the node's anti-aliased edge
+        glow_coverage = antialias(-border_distance);
+#else
+        // Nothing is drawn inside the node
+        glow_coverage = step(0., border_distance);
+#endif
+        if glow_coverage <= 0. {
+            return vec4(0.);
+        }
+        g_distance = max(border_distance, 0.) - scroll;
+    } else if enabled(in.flags, INSET) {
+        g_distance = inset_distance(g_point, in.g_line.xy, in.g_line.zw, in.size.xy, in.radius, !enabled(in.flags, TILED)) - scroll;
+    } else if rotation != 0. {
+        g_distance = rotated_linear_distance(g_point, g_size, in.g_line.zw, rotation) - scroll;
     } else {
-        g_distance = linear_distance(in.point, in.g_start, in.dir);
+        g_distance = linear_distance(g_point, in.g_line.xy, in.g_line.zw) - scroll;
     }
 
-    let gradient_color = interpolate_gradient(
-        g_distance,
-        in.start_color,
-        in.start_len,
-        in.end_color,
-        in.end_len,
-        in.hint,
-        in.flags
-    );
+#ifdef CUSTOM_GRADIENT_FUNCTION
+    if enabled(in.flags, CUSTOM_FUNCTION) {
+        // The id is stored in the high 12 bits of the flags
+        g_distance = custom_gradient(in.flags >> 20u, g_point / g_size + 0.5, g_distance);
+    }
+#endif
+
+    let grain_flags = u32(in.grain.z);
+    if enabled(grain_flags, TEXTURE_DISPLACE) {
+        let texture_value = textureSampleLevel(sprite_texture, sprite_sampler, in.uv, 0.).r;
+        g_distance += (texture_value - 0.5) * in.shape.w;
+    }
+
+    if enabled(in.flags, SPREAD_REPEAT | SPREAD_REFLECT) {
+        g_distance = spread_distance(g_distance, in.spread.xy, enabled(in.flags, SPREAD_REFLECT));
+    }
+
+    var start_color = in.start_color;
+    var end_color = in.end_color;
+    var segment = in.segment;
+    if enabled(in.flags, OSCILLATE) {
+        let start = oscillation(in.mask_or_oscillation.xy);
+        let end = oscillation(in.mask_or_oscillation.zw);
+        segment.x += start.x;
+        segment.y = max(segment.y + end.x, segment.x);
+        start_color = vec4(start_color.rgb * max(1. + start.y, 0.), start_color.a);
+        end_color = vec4(end_color.rgb * max(1. + end.y, 0.), end_color.a);
+    }
+
+    var gradient_color: vec4<f32>;
+    if enabled(grain_flags, VERTEX_COLOR) {
+        gradient_color = in.start_color;
+    } else {
+        gradient_color = interpolate_gradient(
+            g_distance,
+            start_color,
+            segment.x,
+            end_color,
+            segment.y,
+            segment.z,
+            segment.w,
+            in.grain.w,
+            in.flags
+        );
+    }
+
+    if enabled(grain_flags, TEXTURE_MULTIPLY) {
+        gradient_color *= textureSampleLevel(sprite_texture, sprite_sampler, in.uv, 0.);
+    }
+
+#ifndef NO_GRAIN
+    if in.grain.x != 0. {
+        gradient_color = apply_grain(gradient_color, in.position.xy, in.grain.xyz);
+    }
+#endif
+
+    if enabled(in.flags, FILTER) {
+        gradient_color = apply_filter(gradient_color, in.color_filter);
+    }
+
+    if enabled(grain_flags, TEXTURE_ALPHA) {
+        let texture_value = textureSampleLevel(sprite_texture, sprite_sampler, in.uv, 0.).r;
+        gradient_color.a *= mix(1., texture_value, in.shape.w);
+    }
 
+    if enabled(in.flags, PALETTE) {
+        gradient_color = apply_palette(gradient_color, in.position.xy, enabled(grain_flags, PALETTE_DITHER));
+    }
+
+    gradient_color.a *= tile_alpha * glow_coverage;
+
+    if enabled(in.flags, CONIC) {
+        gradient_color.a *= ring_coverage(g_point, in.g_line.xy, in.g_line.w, in.shape.yz);
+    }
+
+    if in.animation.z != 0. {
+        let pulse = 0.5 - 0.5 * cos(TAU * in.animation.w * animation_time());
+        gradient_color.a *= 1. - in.animation.z * pulse;
+    }
+
+    let texture_color = textureSample(sprite_texture, sprite_sampler, in.uv);
+    if enabled(in.flags, MASK) {
+        // Only the alpha of the gradient is used, to mask the background color or image
+        let masked_color = texture_color * bitcast<vec4<f32>>(in.mask_or_oscillation);
+        gradient_color = vec4(masked_color.rgb, masked_color.a * gradient_color.a);
+    }
+
+    var color: vec4<f32>;
     if enabled(in.flags, BORDER) {
-        return draw_uinode_border(gradient_color, in.point, in.size, in.radius, in.border);
+        color = draw_uinode_border(gradient_color, in.point.xy, in.size.xy, in.radius, in.border);
     } else {
-        return draw_uinode_background(gradient_color, in.point, in.size, in.radius, in.border);
+        color = draw_uinode_background(gradient_color, in.point.xy, in.size.xy, in.radius, in.border);
     }
+#ifdef DETERMINISTIC
+    color = round_output(color);
+#endif
+    return blend_output(color);
+}
+
+// The time that animations are evaluated at.
+// With the `deterministic` feature animations are frozen at their start, so screenshots don't depend on when they're taken.
+fn animation_time() -> f32 {
+#ifdef DETERMINISTIC
+    return 0.;
+#else
+    return globals.time;
+#endif
+}
+
+// Rounds the color to the nearest 8-bit sRGB value, with the exact sRGB transfer function so an sRGB target
+// encodes it back to the same value. Small differences in the math of different backends are rounded away.
+fn round_output(color: vec4<f32>) -> vec4<f32> {
+    let linear = clamp(color.rgb, vec3(0.), vec3(1.));
+    let encoded = select(1.055 * pow(linear, vec3(1. / 2.4)) - 0.055, 12.92 * linear, linear <= vec3(0.0031308));
+    let rounded = round(encoded * 255.) / 255.;
+    let decoded = select(pow((rounded + 0.055) / 1.055, vec3(2.4)), rounded / 12.92, rounded <= vec3(0.04045));
+    return vec4(decoded, round(clamp(color.a, 0., 1.) * 255.) / 255.);
+}
+
+// The offsets of a stop's position and brightness at the current time,
+// from its amplitudes in the first packed pair and its frequency and phase in the second
+fn oscillation(packed: vec2<u32>) -> vec2<f32> {
+    let amplitude = unpack2x16float(packed.x);
+    let wave = unpack2x16float(packed.y);
+    return amplitude * sin(TAU * (wave.x * animation_time() + wave.y));
+}
+
+// Prepares the color for the fixed function blend state of the gradient's blend mode
+fn blend_output(color: vec4<f32>) -> vec4<f32> {
+#ifdef BLEND_MULTIPLY
+    // Transparent parts of the gradient multiply by white, leaving the destination unchanged
+    return vec4(mix(vec3(1.), color.rgb, color.a), color.a);
+#else
+#ifdef BLEND_SCREEN
+    return vec4(color.rgb * color.a, color.a);
+#else
+    return color;
+#endif
+#endif
 }
 
 // This function converts two linear rgb colors to srgb space, mixes them, and then converts the result back to linear rgb space.
@@ -113,6 +342,95 @@ fn mix_linear_rgb_in_srgb_space(a: vec4<f32>, b: vec4<f32>, t: f32) -> vec4<f32>
     return vec4(pow(mixed_srgb, vec3(2.2)), mix(a.a, b.a, t));
 }
 
+// A hash of a grain cell's coordinates and the seed, between 0 and 1.
+fn grain_hash(cell: vec2<u32>, seed: u32) -> f32 {
+    var h = (cell.x * 1597334673u) ^ (cell.y * 3812015801u) ^ (seed * 2798796415u);
+    h = (h ^ (h >> 16u)) * 2246822519u;
+    h = (h ^ (h >> 13u)) * 3266489917u;
+    h = h ^ (h >> 16u);
+    return f32(h) / 4294967295.;
+}
+
+// Adds noise to the color in sRGB space, or quantizes it with ordered dithering.
+// x: intensity or the size of the dithering steps, y: size of the grains in physical pixels, z: the grain flags
+fn apply_grain(color: vec4<f32>, frag_coord: vec2<f32>, grain: vec3<f32>) -> vec4<f32> {
+    let cell = vec2<u32>(floor(frag_coord / max(grain.y, 1.)));
+    let flags = u32(grain.z);
+    if enabled(flags, BAYER_4 | BAYER_8) {
+        var bits = 2u;
+        if enabled(flags, BAYER_8) {
+            bits = 3u;
+        }
+        let threshold = bayer_threshold(cell, bits);
+        let srgb = floor(pow(color.rgb, vec3(1. / 2.2)) / grain.x + threshold) * grain.x;
+        return vec4(pow(clamp(srgb, vec3(0.), vec3(1.)), vec3(2.2)), color.a);
+    }
+    var seed = 0u;
+#ifndef DETERMINISTIC
+    if enabled(flags, GRAIN_ANIMATED) {
+        seed = globals.frame_count;
+    }
+#endif
+    let noise = grain_hash(cell, seed) - 0.5;
+    let srgb = max(pow(color.rgb, vec3(1. / 2.2)) + grain.x * noise, vec3(0.));
+    return vec4(pow(srgb, vec3(2.2)), color.a);
+}
+
+// The threshold of the cell in a Bayer matrix with a size of `2^bits`, between 0 and 1.
+// Each bit of the matrix value is built by interleaving the bits of `x ^ y` and `y`, in reverse order.
+fn bayer_threshold(cell: vec2<u32>, bits: u32) -> f32 {
+    let xy = cell.x ^ cell.y;
+    var value = 0u;
+    for (var bit = 0u; bit < bits; bit += 1u) {
+        value = (value << 2u) | (((xy >> bit) & 1u) << 1u) | ((cell.y >> bit) & 1u);
+    }
+    return (f32(value) + 0.5) / f32(1u << (2u * bits));
+}
+
+// Replaces the color with the nearest color in the palette texture in sRGB space, keeping its alpha.
+// With dithering, the color is first offset by the ordered dithering matrix, scaled by the average
+// distance between the palette's colors on each channel.
+fn apply_palette(color: vec4<f32>, frag_coord: vec2<f32>, dither: bool) -> vec4<f32> {
+    let dimensions = textureDimensions(palette_texture);
+    let count = dimensions.x * dimensions.y;
+    var srgb = pow(color.rgb, vec3(1. / 2.2));
+    if dither {
+        let spread = 1. / max(pow(f32(count), 1. / 3.) - 1., 1.);
+        srgb += spread * (bayer_threshold(vec2<u32>(frag_coord), 2u) - 0.5);
+    }
+    var nearest = color.rgb;
+    var nearest_distance = 3.4028235e38;
+    for (var i = 0u; i < count; i += 1u) {
+        let texel = textureLoad(palette_texture, vec2(i % dimensions.x, i / dimensions.x), 0).rgb;
+        let offset = pow(texel, vec3(1. / 2.2)) - srgb;
+        let distance = dot(offset, offset);
+        if distance < nearest_distance {
+            nearest = texel;
+            nearest_distance = distance;
+        }
+    }
+    return vec4(nearest, color.a);
+}
+
+// Applies CSS-style color filters in sRGB space.
+// x: hue rotation in radians, y: saturation, z: grayscale amount, w: brightness
+fn apply_filter(color: vec4<f32>, color_filter: vec4<f32>) -> vec4<f32> {
+    let srgb = pow(color.rgb, vec3(1. / 2.2));
+    let c = cos(color_filter.x);
+    let s = sin(color_filter.x);
+    // The CSS `hue-rotate()` matrix
+    var rgb = vec3(
+        dot(srgb, vec3(0.213 + c * 0.787 - s * 0.213, 0.715 - c * 0.715 - s * 0.715, 0.072 - c * 0.072 + s * 0.928)),
+        dot(srgb, vec3(0.213 - c * 0.213 + s * 0.143, 0.715 + c * 0.285 + s * 0.140, 0.072 - c * 0.072 - s * 0.283)),
+        dot(srgb, vec3(0.213 - c * 0.213 - s * 0.787, 0.715 - c * 0.715 + s * 0.715, 0.072 + c * 0.928 + s * 0.072)),
+    );
+    let luma = vec3(dot(rgb, vec3(0.213, 0.715, 0.072)));
+    rgb = mix(luma, rgb, color_filter.y);
+    rgb = mix(rgb, luma, color_filter.z);
+    rgb = max(rgb * color_filter.w, vec3(0.));
+    return vec4(pow(rgb, vec3(2.2)), color.a);
+}
+
 // These functions are used to calculate the distance in gradient space from the start of the gradient to the point.
 // The distance in gradient space is then used to interpolate between the start and end colors.
 
@@ -124,6 +442,25 @@ fn linear_distance(
     return dot(point - g_start, g_dir);
 }
 
+// The distance along a linear gradient rotated clockwise by `rotation` radians.
+// The distance is scaled so the gradient line keeps the length it has at its original angle.
+fn rotated_linear_distance(
+    point: vec2<f32>,
+    size: vec2<f32>,
+    g_dir: vec2<f32>,
+    rotation: f32,
+) -> f32 {
+    let c = cos(rotation);
+    let s = sin(rotation);
+    let dir = vec2(c * g_dir.x - s * g_dir.y, s * g_dir.x + c * g_dir.y);
+    let length = abs(size.x * dir.x) + abs(size.y * dir.y);
+    let original_length = abs(size.x * g_dir.x) + abs(size.y * g_dir.y);
+    if length == 0. {
+        return 0.;
+    }
+    return (dot(point, dir) + 0.5 * length) * original_length / length;
+}
+
 fn radial_distance(
     point: vec2<f32>,
     center: vec2<f32>,
@@ -133,16 +470,112 @@ fn radial_distance(
     return length(vec2(d.x, d.y * ratio));
 }
 
+// `ratio` is the ratio of the width to the height of the ellipse the angular sweep follows
 fn conic_distance(
     start: f32,
     point: vec2<f32>,
     center: vec2<f32>,
+    ratio: f32,
 ) -> f32 {
     let d = point - center;
-    let angle = atan2(-d.x, d.y) + PI;
+    let angle = atan2(-d.x, d.y * ratio) + PI;
     return (((angle - start) % TAU) + TAU) % TAU;
 }
 
+// The coverage of a point by the ring of a conic gradient between its inner and outer radii.
+// The radii follow the ellipse of the conic gradient, and an outer radius of zero is unbounded.
+fn ring_coverage(point: vec2<f32>, center: vec2<f32>, ratio: f32, radii: vec2<f32>) -> f32 {
+    if radii.x <= 0. && radii.y <= 0. {
+        return 1.;
+    }
+    let d = point - center;
+    let r = length(vec2(d.x, d.y * ratio));
+    var distance = radii.x - r;
+    if 0. < radii.y {
+        distance = max(distance, r - radii.y);
+    }
+#ifdef ANTI_ALIAS
+    return antialias(distance);
+#else
+    return 1. - step(0., distance);
+#endif
+}
+
+// Raises the distance from the center of a radial gradient to the power of `falloff`, as a fraction of `radius`
+fn radial_falloff(distance: f32, radius: f32, falloff: f32) -> f32 {
+    if falloff == 1. || radius <= 0. {
+        return distance;
+    }
+    return radius * pow(distance / radius, falloff);
+}
+
+// Maps an angle clockwise from the start of a conic gradient onto its stops, which are spread over `sweep`.
+// Negative sweeps are counter-clockwise. Sweeps of more than a full turn wrap around, the last revolution
+// covering the angle is drawn.
+fn sweep_distance(angle: f32, sweep: f32) -> f32 {
+    var a = angle;
+    if sweep < 0. {
+        a = (TAU - angle) % TAU;
+    }
+    let length = abs(sweep);
+    let revolution = max(ceil((length - a) / TAU) - 1., 0.);
+    return (a + revolution * TAU) * TAU / length;
+}
+
+// The distance of the point inward from the edges of a box with the given center and size.
+// If `rounded` is true and the box is inside the node, its corners are rounded like the node's corners are
+// rounded at the same inset.
+fn inset_distance(
+    point: vec2<f32>,
+    center: vec2<f32>,
+    size: vec2<f32>,
+    node_size: vec2<f32>,
+    node_radius: vec4<f32>,
+    rounded: bool,
+) -> f32 {
+    // x: left, y: top, z: right, w: bottom
+    let inset = vec4(0.5 * (node_size - size) + center, 0.5 * (node_size - size) - center);
+    if rounded && all(vec4(0.) <= inset) {
+        return -sd_inset_rounded_box(point, node_size, node_radius, inset);
+    }
+    return -sd_rounded_box(point - center, size, vec4(0.));
+}
+
+// The value of a plasma at the point, between 0 and 1.
+// The sum of moving sine waves with a wavelength of `scale`.
+fn plasma(point: vec2<f32>, scale: f32, time: f32) -> f32 {
+    let p = TAU * point / scale;
+    let c = p + 2. * vec2(sin(0.2 * time), cos(0.3 * time));
+    let v = sin(p.x + time) + sin(0.5 * (p.y + time)) + sin(0.5 * (p.x + p.y + time)) + sin(length(c) + time);
+    return 0.5 + 0.125 * v;
+}
+
+// Maps a point relative to the first tile to xy: the point relative to the center of the tile containing it,
+// z: 1. if the point is inside a tile and 0. if it's in the space between tiles.
+// Axes with a zero period don't repeat.
+fn tile_point(point: vec2<f32>, size: vec2<f32>, period: vec2<f32>) -> vec3<f32> {
+    let wrapped = point - period * floor(point / max(period, vec2(1e-6)));
+    let local = select(point, wrapped, vec2(0.) < period);
+    let inside = all(vec2(0.) <= local) && all(local < size);
+    return vec3(local - 0.5 * size, select(0., 1., inside));
+}
+
+// Wraps the distance into the period between the first and last stops.
+// Every other period is reversed if `reflect` is true.
+fn spread_distance(distance: f32, period: vec2<f32>, reflect: bool) -> f32 {
+    let length = period.y - period.x;
+    if length <= 0. {
+        return distance;
+    }
+    if reflect {
+        let d = distance - period.x;
+        let x = d - 2. * length * floor(d / (2. * length));
+        return period.x + length - abs(x - length);
+    }
+    let d = distance - period.x;
+    return period.x + d - length * floor(d / length);
+}
+
 fn interpolate_gradient(
     distance: f32,
     start_color: vec4<f32>,
@@ -150,6 +583,8 @@ fn interpolate_gradient(
     end_color: vec4<f32>,
     end_distance: f32,
     hint: f32,
+    interpolation: f32,
+    interpolation_parameter: f32,
     flags: u32,
 ) -> vec4<f32> {
     if start_distance == end_distance {
@@ -178,14 +613,69 @@ fn interpolate_gradient(
         return vec4(0.0);
     }
 
+#ifndef NO_HINTS
     if t < hint {
         t = 0.5 * t / hint;
     } else {
         t = 0.5 * (1 + (t - hint) / (1.0 - hint));
     }
 
-    // Only color interpolation in SRGB space is supported atm.
+    t = segment_interpolation(t, interpolation, interpolation_parameter);
+#endif
+
+#ifdef LINEAR_COLOR_MIX
+    // Cheaper, but midpoints between contrasting colors are lighter than in sRGB space
+    return mix(start_color, end_color, t);
+#else
     return mix_linear_rgb_in_srgb_space(start_color, end_color, t);
+#endif
+}
+
+// Remaps `t` by the interpolation of the segment, packed by `Interpolation::pack`.
+// Negative values of `a` select the modes without parameters: -1 linear, -2 smoothstep and -3 step.
+// Otherwise `a` and `b` are the control points of a cubic Bézier curve.
+fn segment_interpolation(t: f32, a: f32, b: f32) -> f32 {
+    if a == -2. {
+        return smoothstep(0., 1., t);
+    }
+    if a == -3. {
+        return step(0.5, t);
+    }
+    if a < 0. {
+        return t;
+    }
+    return cubic_bezier(t, unpack_bezier_point(a), unpack_bezier_point(b));
+}
+
+// Each coordinate is quantized to 12 bits, x between 0 and 1 and y between -1 and 2.
+fn unpack_bezier_point(packed: f32) -> vec2<f32> {
+    let q = u32(packed);
+    let n = vec2(f32(q & 0xfffu), f32(q >> 12u)) / 4095.;
+    return vec2(n.x, 3. * n.y - 1.);
+}
+
+// A coordinate of a cubic Bézier curve from 0 to 1 with control point coordinates `a` and `b`.
+fn bezier_component(s: f32, a: f32, b: f32) -> f32 {
+    let u = 1. - s;
+    return 3. * u * u * s * a + 3. * u * s * s * b + s * s * s;
+}
+
+fn bezier_derivative(s: f32, a: f32, b: f32) -> f32 {
+    let u = 1. - s;
+    return 3. * u * u * a + 6. * u * s * (b - a) + 3. * s * s * (1. - b);
+}
+
+// Solves for the curve parameter at `x` with Newton's method, then evaluates y, clamped between 0 and 1.
+fn cubic_bezier(x: f32, p1: vec2<f32>, p2: vec2<f32>) -> f32 {
+    var s = x;
+    for (var i = 0; i < 8; i += 1) {
+        let dx = bezier_derivative(s, p1.x, p2.x);
+        if abs(dx) < 1e-6 {
+            break;
+        }
+        s = clamp(s - (bezier_component(s, p1.x, p2.x) - x) / dx, 0., 1.);
+    }
+    return clamp(bezier_component(s, p1.y, p2.y), 0., 1.);
 }
 
 // The returned value is the shortest distance from the given point to the boundary of the rounded 
@@ -0,0 +1,150 @@
+#define_import_path bevy_ui_gradients::gradient_functions
+
+const PI: f32 = 3.14159265358979323846;
+const TAU: f32 = 2. * PI;
+
+const MAX_MATERIAL_STOPS: u32 = 16u;
+
+const LINEAR: u32 = 0u;
+const RADIAL: u32 = 1u;
+const CONIC: u32 = 2u;
+const INSET: u32 = 3u;
+const PLASMA: u32 = 4u;
+
+const SPREAD_PAD: u32 = 0u;
+const SPREAD_REPEAT: u32 = 1u;
+const SPREAD_REFLECT: u32 = 2u;
+
+struct GradientMaterialUniform {
+    kind: u32,
+    stops_len: u32,
+    start: vec2<f32>,
+    dir: vec2<f32>,
+    spread: u32,
+    colors: array<vec4<f32>, MAX_MATERIAL_STOPS>,
+    // x: distance along the gradient line, y: hint, zw: packed interpolation to the following stop
+    stops: array<vec4<f32>, MAX_MATERIAL_STOPS>,
+};
+
+// This function converts two linear rgb colors to srgb space, mixes them, and then converts the result back to linear rgb space.
+fn mix_linear_rgb_in_srgb_space(a: vec4<f32>, b: vec4<f32>, t: f32) -> vec4<f32> {
+    let a_srgb = pow(a.rgb, vec3(1. / 2.2));
+    let b_srgb = pow(b.rgb, vec3(1. / 2.2));
+    let mixed_srgb = mix(a_srgb, b_srgb, t);
+    return vec4(pow(mixed_srgb, vec3(2.2)), mix(a.a, b.a, t));
+}
+
+// Remaps `t` by the interpolation of the segment, see `segment_interpolation` in `gradient.wgsl`.
+fn segment_interpolation(t: f32, a: f32, b: f32) -> f32 {
+    if a == -2. {
+        return smoothstep(0., 1., t);
+    }
+    if a == -3. {
+        return step(0.5, t);
+    }
+    if a < 0. {
+        return t;
+    }
+    return cubic_bezier(t, unpack_bezier_point(a), unpack_bezier_point(b));
+}
+
+fn unpack_bezier_point(packed: f32) -> vec2<f32> {
+    let q = u32(packed);
+    let n = vec2(f32(q & 0xfffu), f32(q >> 12u)) / 4095.;
+    return vec2(n.x, 3. * n.y - 1.);
+}
+
+fn bezier_component(s: f32, a: f32, b: f32) -> f32 {
+    let u = 1. - s;
+    return 3. * u * u * s * a + 3. * u * s * s * b + s * s * s;
+}
+
+fn bezier_derivative(s: f32, a: f32, b: f32) -> f32 {
+    let u = 1. - s;
+    return 3. * u * u * a + 6. * u * s * (b - a) + 3. * s * s * (1. - b);
+}
+
+fn cubic_bezier(x: f32, p1: vec2<f32>, p2: vec2<f32>) -> f32 {
+    var s = x;
+    for (var i = 0; i < 8; i += 1) {
+        let dx = bezier_derivative(s, p1.x, p2.x);
+        if abs(dx) < 1e-6 {
+            break;
+        }
+        s = clamp(s - (bezier_component(s, p1.x, p2.x) - x) / dx, 0., 1.);
+    }
+    return clamp(bezier_component(s, p1.y, p2.y), 0., 1.);
+}
+
+// Distance in gradient space from the start of the gradient to the point.
+fn gradient_distance(gradient: GradientMaterialUniform, point: vec2<f32>) -> f32 {
+    let d = point - gradient.start;
+    if gradient.kind == RADIAL {
+        return length(vec2(d.x, d.y * gradient.dir.x));
+    } else if gradient.kind == CONIC {
+        let angle = atan2(-d.x, d.y * gradient.dir.y) + PI;
+        return (((angle - gradient.dir.x) % TAU) + TAU) % TAU;
+    } else if gradient.kind == INSET {
+        // The distance inward from the edges of the box
+        let q = abs(d) - 0.5 * gradient.dir;
+        return -length(max(q, vec2(0.))) - min(max(q.x, q.y), 0.);
+    } else if gradient.kind == PLASMA {
+        // The plasma at time zero, see `plasma` in `gradient.wgsl`
+        let scale = gradient.dir.x;
+        let p = TAU * point / scale;
+        let c = p + vec2(0., 2.);
+        let v = sin(p.x) + sin(0.5 * p.y) + sin(0.5 * (p.x + p.y)) + sin(length(c));
+        return (0.5 + 0.125 * v) * scale;
+    }
+    return dot(d, gradient.dir);
+}
+
+// Wraps the distance into the period between the first and last stops of repeated and reflected gradients.
+fn spread_distance(gradient: GradientMaterialUniform, distance: f32) -> f32 {
+    let first = gradient.stops[0].x;
+    let length = gradient.stops[gradient.stops_len - 1u].x - first;
+    if gradient.spread == SPREAD_PAD || length <= 0. {
+        return distance;
+    }
+    let d = distance - first;
+    if gradient.spread == SPREAD_REFLECT {
+        let x = d - 2. * length * floor(d / (2. * length));
+        return first + length - abs(x - length);
+    }
+    return first + d - length * floor(d / length);
+}
+
+// Returns the color of the gradient at `point`, given relative to the center of the node in physical pixels.
+// The areas before the first stop and after the last stop are filled according to the gradient's spread method.
+fn gradient_color(gradient: GradientMaterialUniform, point: vec2<f32>) -> vec4<f32> {
+    if gradient.stops_len == 0u {
+        return vec4(0.);
+    }
+
+    let distance = spread_distance(gradient, gradient_distance(gradient, point));
+
+    if distance <= gradient.stops[0].x {
+        return gradient.colors[0];
+    }
+
+    for (var i = 1u; i < gradient.stops_len; i += 1u) {
+        let start = gradient.stops[i - 1u];
+        let end = gradient.stops[i];
+        if distance < end.x {
+            if start.x == end.x {
+                return gradient.colors[i];
+            }
+            var t = (distance - start.x) / (end.x - start.x);
+            let hint = start.y;
+            if t < hint {
+                t = 0.5 * t / hint;
+            } else {
+                t = 0.5 * (1 + (t - hint) / (1.0 - hint));
+            }
+            t = segment_interpolation(t, start.z, start.w);
+            return mix_linear_rgb_in_srgb_space(gradient.colors[i - 1u], gradient.colors[i], t);
+        }
+    }
+
+    return gradient.colors[gradient.stops_len - 1u];
+}
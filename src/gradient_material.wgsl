@@ -0,0 +1,23 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+#import bevy_ui_gradients::gradient_functions::{GradientMaterialUniform, gradient_color}
+
+@group(1) @binding(0) var<uniform> gradient: GradientMaterialUniform;
+
+// See `sd_rounded_box` in `gradient.wgsl`
+fn sd_rounded_box(point: vec2<f32>, size: vec2<f32>, corner_radii: vec4<f32>) -> f32 {
+    let rs = select(corner_radii.xy, corner_radii.wz, 0.0 < point.y);
+    let radius = select(rs.x, rs.y, 0.0 < point.x);
+    let corner_to_point = abs(point) - 0.5 * size;
+    let q = corner_to_point + radius;
+    let l = length(max(q, vec2(0.0)));
+    let m = min(max(q.x, q.y), 0.0);
+    return l + m - radius;
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let point = (in.uv - 0.5) * in.size;
+    let color = gradient_color(gradient, point);
+    let t = saturate(0.5 - sd_rounded_box(point, in.size, in.border_radius));
+    return vec4(color.rgb, saturate(color.a * t));
+}
@@ -0,0 +1,75 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        entity::Entity,
+        hierarchy::{ChildOf, Children},
+        query::With,
+        system::{Commands, Query},
+    },
+    math::Rect,
+    prelude::DetectChangesMut,
+    transform::components::GlobalTransform,
+};
+
+/// Marks a node whose children resolve their gradients against the bounding box of all the children,
+/// instead of against their own nodes.
+///
+/// Giving the children the same gradients makes them look like they've been cut out of one continuous
+/// gradient, for segmented progress bars and split headers.
+/// The children's origin boxes are ignored, and gradients with [`GradientAttachment::Fixed`] are still resolved against the viewport.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct GradientGroup;
+
+/// The bounding box of the children of a [`GradientGroup`] node, in physical pixels.
+///
+/// Added to and updated on the children of group nodes automatically, and removed when a node leaves its group.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct GradientGroupBounds {
+    /// The center of the bounding box, relative to the top-left corner of the viewport
+    pub center: Vec2,
+    /// The size of the bounding box
+    pub size: Vec2,
+}
+
+/// Updates the [`GradientGroupBounds`] of the children of [`GradientGroup`] nodes.
+pub fn update_gradient_group_bounds(
+    mut commands: Commands,
+    groups: Query<&Children, With<GradientGroup>>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+    mut members: Query<(Entity, Option<&ChildOf>, &mut GradientGroupBounds)>,
+) {
+    for children in &groups {
+        let Some(bounds) = children
+            .iter()
+            .filter_map(|&child| nodes.get(child).ok())
+            .map(|(node, transform)| {
+                Rect::from_center_size(transform.translation().truncate(), node.size())
+            })
+            .reduce(|a, b| a.union(b))
+        else {
+            continue;
+        };
+        let bounds = GradientGroupBounds {
+            center: bounds.center(),
+            size: bounds.size(),
+        };
+        for &child in children.iter().filter(|&&child| nodes.contains(child)) {
+            match members.get_mut(child) {
+                Ok((_, _, mut member_bounds)) => {
+                    member_bounds.set_if_neq(bounds);
+                }
+                Err(_) => {
+                    commands.entity(child).insert(bounds);
+                }
+            }
+        }
+    }
+
+    for (entity, child_of, _) in &members {
+        if child_of.is_none_or(|child_of| !groups.contains(child_of.parent())) {
+            commands.entity(entity).remove::<GradientGroupBounds>();
+        }
+    }
+}
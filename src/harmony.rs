@@ -0,0 +1,108 @@
+use crate::smart::clamp_to_srgb;
+use crate::*;
+use bevy::color::{Hue, Oklcha};
+
+/// Color harmony rules for deriving a gradient theme from a single base color, such as a game's accent color.
+///
+/// The harmonies rotate the base color's hue, or shift its lightness, in OKLCH space, so the derived colors
+/// have the same perceived lightness and chroma as the base color. Colors outside of the sRGB gamut are clamped.
+///
+/// ```
+/// # use bevy_ui_gradients::{ColorHarmony, LinearGradient};
+/// # use bevy::color::palettes::css::CRIMSON;
+/// let palette = ColorHarmony::Triadic.palette(CRIMSON);
+/// assert_eq!(palette.0.len(), 3);
+/// let banner = ColorHarmony::Analogous.linear_gradient(CRIMSON, LinearGradient::TO_RIGHT);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(PartialEq, Debug)]
+pub enum ColorHarmony {
+    /// The base color between its neighbours 30 degrees around the hue wheel
+    Analogous,
+    /// The base color and the color opposite it on the hue wheel
+    Complementary,
+    /// The base color and the two colors a third and two thirds of the way around the hue wheel
+    Triadic,
+    /// Five shades of the base color, from dark to light, with the base color in the middle
+    Monochrome,
+}
+
+impl ColorHarmony {
+    /// All the harmonies
+    pub const ALL: [Self; 4] = [
+        Self::Analogous,
+        Self::Complementary,
+        Self::Triadic,
+        Self::Monochrome,
+    ];
+
+    /// The colors of the harmony derived from `base`
+    pub fn palette(self, base: impl Into<Color>) -> Palette {
+        let base = Oklcha::from(base.into());
+        let rotate = |degrees: f32| base.with_hue((base.hue + degrees).rem_euclid(360.));
+        let colors = match self {
+            ColorHarmony::Analogous => vec![rotate(-30.), base, rotate(30.)],
+            ColorHarmony::Complementary => vec![base, rotate(180.)],
+            ColorHarmony::Triadic => vec![base, rotate(120.), rotate(240.)],
+            ColorHarmony::Monochrome => (-2..=2)
+                .map(|step| {
+                    base.with_lightness((base.lightness + 0.15 * step as f32).clamp(0.05, 0.97))
+                })
+                .collect(),
+        };
+        Palette(colors.into_iter().map(clamp_to_srgb).collect())
+    }
+
+    /// Color stops through the harmony's colors. Adjacent colors are blended with [`ColorStop::smart_blend`],
+    /// so the gradient doesn't pass through gray between complementary hues.
+    pub fn color_stops(self, base: impl Into<Color>) -> Vec<ColorStop> {
+        blend_colors(&self.palette(base).0)
+    }
+
+    /// A linear gradient through the harmony's colors
+    pub fn linear_gradient(
+        self,
+        base: impl Into<Color>,
+        angle: impl Into<Angle>,
+    ) -> LinearGradient {
+        LinearGradient::new(angle, self.color_stops(base))
+    }
+
+    /// A radial gradient through the harmony's colors, from the center to the end shape
+    pub fn radial_gradient(
+        self,
+        base: impl Into<Color>,
+        position: Position,
+        shape: RadialGradientShape,
+    ) -> RadialGradient {
+        RadialGradient::new(position, shape, self.color_stops(base))
+    }
+
+    /// A conic gradient through the harmony's colors. The first color is repeated at the end, so there's no seam.
+    pub fn conic_gradient(self, base: impl Into<Color>) -> ConicGradient {
+        let mut colors = self.palette(base).0;
+        if let Some(&first) = colors.first() {
+            colors.push(first);
+        }
+        ConicGradient::new(
+            blend_colors(&colors)
+                .into_iter()
+                .map(|stop| AngularColorStop::auto(stop.color)),
+        )
+    }
+}
+
+/// Smart blends between each pair of adjacent colors, without repeating the colors where the blends meet
+fn blend_colors(colors: &[Color]) -> Vec<ColorStop> {
+    match colors {
+        [] => vec![],
+        [color] => vec![ColorStop::auto(*color)],
+        _ => {
+            let mut stops = vec![ColorStop::auto(colors[0])];
+            for pair in colors.windows(2) {
+                stops.extend(ColorStop::smart_blend(pair[0], pair[1]).into_iter().skip(1));
+            }
+            stops
+        }
+    }
+}
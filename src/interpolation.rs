@@ -0,0 +1,92 @@
+use crate::*;
+
+/// How colors are interpolated between a color stop and the following stop.
+///
+/// The interpolation is applied after the stop's hint, so a hint still moves the segment's midpoint.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum Interpolation {
+    /// The colors are mixed linearly
+    #[default]
+    Linear,
+    /// The colors are mixed with a smoothstep curve, easing in and out of each stop
+    SmoothStep,
+    /// A cubic Bézier curve through `(0, 0)`, the two control points and `(1, 1)`, like CSS `cubic-bezier()`.
+    /// The `x` coordinates of the control points are clamped between `0.` and `1.`, and the `y`
+    /// coordinates between `-1.` and `2.`. Curves that overshoot are clamped, so the colors stay between the stops' colors.
+    CubicBezier(Vec2, Vec2),
+    /// The color jumps from the stop's color to the following stop's color at the midpoint
+    Step,
+}
+
+impl Interpolation {
+    /// Remaps the fraction `t` of the distance between two stops to the fraction of the second stop's color that's mixed in
+    pub fn sample(self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Interpolation::Linear => t,
+            Interpolation::SmoothStep => t * t * (3. - 2. * t),
+            Interpolation::CubicBezier(p1, p2) => cubic_bezier(
+                t,
+                p1.clamp(BEZIER_MIN, BEZIER_MAX),
+                p2.clamp(BEZIER_MIN, BEZIER_MAX),
+            ),
+            Interpolation::Step => {
+                if t < 0.5 {
+                    0.
+                } else {
+                    1.
+                }
+            }
+        }
+    }
+
+    /// Packs the interpolation into two floats for the shaders, see `segment_interpolation` in `gradient.wgsl`.
+    /// The modes without parameters are negative, otherwise the floats are the Bézier control points packed
+    /// into integers that are exactly representable as `f32`s.
+    pub(crate) fn pack(self) -> [f32; 2] {
+        match self {
+            Interpolation::Linear => [-1., 0.],
+            Interpolation::SmoothStep => [-2., 0.],
+            Interpolation::Step => [-3., 0.],
+            Interpolation::CubicBezier(p1, p2) => [pack_bezier_point(p1), pack_bezier_point(p2)],
+        }
+    }
+}
+
+const BEZIER_MIN: Vec2 = Vec2::new(0., -1.);
+const BEZIER_MAX: Vec2 = Vec2::new(1., 2.);
+
+/// Quantizes each coordinate to 12 bits
+fn pack_bezier_point(point: Vec2) -> f32 {
+    let q = ((point.clamp(BEZIER_MIN, BEZIER_MAX) - BEZIER_MIN) / (BEZIER_MAX - BEZIER_MIN)
+        * 4095.)
+        .round()
+        .as_uvec2();
+    (q.x | q.y << 12) as f32
+}
+
+/// Solves for the curve parameter at `x` with Newton's method, then evaluates `y`.
+/// See `cubic_bezier` in `gradient.wgsl`.
+fn cubic_bezier(x: f32, p1: Vec2, p2: Vec2) -> f32 {
+    let mut s = x;
+    for _ in 0..8 {
+        let dx = bezier_derivative(s, p1.x, p2.x);
+        if dx.abs() < 1e-6 {
+            break;
+        }
+        s = (s - (bezier_component(s, p1.x, p2.x) - x) / dx).clamp(0., 1.);
+    }
+    bezier_component(s, p1.y, p2.y).clamp(0., 1.)
+}
+
+/// A coordinate of a cubic Bézier from `0.` to `1.` with control point coordinates `a` and `b`
+fn bezier_component(s: f32, a: f32, b: f32) -> f32 {
+    let u = 1. - s;
+    3. * u * u * s * a + 3. * u * s * s * b + s * s * s
+}
+
+fn bezier_derivative(s: f32, a: f32, b: f32) -> f32 {
+    let u = 1. - s;
+    3. * u * u * a + 6. * u * s * (b - a) + 3. * s * s * (1. - b)
+}
@@ -0,0 +1,139 @@
+use core::marker::PhantomData;
+
+use crate::*;
+use bevy::{
+    asset::{Assets, Handle, RenderAssetUsages},
+    ecs::{
+        entity::Entity,
+        query::Changed,
+        system::{Commands, Query, ResMut},
+    },
+    image::{Image, ImageFilterMode, ImageSampler, ImageSamplerDescriptor},
+    math::UVec2,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+    ui::Node,
+};
+
+/// A custom gradient shape, evaluated on the CPU.
+///
+/// Implement this for a component and add a [`GradientKindPlugin`] for it. When the component changes,
+/// its parameterization is baked into a lookup texture, which is stored in a [`GradientLut`] on the same entity
+/// and drawn by the gradient pipeline like the built-in gradients, with the same color stops and spread methods.
+///
+/// Custom gradients are drawn over the node's background color and image and beneath its [`BackgroundGradient`]s.
+pub trait GradientKind: Component {
+    /// The position along the gradient line at `point`, from `0.` at the start of the line to `1.` at its end.
+    /// `point` is the position in the node, from `(0., 0.)` at the top left corner to `(1., 1.)` at the bottom right.
+    fn parameterize(&self, point: Vec2) -> f32;
+
+    /// The gradient's color stops.
+    /// Stop lengths are resolved against a gradient line as long as the node's width.
+    fn stops(&self) -> Vec<ColorStop>;
+
+    /// How the areas before the first color stop and after the last color stop are filled
+    fn spread(&self) -> SpreadMethod {
+        SpreadMethod::Pad
+    }
+
+    /// The size of the lookup texture in texels. The texture is sampled with linear filtering,
+    /// so smooth parameterizations don't need a high resolution.
+    fn resolution(&self) -> UVec2 {
+        UVec2::splat(64)
+    }
+
+    /// How the lookup texture is filtered. With [`ImageFilterMode::Nearest`] each texel is drawn as a
+    /// flat block of the color at its center, for crisp palette bands in pixel-art games.
+    fn filter(&self) -> ImageFilterMode {
+        ImageFilterMode::Linear
+    }
+}
+
+/// Bakes the [`GradientKind`] component `K` into [`GradientLut`]s
+pub struct GradientKindPlugin<K: GradientKind>(PhantomData<K>);
+
+impl<K: GradientKind> Default for GradientKindPlugin<K> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<K: GradientKind> Plugin for GradientKindPlugin<K> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, bake_gradient_kinds::<K>);
+    }
+}
+
+/// A gradient drawn from a lookup texture of positions along its gradient line,
+/// baked from a [`GradientKind`] by [`bake_gradient_kinds`].
+#[derive(Component, Clone, Debug, PartialEq)]
+#[require(Node)]
+pub struct GradientLut {
+    /// A texture with the position along the gradient line across the node in its red channel,
+    /// as a fraction of the line's length
+    pub image: Handle<Image>,
+    /// The list of color stops
+    pub stops: Vec<ColorStop>,
+    /// How the areas before the first color stop and after the last color stop are filled
+    pub spread: SpreadMethod,
+}
+
+impl GradientLut {
+    /// A lookup texture of `parameterize` sampled at the centers of the texels of a texture with the given `resolution`,
+    /// with linear filtering
+    pub fn bake(parameterize: impl Fn(Vec2) -> f32, resolution: UVec2) -> Image {
+        Self::bake_with_filter(parameterize, resolution, ImageFilterMode::Linear)
+    }
+
+    /// A lookup texture like [`GradientLut::bake`], sampled with the given `filter`
+    pub fn bake_with_filter(
+        parameterize: impl Fn(Vec2) -> f32,
+        resolution: UVec2,
+        filter: ImageFilterMode,
+    ) -> Image {
+        let resolution = resolution.max(UVec2::ONE);
+        let data = (0..resolution.y)
+            .flat_map(|y| (0..resolution.x).map(move |x| UVec2::new(x, y)))
+            .flat_map(|texel| {
+                let point = (texel.as_vec2() + 0.5) / resolution.as_vec2();
+                half::f16::from_f32(parameterize(point)).to_le_bytes()
+            })
+            .collect();
+        let mut image = Image::new(
+            Extent3d {
+                width: resolution.x,
+                height: resolution.y,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::R16Float,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+            mag_filter: filter,
+            min_filter: filter,
+            ..Default::default()
+        });
+        image
+    }
+}
+
+/// Bakes changed [`GradientKind`] components into [`GradientLut`]s
+pub fn bake_gradient_kinds<K: GradientKind>(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    kinds: Query<(Entity, &K), Changed<K>>,
+) {
+    for (entity, kind) in &kinds {
+        let image = GradientLut::bake_with_filter(
+            |point| kind.parameterize(point),
+            kind.resolution(),
+            kind.filter(),
+        );
+        commands.entity(entity).insert(GradientLut {
+            image: images.add(image),
+            stops: kind.stops(),
+            spread: kind.spread(),
+        });
+    }
+}
@@ -0,0 +1,251 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        entity::Entity,
+        hierarchy::Children,
+        query::Changed,
+        system::{Commands, Query},
+    },
+    ui::{
+        AlignItems, BackgroundColor, FlexDirection, JustifyContent, Node, PositionType,
+        widget::Text,
+    },
+};
+
+/// The direction of a [`GradientLegend`]'s bar
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(Default, PartialEq, Debug)]
+pub enum LegendOrientation {
+    /// The bar runs from its minimum on the left to its maximum on the right
+    #[default]
+    Horizontal,
+    /// The bar runs from its minimum at the bottom to its maximum at the top
+    Vertical,
+}
+
+/// A color scale legend for charts and minimaps: a bar showing a gradient, with tick marks and labels
+/// for its minimum and maximum values.
+///
+/// The legend's contents are spawned as children of its node, replacing any other children, and are rebuilt
+/// whenever the legend changes. The labels are spawned with the [`GradientLegendMinLabel`] and
+/// [`GradientLegendMaxLabel`] markers so they can be queried and styled.
+///
+/// The bar is always drawn as a linear gradient along its length, with the color stops of `gradient`.
+/// Stops of conic gradients are placed at their fractions of the full turn.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
+#[require(Node)]
+pub struct GradientLegend {
+    /// The gradient whose color stops are shown by the bar
+    pub gradient: Gradient,
+    /// The direction of the bar
+    pub orientation: LegendOrientation,
+    /// The thickness of the bar
+    pub thickness: Val,
+    /// The length of the tick marks
+    pub tick_length: Val,
+    /// Positions of the tick marks along the bar, between `0.` and `1.`
+    pub ticks: Vec<f32>,
+    /// The color of the tick marks
+    pub tick_color: Color,
+    /// The text of the minimum label
+    pub min_label: String,
+    /// The text of the maximum label
+    pub max_label: String,
+}
+
+impl GradientLegend {
+    /// Create a new horizontal legend for `gradient`, without ticks or labels
+    pub fn new(gradient: impl Into<Gradient>) -> Self {
+        Self {
+            gradient: gradient.into(),
+            orientation: LegendOrientation::Horizontal,
+            thickness: Val::Px(16.),
+            tick_length: Val::Px(6.),
+            ticks: vec![],
+            tick_color: Color::WHITE,
+            min_label: String::new(),
+            max_label: String::new(),
+        }
+    }
+
+    /// Sets the direction of the bar
+    pub fn with_orientation(mut self, orientation: LegendOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the thickness of the bar
+    pub fn with_thickness(mut self, thickness: Val) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the positions of the tick marks, between `0.` and `1.`
+    pub fn with_ticks(mut self, ticks: Vec<f32>) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets `count` evenly spaced tick marks, including ticks at both ends of the bar
+    pub fn with_even_ticks(mut self, count: usize) -> Self {
+        self.ticks = match count {
+            0 => vec![],
+            1 => vec![0.5],
+            _ => (0..count).map(|i| i as f32 / (count - 1) as f32).collect(),
+        };
+        self
+    }
+
+    /// Sets the color of the tick marks
+    pub fn with_tick_color(mut self, color: impl Into<Color>) -> Self {
+        self.tick_color = color.into();
+        self
+    }
+
+    /// Sets the text of the minimum and maximum labels
+    pub fn with_labels(mut self, min: impl Into<String>, max: impl Into<String>) -> Self {
+        self.min_label = min.into();
+        self.max_label = max.into();
+        self
+    }
+
+    /// The linear gradient drawn by the bar
+    pub fn bar_gradient(&self) -> LinearGradient {
+        let angle = match self.orientation {
+            LegendOrientation::Horizontal => LinearGradient::TO_RIGHT,
+            LegendOrientation::Vertical => LinearGradient::TO_TOP,
+        };
+        let stops = match &self.gradient {
+            Gradient::Linear(LinearGradient { stops, .. })
+            | Gradient::Radial(RadialGradient { stops, .. })
+            | Gradient::Inset(InsetGradient { stops, .. })
+            | Gradient::Plasma(PlasmaGradient { stops, .. }) => stops.clone(),
+            Gradient::Conic(_) => self
+                .gradient
+                .stops()
+                .map(|stop| ColorStop {
+                    color: stop.color,
+                    point: stop
+                        .position
+                        .map(|position| Val::Percent(100. * position))
+                        .unwrap_or(Val::Auto),
+                    hint: stop.hint,
+                    hint_point: None,
+                    interpolation: stop.interpolation,
+                    end_point: None,
+                    oscillation: None,
+                })
+                .collect(),
+        };
+        let layer = self.gradient.layer();
+        LinearGradient {
+            stops,
+            layer: GradientLayer {
+                spread: layer.spread,
+                filter: layer.filter,
+                smoothing: layer.smoothing,
+                blend_mode: layer.blend_mode,
+                ..Default::default()
+            },
+            ..LinearGradient::new(angle, vec![])
+        }
+    }
+}
+
+/// Marks the text node of a [`GradientLegend`]'s minimum label
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct GradientLegendMinLabel;
+
+/// Marks the text node of a [`GradientLegend`]'s maximum label
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct GradientLegendMaxLabel;
+
+/// Rebuilds the children of changed [`GradientLegend`]s
+pub fn update_gradient_legends(
+    mut commands: Commands,
+    legends: Query<(Entity, &GradientLegend), Changed<GradientLegend>>,
+) {
+    for (entity, legend) in &legends {
+        let horizontal = legend.orientation == LegendOrientation::Horizontal;
+        let full = Val::Percent(100.);
+        // Lengths along and across the bar
+        let width = |along: Val, across: Val| if horizontal { along } else { across };
+        let height = |along: Val, across: Val| if horizontal { across } else { along };
+        let bar = (
+            Node {
+                width: width(full, legend.thickness),
+                height: height(full, legend.thickness),
+                ..Default::default()
+            },
+            BackgroundGradient::from(legend.bar_gradient()),
+        );
+        let tick_track = Node {
+            width: width(full, legend.tick_length),
+            height: height(full, legend.tick_length),
+            ..Default::default()
+        };
+        let labels = Node {
+            flex_direction: if horizontal {
+                FlexDirection::Row
+            } else {
+                FlexDirection::ColumnReverse
+            },
+            justify_content: JustifyContent::SpaceBetween,
+            ..Default::default()
+        };
+
+        commands
+            .entity(entity)
+            .despawn_related::<Children>()
+            .with_children(|commands| {
+                commands
+                    .spawn(Node {
+                        width: full,
+                        height: full,
+                        flex_direction: if horizontal {
+                            FlexDirection::Column
+                        } else {
+                            FlexDirection::Row
+                        },
+                        align_items: AlignItems::Stretch,
+                        ..Default::default()
+                    })
+                    .with_children(|commands| {
+                        commands.spawn(bar);
+                        commands.spawn(tick_track).with_children(|commands| {
+                            for &tick in &legend.ticks {
+                                let offset = Val::Percent(100. * tick.clamp(0., 1.));
+                                let mut node = Node {
+                                    position_type: PositionType::Absolute,
+                                    width: width(Val::Px(1.), full),
+                                    height: height(Val::Px(1.), full),
+                                    ..Default::default()
+                                };
+                                // Center the tick mark on its position
+                                if horizontal {
+                                    node.left = offset;
+                                    node.margin.left = Val::Px(-0.5);
+                                } else {
+                                    node.bottom = offset;
+                                    node.margin.bottom = Val::Px(-0.5);
+                                }
+                                commands.spawn((node, BackgroundColor(legend.tick_color)));
+                            }
+                        });
+                        commands.spawn(labels).with_children(|commands| {
+                            commands.spawn((
+                                Text::new(legend.min_label.clone()),
+                                GradientLegendMinLabel,
+                            ));
+                            commands.spawn((
+                                Text::new(legend.max_label.clone()),
+                                GradientLegendMaxLabel,
+                            ));
+                        });
+                    });
+            });
+    }
+}
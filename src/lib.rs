@@ -1,15 +1,131 @@
+mod accessibility;
+mod adjust;
+mod angle;
+mod animation;
+mod animation_clip;
+mod asset;
+mod backdrop;
+mod baked;
+mod button;
+mod clipboard;
+mod color_picker;
+mod colormap;
+mod contrast;
+mod css;
+mod custom_function;
+mod dev_tools;
+mod diagnostics;
+#[cfg(feature = "editor")]
+mod editor;
+mod export;
+mod fade;
+mod generator;
+mod glow;
+mod group;
+mod harmony;
+mod interpolation;
+mod kind;
+mod legend;
+mod material;
+mod opacity;
+mod palette;
+mod picking;
+mod quantize;
+mod ramp;
+#[cfg(feature = "render-internals")]
+pub mod render;
+#[cfg(not(feature = "render-internals"))]
 mod render;
-
-use bevy::app::{App, Plugin};
-use bevy::color::{Color, Srgba};
+mod sample;
+mod shadow;
+mod shared;
+mod sky;
+mod slider;
+mod smart;
+mod smoothing;
+mod static_gradient;
+mod stop_table;
+mod stroke;
+#[cfg(feature = "stylesheet")]
+mod stylesheet;
+mod theme;
+mod timeline;
+mod transition;
+mod validation;
+
+pub use accessibility::*;
+pub use angle::*;
+pub use animation::*;
+pub use animation_clip::*;
+pub use asset::*;
+pub use backdrop::*;
+pub use baked::*;
+use bevy::app::{App, Plugin, PostUpdate, PreUpdate, Update};
+use bevy::asset::{AssetApp, Handle};
+use bevy::color::{Alpha, Color, LinearRgba, Srgba};
 use bevy::ecs::component::Component;
-use bevy::math::Vec2;
-use bevy::prelude::ReflectDefault;
+use bevy::ecs::resource::Resource;
+use bevy::ecs::schedule::IntoScheduleConfigs;
+use bevy::image::Image;
+use bevy::math::{Vec2, Vec3};
+use bevy::picking::PickSet;
+use bevy::prelude::{ReflectComponent, ReflectDefault, ReflectResource};
+use bevy::sprite::BorderRect;
+use bevy::transform::TransformSystem;
+use bevy::ui::{ComputedNode, UiMaterialPlugin, UiSystem};
 use bevy::utils::default;
 use bevy::{reflect::Reflect, ui::Val};
+pub use button::*;
+pub use clipboard::*;
+pub use color_picker::*;
+pub use colormap::*;
+pub use contrast::*;
 use core::{f32, f32::consts::TAU};
+pub use css::*;
+pub use custom_function::*;
+pub use dev_tools::*;
+pub use diagnostics::*;
+#[cfg(feature = "editor")]
+pub use editor::*;
+pub use export::*;
+pub use fade::*;
+pub use generator::*;
+pub use glow::*;
+pub use group::*;
+pub use harmony::*;
+pub use interpolation::*;
+pub use kind::*;
+pub use legend::*;
+use material::build_gradient_material;
+pub use material::*;
+pub use opacity::*;
+pub use palette::*;
+pub use picking::*;
+pub use quantize::*;
+pub use ramp::*;
+pub use render::{GradientShaderOverride, UI_GRADIENT_SHADER_HANDLE, UiGradientPipelineKey};
 use render::{build_gradients_renderer, finish_gradients_renderer};
-
+pub use sample::*;
+pub use shadow::*;
+pub use shared::*;
+pub use sky::*;
+pub use slider::*;
+use smallvec::SmallVec;
+pub use smart::*;
+pub use smoothing::*;
+pub use static_gradient::*;
+pub use stop_table::*;
+pub use stroke::*;
+#[cfg(feature = "stylesheet")]
+pub use stylesheet::*;
+pub use theme::*;
+pub use timeline::*;
+pub use transition::*;
+pub use validation::*;
+
+/// Converts logical pixel values to physical pixels.
+/// `scale_factor` is the node's target scale factor from `ComputedNodeTarget`, which already
+/// includes `UiScale`.
 fn scale_val(val: Val, scale_factor: f32) -> Val {
     match val {
         Val::Px(px) => Val::Px(px * scale_factor),
@@ -153,6 +269,10 @@ impl Position {
     }
 
     /// Resolves the `Position` into physical coordinates.
+    ///
+    /// `scale_factor` is the node's target scale factor, including `UiScale`.
+    /// `physical_target_size` is the size of the viewport of the UI node's camera, which may be
+    /// an image when the UI is rendered to a texture.
     pub fn resolve(
         self,
         scale_factor: f32,
@@ -185,6 +305,52 @@ impl From<(Val, Val)> for Position {
     }
 }
 
+/// Animates the position and brightness of a color stop in the shader, using the time from bevy's globals uniform,
+/// so breathing highlights don't need the gradient components to change every frame.
+///
+/// Both follow a sine wave. The gradient sampled on the CPU by [`GradientSampler`] and
+/// [`Gradient::color_at`] isn't oscillated. Oscillations are ignored by [`GradientMask`]s and [`GradientMaterial`]s.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Reflect)]
+#[reflect(Default, PartialEq, Debug)]
+pub struct StopOscillation {
+    /// How far the stop moves back and forth along the gradient line, in logical pixels for [`ColorStop`]s
+    /// and radians for [`AngularColorStop`]s.
+    /// The stop shouldn't be moved past its neighbours.
+    pub position: f32,
+    /// How much the brightness of the stop's color rises and falls, as a fraction of its brightness
+    pub brightness: f32,
+    /// Cycles per second
+    pub frequency: f32,
+    /// Offset of the wave, as a fraction of a cycle
+    pub phase: f32,
+}
+
+impl StopOscillation {
+    /// Moves the stop back and forth by `amplitude` at `frequency` cycles per second
+    pub fn position(amplitude: f32, frequency: f32) -> Self {
+        Self {
+            position: amplitude,
+            frequency,
+            ..Default::default()
+        }
+    }
+
+    /// Raises and lowers the brightness of the stop's color by `amplitude` at `frequency` cycles per second
+    pub fn brightness(amplitude: f32, frequency: f32) -> Self {
+        Self {
+            brightness: amplitude,
+            frequency,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the offset of the wave, as a fraction of a cycle
+    pub fn with_phase(mut self, phase: f32) -> Self {
+        self.phase = phase;
+        self
+    }
+}
+
 /// A color stop for a gradient
 #[derive(Debug, Copy, Clone, PartialEq, Reflect)]
 #[reflect(Default, PartialEq, Debug)]
@@ -196,33 +362,155 @@ pub struct ColorStop {
     pub point: Val,
     /// Normalized position between this and the following stop of the interpolation midpoint.
     pub hint: f32,
+    /// If set, the interpolation midpoint is at this position along the gradient line instead of at `hint`,
+    /// like a CSS color hint. It's converted to the normalized position between the stops when the stops are resolved.
+    pub hint_point: Option<Val>,
+    /// How colors are interpolated between this and the following stop
+    pub interpolation: Interpolation,
+    /// If set, the color is held from `point` to `end_point`, like a CSS color stop with two positions.
+    /// The stop is expanded into a pair of stops when it's resolved.
+    /// Ignored if `point` is [`Val::Auto`].
+    pub end_point: Option<Val>,
+    /// If set, the stop is animated in the shader
+    pub oscillation: Option<StopOscillation>,
 }
 
 impl ColorStop {
     /// Create a new color stop
     pub fn new(color: impl Into<Color>, point: Val) -> Self {
+        Self::const_new(color.into(), point)
+    }
+
+    /// Create a new color stop in a `const` context, like [`ColorStop::new`]
+    pub const fn const_new(color: Color, point: Val) -> Self {
         Self {
-            color: color.into(),
+            color,
             point,
             hint: 0.5,
+            hint_point: None,
+            interpolation: Interpolation::Linear,
+            end_point: None,
+            oscillation: None,
+        }
+    }
+
+    /// A color stop at `percent` of the length of the gradient line, in a `const` context
+    pub const fn const_percent(color: Color, percent: f32) -> Self {
+        Self::const_new(color, Val::Percent(percent))
+    }
+
+    /// An automatic color stop in a `const` context, like [`ColorStop::auto`]
+    pub const fn const_auto(color: Color) -> Self {
+        Self::const_new(color, Val::Auto)
+    }
+
+    /// A color stop at `percent` of the length of the gradient line
+    pub fn percent(color: impl Into<Color>, percent: f32) -> Self {
+        Self::new(color, Val::Percent(percent))
+    }
+
+    /// A band of solid `color` from `from` to `to`, like the CSS color stop `red 10% 30%`
+    ///
+    /// ```
+    /// # use bevy_ui_gradients::{ColorStop, LinearGradient};
+    /// # use bevy::{color::palettes::css::{RED, BLUE}, ui::Val};
+    /// // A flag with hard edged bands
+    /// let flag = LinearGradient::to_right(vec![
+    ///     ColorStop::span(RED, Val::Percent(0.), Val::Percent(50.)),
+    ///     ColorStop::span(BLUE, Val::Percent(50.), Val::Percent(100.)),
+    /// ]);
+    /// ```
+    pub fn span(color: impl Into<Color>, from: Val, to: Val) -> Self {
+        Self {
+            end_point: Some(to),
+            ..Self::new(color, from)
         }
     }
 
+    /// The stop, or the pair of stops at its start and end if it has an `end_point`.
+    /// The hints are kept by the last stop of the pair.
+    pub fn expand(self) -> impl Iterator<Item = Self> {
+        let end = self
+            .end_point
+            .filter(|_| self.point != Val::Auto)
+            .map(|end_point| Self {
+                point: end_point,
+                end_point: None,
+                ..self
+            });
+        let start = match end {
+            Some(_) => Self {
+                hint: 0.5,
+                hint_point: None,
+                interpolation: Interpolation::Linear,
+                end_point: None,
+                ..self
+            },
+            None => Self {
+                end_point: None,
+                ..self
+            },
+        };
+        core::iter::once(start).chain(end)
+    }
+
     /// An automatic color stop.
     /// The positions of automatic stops are interpolated evenly between explicit stops.
     pub fn auto(color: impl Into<Color>) -> Self {
-        Self {
-            color: color.into(),
-            point: Val::Auto,
-            hint: 0.5,
-        }
+        Self::const_auto(color.into())
+    }
+
+    /// Color stops from a table of percentages and colors
+    ///
+    /// ```
+    /// # use bevy_ui_gradients::ColorStop;
+    /// # use bevy::color::Color;
+    /// let stops = ColorStop::percentages(&[(0., Color::BLACK), (40., Color::WHITE)]);
+    /// assert_eq!(stops[1], ColorStop::percent(Color::WHITE, 40.));
+    /// ```
+    pub fn percentages(stops: &[(f32, Color)]) -> Vec<Self> {
+        stops.iter().copied().map(Self::from).collect()
     }
 
     // Set the interpolation midpoint between this and and the following stop
-    pub fn with_hint(mut self, hint: f32) -> Self {
+    pub const fn with_hint(mut self, hint: f32) -> Self {
         self.hint = hint;
         self
     }
+
+    /// Sets the position of the interpolation midpoint between this and the following stop along the gradient line
+    ///
+    /// ```
+    /// # use bevy_ui_gradients::{ColorStop, LinearGradient};
+    /// # use bevy::{color::Color, ui::Val};
+    /// // Like the CSS `linear-gradient(black 20px, 30px, white 100px)`
+    /// let gradient = LinearGradient::to_right(vec![
+    ///     ColorStop::new(Color::BLACK, Val::Px(20.)).with_hint_point(Val::Px(30.)),
+    ///     ColorStop::new(Color::WHITE, Val::Px(100.)),
+    /// ]);
+    /// ```
+    pub const fn with_hint_point(mut self, hint_point: Val) -> Self {
+        self.hint_point = Some(hint_point);
+        self
+    }
+
+    /// Sets how colors are interpolated between this and the following stop
+    pub const fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Holds the stop's color until `end_point`
+    pub const fn with_end_point(mut self, end_point: Val) -> Self {
+        self.end_point = Some(end_point);
+        self
+    }
+
+    /// Animates the stop in the shader
+    pub const fn with_oscillation(mut self, oscillation: StopOscillation) -> Self {
+        self.oscillation = Some(oscillation);
+        self
+    }
 }
 
 impl From<(Color, Val)> for ColorStop {
@@ -231,16 +519,30 @@ impl From<(Color, Val)> for ColorStop {
             color,
             point: stop,
             hint: 0.5,
+            hint_point: None,
+            interpolation: Interpolation::Linear,
+            end_point: None,
+            oscillation: None,
         }
     }
 }
 
+impl From<(f32, Color)> for ColorStop {
+    fn from((percent, color): (f32, Color)) -> Self {
+        Self::percent(color, percent)
+    }
+}
+
 impl From<Color> for ColorStop {
     fn from(color: Color) -> Self {
         Self {
             color,
             point: Val::Auto,
             hint: 0.5,
+            hint_point: None,
+            interpolation: Interpolation::Linear,
+            end_point: None,
+            oscillation: None,
         }
     }
 }
@@ -251,6 +553,10 @@ impl From<Srgba> for ColorStop {
             color: color.into(),
             point: Val::Auto,
             hint: 0.5,
+            hint_point: None,
+            interpolation: Interpolation::Linear,
+            end_point: None,
+            oscillation: None,
         }
     }
 }
@@ -261,6 +567,60 @@ impl Default for ColorStop {
             color: Color::WHITE,
             point: Val::Auto,
             hint: 0.5,
+            hint_point: None,
+            interpolation: Interpolation::Linear,
+            end_point: None,
+            oscillation: None,
+        }
+    }
+}
+
+/// The angle of an [`AngularColorStop`], relative to the start of the gradient
+#[derive(Default, Debug, Copy, Clone, PartialEq, Reflect)]
+#[reflect(Default, PartialEq, Debug)]
+pub enum AngularPosition {
+    /// Interpolated between the explicit stops
+    #[default]
+    Auto,
+    /// An angle in radians
+    Radians(f32),
+    /// A percentage of the full turn
+    Percent(f32),
+    /// A fraction of the full turn
+    Turns(f32),
+}
+
+impl AngularPosition {
+    /// The angle in radians, or `None` if it's automatic
+    pub fn resolve(self) -> Option<f32> {
+        match self {
+            AngularPosition::Auto => None,
+            AngularPosition::Radians(angle) => Some(angle),
+            AngularPosition::Percent(percent) => Some(percent * TAU / 100.),
+            AngularPosition::Turns(turns) => Some(turns * TAU),
+        }
+    }
+}
+
+impl From<f32> for AngularPosition {
+    fn from(angle: f32) -> Self {
+        Self::Radians(angle)
+    }
+}
+
+impl From<Angle> for AngularPosition {
+    fn from(angle: Angle) -> Self {
+        Self::Radians(angle.to_radians())
+    }
+}
+
+/// [`Val::Percent`] is a percentage of the full turn.
+/// The other units have no angular meaning and are treated as [`Val::Auto`].
+impl From<Val> for AngularPosition {
+    fn from(val: Val) -> Self {
+        match val {
+            Val::Percent(percent) => Self::Percent(percent),
+            _ => Self::Auto,
         }
     }
 }
@@ -273,59 +633,413 @@ pub struct AngularColorStop {
     pub color: Color,
     /// The angle of the stop.
     /// Angles are relative to the start of the gradient and not other stops.
-    /// If set to `None` the angle of the stop will be interpolated between the explicit stops or 0 and 2 PI degrees if there no explicit stops.
-    /// Given angles are clamped to between `0.`, and [`TAU`].
+    /// If set to [`AngularPosition::Auto`] the angle of the stop will be interpolated between the explicit stops or 0 and 2 PI degrees if there no explicit stops.
+    /// Explicit stops are sorted by angle. Angles outside of `0.` to [`TAU`] are kept, and the colors
+    /// at the start and end of the turn are interpolated between them, like CSS.
     /// This means that a list of stops:
     /// ```
+    /// # use bevy::color::{Color, palettes::css::{BLUE, RED}};
+    /// # use bevy_ui_gradients::AngularColorStop;
+    /// # use core::f32::consts::TAU;
+    /// # let _ =
     /// [
-    ///     ColorStop::new(Color::WHITE, 0.),
-    ///     ColorStop::new(Color::BLACK, -1.),
-    ///     ColorStop::new(RED, 2. * TAU),
-    ///     ColorStop::new(BLUE, TAU),
-    /// ]
-    /// ```
-    /// is equivalent to:
-    /// ```
-    /// [
-    ///     ColorStop::new(Color::WHITE, 0.),
-    ///     ColorStop::new(Color::BLACK, 0.),
-    ///     ColorStop::new(RED, TAU),
-    ///     ColorStop::new(BLUE, TAU),
+    ///     AngularColorStop::new(RED, -TAU),
+    ///     AngularColorStop::new(BLUE, TAU),
     /// ]
+    /// # ;
     /// ```
-    /// Resulting in a black to red gradient, not white to blue.
-    pub angle: Option<f32>,
+    /// starts the turn halfway between red and blue, and ends it at blue.
+    pub angle: AngularPosition,
     /// Normalized angle between this and the following stop of the interpolation midpoint.
     pub hint: f32,
+    /// How colors are interpolated between this and the following stop
+    pub interpolation: Interpolation,
+    /// If set, the stop is animated in the shader
+    pub oscillation: Option<StopOscillation>,
+}
+
+impl From<(f32, Color)> for AngularColorStop {
+    fn from((percent, color): (f32, Color)) -> Self {
+        Self::percent(color, percent)
+    }
 }
 
 impl AngularColorStop {
     // Create a new color stop
     pub fn new(color: impl Into<Color>, angle: f32) -> Self {
+        Self::at(color, AngularPosition::Radians(angle))
+    }
+
+    /// A color stop at `percent` of the full turn
+    pub fn percent(color: impl Into<Color>, percent: f32) -> Self {
+        Self::at(color, AngularPosition::Percent(percent))
+    }
+
+    /// A color stop at `turns` of the full turn
+    pub fn turns(color: impl Into<Color>, turns: f32) -> Self {
+        Self::at(color, AngularPosition::Turns(turns))
+    }
+
+    /// A color stop at `angle`
+    pub fn at(color: impl Into<Color>, angle: impl Into<AngularPosition>) -> Self {
+        Self::const_at(color.into(), angle.into())
+    }
+
+    /// A color stop at `angle` in a `const` context, like [`AngularColorStop::at`]
+    pub const fn const_at(color: Color, angle: AngularPosition) -> Self {
         Self {
-            color: color.into(),
-            angle: Some(angle),
+            color,
+            angle,
             hint: 0.5,
+            interpolation: Interpolation::Linear,
+            oscillation: None,
         }
     }
 
+    /// An automatic angular stop in a `const` context, like [`AngularColorStop::auto`]
+    pub const fn const_auto(color: Color) -> Self {
+        Self::const_at(color, AngularPosition::Auto)
+    }
+
     /// An angular stop without an explicit angle. The angles of automatic stops
     /// are interpolated evenly between explicit stops.
     pub fn auto(color: impl Into<Color>) -> Self {
-        Self {
-            color: color.into(),
-            angle: None,
-            hint: 0.5,
-        }
+        Self::at(color, AngularPosition::Auto)
+    }
+
+    /// Angular color stops from a table of percentages of the full turn and colors
+    pub fn percentages(stops: &[(f32, Color)]) -> Vec<Self> {
+        stops.iter().copied().map(Self::from).collect()
     }
 
     // Set the interpolation midpoint between this and and the following stop
-    pub fn with_hint(mut self, hint: f32) -> Self {
+    pub const fn with_hint(mut self, hint: f32) -> Self {
         self.hint = hint;
         self
     }
+
+    /// Sets how colors are interpolated between this and the following stop
+    pub const fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Animates the stop in the shader
+    pub const fn with_oscillation(mut self, oscillation: StopOscillation) -> Self {
+        self.oscillation = Some(oscillation);
+        self
+    }
+}
+
+/// A view of a color stop of any kind of gradient, returned by [`Gradient::stops`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GradientStop {
+    /// Color of the stop
+    pub color: Color,
+    /// Position of the stop between `0.` and `1.`, as a fraction of the gradient line for linear and
+    /// radial gradients or of the full turn for conic gradients.
+    /// `None` for automatic stops and stops in units that depend on the size of the node.
+    pub position: Option<f32>,
+    /// Normalized position between this and the following stop of the interpolation midpoint
+    pub hint: f32,
+    /// How colors are interpolated between this and the following stop
+    pub interpolation: Interpolation,
+}
+
+impl From<ColorStop> for GradientStop {
+    fn from(stop: ColorStop) -> Self {
+        Self {
+            color: stop.color,
+            position: match stop.point {
+                Val::Percent(percent) => Some(percent / 100.),
+                Val::Px(0.) => Some(0.),
+                _ => None,
+            },
+            hint: stop.hint,
+            interpolation: stop.interpolation,
+        }
+    }
 }
 
+impl From<AngularColorStop> for GradientStop {
+    fn from(stop: AngularColorStop) -> Self {
+        Self {
+            color: stop.color,
+            position: stop.angle.resolve().map(|angle| angle / TAU),
+            hint: stop.hint,
+            interpolation: stop.interpolation,
+        }
+    }
+}
+
+/// The number of color stops a gradient stores inline, without allocating
+pub const INLINE_STOPS: usize = 4;
+
+/// Implements a list of color stops that stores up to [`INLINE_STOPS`] stops inline and
+/// derefs to a slice of stops.
+macro_rules! impl_color_stops {
+    ($($(#[$meta:meta])* $name:ident($stop:ty);)*) => {$(
+        $(#[$meta])*
+        #[derive(Clone, PartialEq, Debug, Default, Reflect)]
+        #[reflect(PartialEq, Default)]
+        pub struct $name(SmallVec<[$stop; INLINE_STOPS]>);
+
+        impl $name {
+            /// Creates an empty list of stops
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Appends a stop to the end of the list
+            pub fn push(&mut self, stop: $stop) {
+                self.0.push(stop);
+            }
+
+            /// Removes every stop
+            pub fn clear(&mut self) {
+                self.0.clear();
+            }
+
+            /// The stops as a slice
+            pub fn as_slice(&self) -> &[$stop] {
+                &self.0
+            }
+
+            /// Converts the stops into a `Vec`, moving any inline stops to the heap
+            pub fn into_vec(self) -> Vec<$stop> {
+                self.0.into_vec()
+            }
+
+            /// Returns true if the stops are stored inline, without a heap allocation
+            pub fn is_inline(&self) -> bool {
+                !self.0.spilled()
+            }
+        }
+
+        impl core::ops::Deref for $name {
+            type Target = [$stop];
+
+            fn deref(&self) -> &[$stop] {
+                &self.0
+            }
+        }
+
+        impl core::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut [$stop] {
+                &mut self.0
+            }
+        }
+
+        impl From<Vec<$stop>> for $name {
+            fn from(stops: Vec<$stop>) -> Self {
+                Self(SmallVec::from_vec(stops))
+            }
+        }
+
+        impl<const N: usize> From<[$stop; N]> for $name {
+            fn from(stops: [$stop; N]) -> Self {
+                stops.into_iter().collect()
+            }
+        }
+
+        impl From<&[$stop]> for $name {
+            fn from(stops: &[$stop]) -> Self {
+                Self(SmallVec::from_slice(stops))
+            }
+        }
+
+        impl FromIterator<$stop> for $name {
+            fn from_iter<I: IntoIterator<Item = $stop>>(iter: I) -> Self {
+                Self(iter.into_iter().collect())
+            }
+        }
+
+        impl Extend<$stop> for $name {
+            fn extend<I: IntoIterator<Item = $stop>>(&mut self, iter: I) {
+                self.0.extend(iter);
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $name {
+            type Item = &'a $stop;
+            type IntoIter = core::slice::Iter<'a, $stop>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $name {
+            type Item = &'a mut $stop;
+            type IntoIter = core::slice::IterMut<'a, $stop>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter_mut()
+            }
+        }
+    )*};
+}
+
+impl_color_stops! {
+    /// The color stops of a gradient. Up to [`INLINE_STOPS`] stops are stored inline, so most
+    /// gradients can be cloned and animated without allocating.
+    ///
+    /// Converts from a `Vec`, an array or an iterator of stops:
+    /// ```
+    /// # use bevy::color::palettes::css::{RED, BLUE};
+    /// # use bevy_ui_gradients::*;
+    /// let stops = ColorStops::from([ColorStop::auto(RED), ColorStop::auto(BLUE)]);
+    /// assert!(stops.is_inline());
+    /// assert_eq!(stops.len(), 2);
+    /// ```
+    ColorStops(ColorStop);
+    /// The color stops of a conic gradient. Up to [`INLINE_STOPS`] stops are stored inline.
+    AngularColorStops(AngularColorStop);
+}
+
+/// The properties shared by every kind of gradient, describing how the gradient is drawn as a layer of a
+/// node's background: how it's spread, clipped, positioned, tiled, filtered and composited.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub struct GradientLayer {
+    /// How the areas before the first color stop and after the last color stop are filled
+    pub spread: SpreadMethod,
+    /// Film grain noise drawn over the gradient
+    pub grain: Option<GradientGrain>,
+    /// The area the gradient is drawn in, like CSS `background-clip`.
+    /// Ignored by border gradients, which are always drawn in the border.
+    pub clip: GradientBox,
+    /// The box the gradient's geometry is resolved against, like CSS `background-origin`
+    pub origin: GradientBox,
+    /// If set, the gradient is drawn at a fixed size and repeated across the node
+    pub tiling: Option<GradientTiling>,
+    /// Whether the gradient is resolved against the node or the viewport, like CSS `background-attachment`
+    pub attachment: GradientAttachment,
+    /// Color filters applied to the gradient in the shader
+    pub filter: Option<GradientFilter>,
+    /// If set, the gradient's distances are remapped by the custom WGSL function registered with
+    /// [`CustomGradientFunctionPlugin`], which is passed this id
+    pub custom_function: Option<u32>,
+    /// Smooths the colors across all of the stops with a spline
+    pub smoothing: GradientSmoothing,
+    /// How the gradient is composited with what's drawn beneath it
+    pub blend_mode: GradientBlendMode,
+    /// A texture modulating the gradient's alpha or distances
+    pub texture: Option<GradientTexture>,
+}
+
+impl Default for GradientLayer {
+    fn default() -> Self {
+        Self {
+            spread: SpreadMethod::Pad,
+            grain: None,
+            clip: GradientBox::PaddingBox,
+            origin: GradientBox::BorderBox,
+            tiling: None,
+            attachment: GradientAttachment::Scroll,
+            filter: None,
+            custom_function: None,
+            smoothing: GradientSmoothing::None,
+            blend_mode: GradientBlendMode::Normal,
+            texture: None,
+        }
+    }
+}
+
+/// Mutable access to a gradient's [`GradientLayer`], for the builders generated by `impl_gradient_layer_builders`
+trait GradientLayerMut {
+    fn layer_mut(&mut self) -> &mut GradientLayer;
+}
+
+macro_rules! impl_gradient_layer_builders {
+    ($($gradient:ident),*) => {$(
+        impl GradientLayerMut for $gradient {
+            fn layer_mut(&mut self) -> &mut GradientLayer {
+                &mut self.layer
+            }
+        }
+    )*
+    impl_gradient_layer_builders!(@builders $($gradient,)* Gradient);
+    };
+    (@builders $($gradient:ident),*) => {$(
+        impl $gradient {
+            /// Sets how the areas before the first color stop and after the last color stop are filled
+            pub fn with_spread(mut self, spread: SpreadMethod) -> Self {
+                self.layer_mut().spread = spread;
+                self
+            }
+
+            /// Sets the film grain drawn over the gradient
+            pub fn with_grain(mut self, grain: GradientGrain) -> Self {
+                self.layer_mut().grain = Some(grain);
+                self
+            }
+
+            /// Sets the area the gradient is drawn in
+            pub fn with_clip(mut self, clip: GradientBox) -> Self {
+                self.layer_mut().clip = clip;
+                self
+            }
+
+            /// Sets the box the gradient's geometry is resolved against
+            pub fn with_origin(mut self, origin: GradientBox) -> Self {
+                self.layer_mut().origin = origin;
+                self
+            }
+
+            /// Sets the size and repetition of the gradient's tiles
+            pub fn with_tiling(mut self, tiling: GradientTiling) -> Self {
+                self.layer_mut().tiling = Some(tiling);
+                self
+            }
+
+            /// Sets whether the gradient is resolved against the node or the viewport
+            pub fn with_attachment(mut self, attachment: GradientAttachment) -> Self {
+                self.layer_mut().attachment = attachment;
+                self
+            }
+
+            /// Sets the color filters applied to the gradient
+            pub fn with_filter(mut self, filter: GradientFilter) -> Self {
+                self.layer_mut().filter = Some(filter);
+                self
+            }
+
+            /// Remaps the gradient's distances with the custom WGSL function, passing it `id`
+            pub fn with_custom_function(mut self, id: u32) -> Self {
+                self.layer_mut().custom_function = Some(id);
+                self
+            }
+
+            /// Smooths the colors across all of the stops with a spline
+            pub fn with_smoothing(mut self, smoothing: GradientSmoothing) -> Self {
+                self.layer_mut().smoothing = smoothing;
+                self
+            }
+
+            /// Sets how the gradient is composited with what's drawn beneath it
+            pub fn with_blend_mode(mut self, blend_mode: GradientBlendMode) -> Self {
+                self.layer_mut().blend_mode = blend_mode;
+                self
+            }
+
+            /// Sets the texture modulating the gradient
+            pub fn with_texture(mut self, texture: GradientTexture) -> Self {
+                self.layer_mut().texture = Some(texture);
+                self
+            }
+        }
+    )*};
+}
+
+impl_gradient_layer_builders!(
+    LinearGradient,
+    RadialGradient,
+    ConicGradient,
+    InsetGradient,
+    PlasmaGradient
+);
+
 /// A linear gradient
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/linear-gradient>
@@ -333,105 +1047,121 @@ impl AngularColorStop {
 #[reflect(PartialEq)]
 pub struct LinearGradient {
     /// The direction of the gradient.
-    /// An angle of `0.` points upward, angles increasing clockwise.
-    pub angle: f32,
+    /// An angle of zero points upward, angles increasing clockwise.
+    pub angle: Angle,
+    /// If set, the gradient line runs between these points and `angle` is ignored
+    pub endpoints: Option<GradientEndpoints>,
     /// The list of color stops
-    pub stops: Vec<ColorStop>,
+    pub stops: ColorStops,
+    /// The properties shared by every kind of gradient, like its spread, clip and blend mode
+    pub layer: GradientLayer,
 }
 
 impl LinearGradient {
     /// Angle of a linear gradient transitioning from bottom to top
-    pub const TO_TOP: f32 = 0.;
+    pub const TO_TOP: Angle = Angle::ZERO;
     /// Angle of a linear gradient transitioning from bottom-left to top-right
-    pub const TO_TOP_RIGHT: f32 = TAU / 8.;
+    pub const TO_TOP_RIGHT: Angle = Angle::turns(0.125);
     /// Angle of a linear gradient transitioning from left to right
-    pub const TO_RIGHT: f32 = 2. * Self::TO_TOP_RIGHT;
+    pub const TO_RIGHT: Angle = Angle::turns(0.25);
     /// Angle of a linear gradient transitioning from top-left to bottom-right
-    pub const TO_BOTTOM_RIGHT: f32 = 3. * Self::TO_TOP_RIGHT;
+    pub const TO_BOTTOM_RIGHT: Angle = Angle::turns(0.375);
     /// Angle of a linear gradient transitioning from top to bottom
-    pub const TO_BOTTOM: f32 = 4. * Self::TO_TOP_RIGHT;
+    pub const TO_BOTTOM: Angle = Angle::turns(0.5);
     /// Angle of a linear gradient transitioning from top-right to bottom-left
-    pub const TO_BOTTOM_LEFT: f32 = 5. * Self::TO_TOP_RIGHT;
+    pub const TO_BOTTOM_LEFT: Angle = Angle::turns(0.625);
     /// Angle of a linear gradient transitioning from right to left
-    pub const TO_LEFT: f32 = 6. * Self::TO_TOP_RIGHT;
+    pub const TO_LEFT: Angle = Angle::turns(0.75);
     /// Angle of a linear gradient transitioning from bottom-right to top-left
-    pub const TO_TOP_LEFT: f32 = 7. * Self::TO_TOP_RIGHT;
+    pub const TO_TOP_LEFT: Angle = Angle::turns(0.875);
 
     /// Create a new linear gradient
-    pub fn new(angle: f32, stops: Vec<ColorStop>) -> Self {
-        Self { angle, stops }
+    pub fn new(angle: impl Into<Angle>, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self {
+            angle: angle.into(),
+            stops: stops.into_iter().collect(),
+            endpoints: None,
+            layer: GradientLayer::default(),
+        }
     }
 
     /// A linear gradient transitioning from bottom to top
-    pub fn to_top(stops: Vec<ColorStop>) -> Self {
-        Self {
-            angle: Self::TO_TOP,
-            stops,
-        }
+    pub fn to_top(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self::new(Self::TO_TOP, stops)
     }
 
     /// A linear gradient transitioning from bottom-left to top-right
-    pub fn to_top_right(stops: Vec<ColorStop>) -> Self {
-        Self {
-            angle: Self::TO_TOP_RIGHT,
-            stops,
-        }
+    pub fn to_top_right(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self::new(Self::TO_TOP_RIGHT, stops)
     }
 
     /// A linear gradient transitioning from left to right
-    pub fn to_right(stops: Vec<ColorStop>) -> Self {
-        Self {
-            angle: Self::TO_RIGHT,
-            stops,
-        }
+    pub fn to_right(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self::new(Self::TO_RIGHT, stops)
     }
 
     /// A linear gradient transitioning from top-left to bottom-right
-    pub fn to_bottom_right(stops: Vec<ColorStop>) -> Self {
-        Self {
-            angle: Self::TO_BOTTOM_RIGHT,
-            stops,
-        }
+    pub fn to_bottom_right(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self::new(Self::TO_BOTTOM_RIGHT, stops)
     }
 
     /// A linear gradient transitioning from top to bottom
-    pub fn to_bottom(stops: Vec<ColorStop>) -> Self {
-        Self {
-            angle: Self::TO_BOTTOM,
-            stops,
-        }
+    pub fn to_bottom(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self::new(Self::TO_BOTTOM, stops)
     }
 
     /// A linear gradient transitioning from top-right to bottom-left
-    pub fn to_bottom_left(stops: Vec<ColorStop>) -> Self {
-        Self {
-            angle: Self::TO_BOTTOM_LEFT,
-            stops,
-        }
+    pub fn to_bottom_left(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self::new(Self::TO_BOTTOM_LEFT, stops)
     }
 
     /// A linear gradient transitioning from right to left
-    pub fn to_left(stops: Vec<ColorStop>) -> Self {
-        Self {
-            angle: Self::TO_LEFT,
-            stops,
-        }
+    pub fn to_left(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self::new(Self::TO_LEFT, stops)
     }
 
     /// A linear gradient transitioning from bottom-right to top-left
-    pub fn to_top_left(stops: Vec<ColorStop>) -> Self {
-        Self {
-            angle: Self::TO_TOP_LEFT,
-            stops,
-        }
+    pub fn to_top_left(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self::new(Self::TO_TOP_LEFT, stops)
+    }
+
+    /// A linear gradient with its gradient line running from `start` to `end`.
+    ///
+    /// The endpoints are resolved against the node's size when it's rendered.
+    /// Color stop lengths are resolved against the distance between the endpoints.
+    pub fn between(
+        start: Position,
+        end: Position,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        Self::new(Angle::ZERO, stops).with_endpoints(start, end)
+    }
+
+    /// Sets the endpoints of the gradient line, replacing the angle
+    pub fn with_endpoints(mut self, start: Position, end: Position) -> Self {
+        self.endpoints = Some(GradientEndpoints { start, end });
+        self
     }
 
     /// A linear gradient with the given angle in degrees
-    pub fn degrees(degrees: f32, stops: Vec<ColorStop>) -> Self {
-        Self {
-            angle: degrees.to_radians(),
-            stops,
-        }
+    pub fn degrees(degrees: f32, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self::new(Angle::deg(degrees), stops)
+    }
+}
+
+/// The start and end points of a linear gradient's gradient line
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq)]
+pub struct GradientEndpoints {
+    /// Where the gradient line starts, the position of stops at `0%`
+    pub start: Position,
+    /// Where the gradient line ends, the position of stops at `100%`
+    pub end: Position,
+}
+
+impl Default for LinearGradient {
+    fn default() -> Self {
+        Self::new(Self::TO_TOP, Vec::new())
     }
 }
 
@@ -445,28 +1175,46 @@ pub struct RadialGradient {
     pub position: Position,
     /// Defines the end shape of the radial gradient
     pub shape: RadialGradientShape,
+    /// The exponent the distance from the center is raised to, as a fraction of the end shape, before the
+    /// stops are looked up. Values above `1.` tighten the colors near the center into a hotspot and values
+    /// below `1.` spread them out. `1.` is the normal linear falloff. Ignored by [`GradientMaterial`]s.
+    pub falloff: f32,
     /// The list of color stops
-    pub stops: Vec<ColorStop>,
+    pub stops: ColorStops,
+    /// The properties shared by every kind of gradient, like its spread, clip and blend mode
+    pub layer: GradientLayer,
 }
 
 impl RadialGradient {
     /// Create a new radial gradient
-    pub fn new(position: Position, shape: RadialGradientShape, stops: Vec<ColorStop>) -> Self {
+    pub fn new(
+        position: Position,
+        shape: RadialGradientShape,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
         Self {
             position,
             shape,
-            stops,
+            falloff: 1.,
+            stops: stops.into_iter().collect(),
+            layer: GradientLayer::default(),
         }
     }
+
+    /// Sets the exponent of the falloff from the center
+    pub fn with_falloff(mut self, falloff: f32) -> Self {
+        self.falloff = falloff;
+        self
+    }
 }
 
 impl Default for RadialGradient {
     fn default() -> Self {
-        Self {
-            position: Position::CENTER,
-            shape: RadialGradientShape::ClosestCorner,
-            stops: Vec::new(),
-        }
+        Self::new(
+            Position::CENTER,
+            RadialGradientShape::ClosestCorner,
+            Vec::new(),
+        )
     }
 }
 
@@ -477,26 +1225,49 @@ impl Default for RadialGradient {
 #[reflect(PartialEq)]
 pub struct ConicGradient {
     /// The starting angle of the gradient
-    pub start: f32,
+    pub start: Angle,
     /// The center of the conic gradient
     pub position: Position,
+    /// The ratio of the width to the height of the ellipse that the angular sweep follows.
+    /// With `1.` the sweep is circular, with `2.` the gradient is stretched horizontally to twice its height.
+    pub aspect_ratio: f32,
+    /// If set, the full turn of the stops is mapped onto the sweep from `start` to `end`,
+    /// instead of a turn clockwise from `start`.
+    /// An `end` before `start` sweeps counter-clockwise. Sweeps of more than a full turn wrap around the center,
+    /// with each revolution drawn over the previous one. An `end` equal to `start` is ignored, and
+    /// [`GradientMaterial`]s ignore `end`.
+    pub end: Option<Angle>,
+    /// The gradient is transparent closer to the center than the inner radius, for donut charts and circular
+    /// progress tracks. Percentages are of half the length of the shorter side of the node, and the radius
+    /// follows the ellipse of the `aspect_ratio`. Ignored by [`GradientMaterial`]s.
+    pub inner_radius: Val,
+    /// If not [`Val::Auto`], the gradient is transparent further from the center than the outer radius.
+    /// Resolved like the `inner_radius`.
+    pub outer_radius: Val,
     /// The list of color stops
-    pub stops: Vec<AngularColorStop>,
+    pub stops: AngularColorStops,
+    /// The properties shared by every kind of gradient, like its spread, clip and blend mode
+    pub layer: GradientLayer,
 }
 
 impl ConicGradient {
     /// Create a new conic gradient
-    pub fn new(stops: Vec<AngularColorStop>) -> Self {
+    pub fn new(stops: impl IntoIterator<Item = AngularColorStop>) -> Self {
         Self {
-            start: 0.,
+            start: Angle::ZERO,
             position: Position::CENTER,
-            stops,
+            aspect_ratio: 1.,
+            end: None,
+            inner_radius: Val::Px(0.),
+            outer_radius: Val::Auto,
+            stops: stops.into_iter().collect(),
+            layer: GradientLayer::default(),
         }
     }
 
     /// Sets the starting angle of the gradient
-    pub fn with_start(mut self, start: f32) -> Self {
-        self.start = start;
+    pub fn with_start(mut self, start: impl Into<Angle>) -> Self {
+        self.start = start.into();
         self
     }
 
@@ -505,54 +1276,829 @@ impl ConicGradient {
         self.position = position;
         self
     }
-}
 
-#[derive(Clone, PartialEq, Debug, Reflect)]
-#[reflect(PartialEq)]
-pub enum Gradient {
-    /// A linear gradient
-    ///
-    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/linear-gradient>
-    Linear(LinearGradient),
-    /// A radial gradient
+    /// Sets the ratio of the width to the height of the ellipse that the angular sweep follows
+    pub fn with_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = aspect_ratio;
+        self
+    }
+
+    /// The angle in radians that the full turn of the stops is mapped onto, negative if it's counter-clockwise
+    pub fn sweep(&self) -> f32 {
+        self.end
+            .map(|end| (end - self.start).to_radians())
+            .filter(|sweep| *sweep != 0.)
+            .unwrap_or(TAU)
+    }
+
+    /// Only fills the ring between `inner_radius` and `outer_radius`
     ///
-    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/linear-gradient>
-    Radial(RadialGradient),
-    /// A conic gradient
+    /// ```
+    /// # use bevy_ui_gradients::*;
+    /// # use bevy::{color::palettes::css::{BLUE, RED}, ui::Val};
+    /// // A donut chart with a quarter in red
+    /// let chart = ConicGradient::new(AngularColorStop::percentages(&[
+    ///     (0., RED.into()),
+    ///     (25., RED.into()),
+    ///     (25., BLUE.into()),
+    ///     (100., BLUE.into()),
+    /// ]))
+    /// .with_ring(Val::Percent(60.), Val::Percent(100.));
+    /// ```
+    pub fn with_ring(mut self, inner_radius: Val, outer_radius: Val) -> Self {
+        self.inner_radius = inner_radius;
+        self.outer_radius = outer_radius;
+        self
+    }
+
+    /// Maps the stops onto the sweep from the start angle to `end`.
     ///
-    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/radial-gradient>
-    Conic(ConicGradient),
+    /// ```
+    /// # use bevy_ui_gradients::*;
+    /// # use bevy::color::palettes::css::{GREEN, RED};
+    /// // A dial whose needle turns one and a half times counter-clockwise from the top
+    /// let dial = ConicGradient::new(vec![
+    ///     AngularColorStop::auto(GREEN),
+    ///     AngularColorStop::auto(RED),
+    /// ])
+    /// .with_end(Angle::deg(-540.));
+    /// ```
+    pub fn with_end(mut self, end: impl Into<Angle>) -> Self {
+        self.end = Some(end.into());
+        self
+    }
 }
 
-impl Gradient {
-    /// Returns true if the gradient has no stops.
-    pub fn is_empty(&self) -> bool {
-        match self {
-            Gradient::Linear(gradient) => gradient.stops.is_empty(),
-            Gradient::Radial(gradient) => gradient.stops.is_empty(),
-            Gradient::Conic(gradient) => gradient.stops.is_empty(),
-        }
+impl Default for ConicGradient {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+/// A gradient that follows the node's border inward, like an inner glow or an inset shadow.
+///
+/// Distances are measured inward from the edges of the gradient's origin box, which are rounded like the node's corners.
+/// Color stop lengths are resolved against half the smaller side of the origin box,
+/// so on a square node `100%` is at its center.
+/// Tiles and boxes that extend beyond the node, from a fixed [`GradientAttachment`], aren't rounded.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq)]
+pub struct InsetGradient {
+    /// The list of color stops, from the edges of the origin box inward
+    pub stops: ColorStops,
+    /// The properties shared by every kind of gradient, like its spread, clip and blend mode
+    pub layer: GradientLayer,
+}
+
+impl InsetGradient {
+    /// Create a new inset gradient
+    pub fn new(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self {
+            stops: stops.into_iter().collect(),
+            layer: GradientLayer {
+                origin: GradientBox::PaddingBox,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Default for InsetGradient {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+/// An animated plasma, an interference pattern of moving sine waves mapped through the color stops,
+/// for retro menu backgrounds.
+///
+/// The plasma's value runs from `0%` to `100%` along a gradient line as long as its `scale`,
+/// so percentage stops map directly onto the plasma's range.
+/// The plasma is animated in the shader. It's sampled at time zero on the CPU and by [`GradientMaterial`],
+/// and frozen if [`GradientAccessibility::reduced_motion`] is set.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq)]
+pub struct PlasmaGradient {
+    /// The list of color stops, the color ramp the plasma is mapped through
+    pub stops: ColorStops,
+    /// The wavelength of the plasma's waves in logical pixels
+    pub scale: f32,
+    /// How fast the plasma moves, `1.` is one radian of wave phase per second
+    pub speed: f32,
+    /// The properties shared by every kind of gradient, like its spread, clip and blend mode
+    pub layer: GradientLayer,
+}
+
+impl PlasmaGradient {
+    /// Create a new plasma with a scale of 100 logical pixels and a speed of `1.`
+    pub fn new(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        Self {
+            stops: stops.into_iter().collect(),
+            scale: 100.,
+            speed: 1.,
+            layer: GradientLayer::default(),
+        }
+    }
+
+    /// Sets the wavelength of the plasma's waves in logical pixels
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets how fast the plasma moves
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+}
+
+impl Default for PlasmaGradient {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq)]
+pub enum Gradient {
+    /// A linear gradient
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/linear-gradient>
+    Linear(LinearGradient),
+    /// A radial gradient
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/linear-gradient>
+    Radial(RadialGradient),
+    /// A conic gradient
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/radial-gradient>
+    Conic(ConicGradient),
+    /// A gradient following the node's border inward
+    Inset(InsetGradient),
+    /// An animated plasma
+    Plasma(PlasmaGradient),
+}
+
+impl Gradient {
+    /// Returns true if the gradient has no stops.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Gradient::Linear(gradient) => gradient.stops.is_empty(),
+            Gradient::Radial(gradient) => gradient.stops.is_empty(),
+            Gradient::Conic(gradient) => gradient.stops.is_empty(),
+            Gradient::Inset(gradient) => gradient.stops.is_empty(),
+            Gradient::Plasma(gradient) => gradient.stops.is_empty(),
+        }
+    }
+
+    /// Returns true if every color stop of the gradient is fully transparent, so it draws nothing
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_gradients::*;
+    /// let gradient: Gradient = LinearGradient::to_right(vec![Color::NONE.into(), Color::NONE.into()]).into();
+    /// assert!(gradient.is_transparent());
+    /// ```
+    pub fn is_transparent(&self) -> bool {
+        self.stops().all(|stop| stop.color.alpha() <= 0.)
+    }
+
+    /// The number of color stops in the gradient's `stops` field, as they were authored.
+    ///
+    /// A stop with an `end_point` counts once, but [`Gradient::stops`] yields it as a pair of stops,
+    /// so use `stops().count()` for the length of that list.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::color::palettes::css::{RED, BLUE};
+    /// # use bevy_ui_gradients::*;
+    /// let flag: Gradient = LinearGradient::to_right(vec![
+    ///     ColorStop::span(RED, Val::Percent(0.), Val::Percent(50.)),
+    ///     ColorStop::span(BLUE, Val::Percent(50.), Val::Percent(100.)),
+    /// ])
+    /// .into();
+    /// assert_eq!(flag.authored_stops_len(), 2);
+    /// assert_eq!(flag.stops().count(), 4);
+    /// ```
+    pub fn authored_stops_len(&self) -> usize {
+        match self {
+            Gradient::Linear(gradient) => gradient.stops.len(),
+            Gradient::Radial(gradient) => gradient.stops.len(),
+            Gradient::Conic(gradient) => gradient.stops.len(),
+            Gradient::Inset(gradient) => gradient.stops.len(),
+            Gradient::Plasma(gradient) => gradient.stops.len(),
+        }
+    }
+
+    /// Iterates over views of the gradient's color stops, for any kind of gradient
+    pub fn stops(&self) -> impl Iterator<Item = GradientStop> + '_ {
+        let (stops, angular_stops) = match self {
+            Gradient::Linear(gradient) => (gradient.stops.as_slice(), [].as_slice()),
+            Gradient::Radial(gradient) => (gradient.stops.as_slice(), [].as_slice()),
+            Gradient::Conic(gradient) => ([].as_slice(), gradient.stops.as_slice()),
+            Gradient::Inset(gradient) => (gradient.stops.as_slice(), [].as_slice()),
+            Gradient::Plasma(gradient) => (gradient.stops.as_slice(), [].as_slice()),
+        };
+        stops
+            .iter()
+            .copied()
+            .flat_map(ColorStop::expand)
+            .map(GradientStop::from)
+            .chain(angular_stops.iter().copied().map(GradientStop::from))
+    }
+
+    /// Iterates over mutable references to the colors of the gradient's color stops
+    pub fn colors_mut(&mut self) -> impl Iterator<Item = &mut Color> + '_ {
+        let (stops, angular_stops): (&mut [ColorStop], &mut [AngularColorStop]) = match self {
+            Gradient::Linear(gradient) => (&mut gradient.stops, &mut []),
+            Gradient::Radial(gradient) => (&mut gradient.stops, &mut []),
+            Gradient::Conic(gradient) => (&mut [], &mut gradient.stops),
+            Gradient::Inset(gradient) => (&mut gradient.stops, &mut []),
+            Gradient::Plasma(gradient) => (&mut gradient.stops, &mut []),
+        };
+        stops
+            .iter_mut()
+            .map(|stop| &mut stop.color)
+            .chain(angular_stops.iter_mut().map(|stop| &mut stop.color))
+    }
+
+    /// The properties shared by every kind of gradient, like its spread, clip and blend mode
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_gradients::*;
+    /// let gradient: Gradient = InsetGradient::new(vec![Color::BLACK.into(), Color::WHITE.into()])
+    ///     .with_spread(SpreadMethod::Repeat)
+    ///     .into();
+    /// assert_eq!(gradient.layer().spread, SpreadMethod::Repeat);
+    /// // Inset gradients are resolved against the padding box by default
+    /// assert_eq!(gradient.layer().origin, GradientBox::PaddingBox);
+    /// ```
+    pub fn layer(&self) -> &GradientLayer {
+        match self {
+            Gradient::Linear(gradient) => &gradient.layer,
+            Gradient::Radial(gradient) => &gradient.layer,
+            Gradient::Conic(gradient) => &gradient.layer,
+            Gradient::Inset(gradient) => &gradient.layer,
+            Gradient::Plasma(gradient) => &gradient.layer,
+        }
+    }
+
+    /// Mutable access to the properties shared by every kind of gradient
+    pub fn layer_mut(&mut self) -> &mut GradientLayer {
+        match self {
+            Gradient::Linear(gradient) => &mut gradient.layer,
+            Gradient::Radial(gradient) => &mut gradient.layer,
+            Gradient::Conic(gradient) => &mut gradient.layer,
+            Gradient::Inset(gradient) => &mut gradient.layer,
+            Gradient::Plasma(gradient) => &mut gradient.layer,
+        }
+    }
+
+    /// The size of the box the gradient's geometry is resolved against, and the offset of its center from the center of the node.
+    /// `node_center` is the center of the node in physical pixels, relative to the top-left corner of the viewport.
+    /// If the node is in a [`GradientGroup`], scrolling gradients are resolved against the group's bounds.
+    pub fn resolve_box(
+        &self,
+        node: &ComputedNode,
+        node_center: Vec2,
+        group: Option<&GradientGroupBounds>,
+        physical_target_size: Vec2,
+    ) -> (Vec2, Vec2) {
+        let layer = self.layer();
+        match (layer.attachment, group) {
+            (GradientAttachment::Scroll, Some(group)) => (group.size, group.center - node_center),
+            (GradientAttachment::Scroll, None) => {
+                layer
+                    .origin
+                    .resolve(node.size(), node.border(), node.padding())
+            }
+            (GradientAttachment::Fixed, _) => (
+                physical_target_size,
+                0.5 * physical_target_size - node_center,
+            ),
+        }
+    }
+
+    /// An image layer, drawn between the gradient layers of a stack in order, like an image in a CSS
+    /// multi-layer background. The image is stretched across the node.
+    ///
+    /// Image layers are gradients with a single white stop multiplied by the image with [`TextureModulation::Multiply`],
+    /// so they can be tinted by changing the stop's color and support the other gradient layer properties,
+    /// like blend modes, filters and clipping. CPU sampling ignores the image.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_gradients::*;
+    /// # let paper = Handle::<Image>::default();
+    /// let background = BackgroundGradient(vec![
+    ///     LinearGradient::to_bottom(vec![Color::NONE.into(), Color::BLACK.into()]).into(),
+    ///     Gradient::tiled_image(paper, Vec2::splat(128.)),
+    ///     LinearGradient::to_right(vec![Color::WHITE.into(), Color::srgb(0.9, 0.8, 0.6).into()]).into(),
+    /// ]);
+    /// assert!(background.0[1].layer().texture.is_some());
+    /// ```
+    pub fn image(image: Handle<Image>) -> Self {
+        LinearGradient::to_bottom(vec![Color::WHITE.into()])
+            .with_texture(GradientTexture::new(image, TextureModulation::Multiply))
+            .into()
+    }
+
+    /// An image layer with the image repeated every `tile_size` logical pixels, see [`Gradient::image`].
+    /// The image needs a sampler with `ImageAddressMode::Repeat`.
+    pub fn tiled_image(image: Handle<Image>, tile_size: Vec2) -> Self {
+        LinearGradient::to_bottom(vec![Color::WHITE.into()])
+            .with_texture(
+                GradientTexture::new(image, TextureModulation::Multiply).with_tile_size(tile_size),
+            )
+            .into()
     }
 
     /// If the gradient has only a single color stop `get_single` returns its color.
     pub fn get_single(&self) -> Option<Color> {
+        (self.authored_stops_len() == 1)
+            .then(|| self.stops().next().map(|stop| stop.color))
+            .flatten()
+    }
+}
+
+/// How the areas of a gradient before its first color stop and after its last color stop are filled.
+///
+/// <https://www.w3.org/TR/SVG11/pservers.html#LinearGradientElementSpreadMethodAttribute>
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub enum SpreadMethod {
+    /// Fill with the colors of the first and last stops
+    #[default]
+    Pad,
+    /// Repeat the gradient between the first and last stops, like CSS `repeating-linear-gradient`
+    Repeat,
+    /// Repeat the gradient between the first and last stops, reversing it every other repetition
+    Reflect,
+}
+
+/// A box of a UI node, like the `<visual-box>` values of CSS `background-clip` and `background-origin`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(PartialEq)]
+pub enum GradientBox {
+    /// The whole node, including its border
+    BorderBox,
+    /// The node inside its border
+    PaddingBox,
+    /// The node inside its border and padding
+    ContentBox,
+}
+
+impl GradientBox {
+    /// The insets of the box from the edges of the node
+    pub fn inset(self, border: BorderRect, padding: BorderRect) -> BorderRect {
         match self {
-            Gradient::Linear(gradient) => gradient
-                .stops
-                .first()
-                .and_then(|stop| (gradient.stops.len() == 1).then_some(stop.color)),
-            Gradient::Radial(gradient) => gradient
-                .stops
-                .first()
-                .and_then(|stop| (gradient.stops.len() == 1).then_some(stop.color)),
-            Gradient::Conic(gradient) => gradient
-                .stops
-                .first()
-                .and_then(|stop| (gradient.stops.len() == 1).then_some(stop.color)),
+            GradientBox::BorderBox => BorderRect::ZERO,
+            GradientBox::PaddingBox => border,
+            GradientBox::ContentBox => BorderRect {
+                left: border.left + padding.left,
+                right: border.right + padding.right,
+                top: border.top + padding.top,
+                bottom: border.bottom + padding.bottom,
+            },
+        }
+    }
+
+    /// The size of the box, and the offset of its center from the center of the node,
+    /// for a node with the given size, border and padding
+    pub fn resolve(self, size: Vec2, border: BorderRect, padding: BorderRect) -> (Vec2, Vec2) {
+        let inset = self.inset(border, padding);
+        let box_size =
+            (size - Vec2::new(inset.left + inset.right, inset.top + inset.bottom)).max(Vec2::ZERO);
+        let offset = 0.5 * Vec2::new(inset.left - inset.right, inset.top - inset.bottom);
+        (box_size, offset)
+    }
+}
+
+/// What a gradient's geometry is resolved against, like the values of CSS `background-attachment`
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum GradientAttachment {
+    /// The gradient is resolved against the node's origin box and moves with the node
+    #[default]
+    Scroll,
+    /// The gradient is resolved against the viewport of the node's camera, so nodes with fixed gradients
+    /// show parts of one continuous gradient that stays still as they move.
+    /// The gradient's origin box is ignored. Fixed attachment isn't applied by [`GradientMaterial`].
+    Fixed,
+}
+
+/// How gradient tiles are repeated along an axis, like the values of CSS `background-repeat`
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum TileRepeat {
+    /// Repeat the tile, clipping tiles at the edges of the node
+    #[default]
+    Repeat,
+    /// Repeat as many whole tiles as fit in the origin box, with the first and last tiles
+    /// touching its edges and the space left over distributed between the tiles
+    Space,
+    /// Stretch the tiles so a whole number of them fit in the origin box
+    Round,
+    /// Draw a single tile
+    NoRepeat,
+}
+
+/// Draws a gradient at a fixed size and repeats it across the node, like CSS `background-size` and `background-repeat`.
+///
+/// The first tile is placed at the top-left corner of the gradient's origin box.
+/// The gradient's geometry is resolved against the size of a tile instead of the origin box.
+/// Tiling isn't applied by [`GradientMaterial`].
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub struct GradientTiling {
+    /// The width of a tile. Percentages and `Val::Auto` resolve against the width of the origin box.
+    pub width: Val,
+    /// The height of a tile. Percentages and `Val::Auto` resolve against the height of the origin box.
+    pub height: Val,
+    /// How tiles repeat horizontally
+    pub repeat_x: TileRepeat,
+    /// How tiles repeat vertically
+    pub repeat_y: TileRepeat,
+}
+
+impl GradientTiling {
+    /// Tiles of the given size, repeating in both directions
+    pub fn new(width: Val, height: Val) -> Self {
+        Self {
+            width,
+            height,
+            repeat_x: TileRepeat::Repeat,
+            repeat_y: TileRepeat::Repeat,
+        }
+    }
+
+    /// Square tiles with sides of `size` logical pixels, repeating in both directions
+    pub fn px(size: f32) -> Self {
+        Self::new(Val::Px(size), Val::Px(size))
+    }
+
+    /// Sets how tiles repeat in both directions
+    pub fn with_repeat(self, repeat: TileRepeat) -> Self {
+        self.with_repeat_xy(repeat, repeat)
+    }
+
+    /// Sets how tiles repeat horizontally and vertically
+    pub fn with_repeat_xy(mut self, repeat_x: TileRepeat, repeat_y: TileRepeat) -> Self {
+        self.repeat_x = repeat_x;
+        self.repeat_y = repeat_y;
+        self
+    }
+
+    /// Resolves the tiles for an origin box of `box_size` physical pixels, with its center offset from the center of the node by `box_offset`.
+    pub fn resolve(
+        &self,
+        box_size: Vec2,
+        box_offset: Vec2,
+        scale_factor: f32,
+        physical_target_size: Vec2,
+    ) -> PhysicalTiling {
+        let resolve_axis = |val: Val, repeat: TileRepeat, length: f32| {
+            // `Val::Auto` fails to resolve and falls back to the length of the origin box
+            let tile = scale_val(val, scale_factor)
+                .resolve(length, physical_target_size)
+                .unwrap_or(length)
+                .max(0.);
+            let (tile, period) = match repeat {
+                TileRepeat::Repeat => (tile, tile),
+                TileRepeat::Round if 0. < tile => {
+                    let tile = length / (length / tile).round().max(1.);
+                    (tile, tile)
+                }
+                TileRepeat::Space if 0. < tile && 2. <= (length / tile).floor() => {
+                    let count = (length / tile).floor();
+                    (tile, tile + (length - count * tile) / (count - 1.))
+                }
+                _ => (tile, f32::INFINITY),
+            };
+            (tile, if 0. < period { period } else { f32::INFINITY })
+        };
+        let (width, period_x) = resolve_axis(self.width, self.repeat_x, box_size.x);
+        let (height, period_y) = resolve_axis(self.height, self.repeat_y, box_size.y);
+        PhysicalTiling {
+            start: box_offset - 0.5 * box_size,
+            size: Vec2::new(width, height),
+            period: Vec2::new(period_x, period_y),
         }
     }
 }
 
+impl Default for GradientTiling {
+    fn default() -> Self {
+        Self::new(Val::Auto, Val::Auto)
+    }
+}
+
+/// The pattern of a [`GradientGrain`]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub enum GrainPattern {
+    /// Random noise added to the colors
+    #[default]
+    Noise,
+    /// Ordered dithering with a 4x4 Bayer matrix
+    Bayer4,
+    /// Ordered dithering with an 8x8 Bayer matrix, with finer gradations than [`GrainPattern::Bayer4`]
+    Bayer8,
+}
+
+/// Film grain noise or ordered dithering drawn over a gradient.
+///
+/// The noise is added in sRGB space, which hides banding in dark gradients more effectively than ordered dithering.
+///
+/// With a Bayer pattern, each sRGB channel is instead quantized to steps of `intensity`, and the Bayer matrix
+/// picks between the two nearest steps, giving the visible crosshatch of PSX and DOS era graphics.
+/// Bayer patterns aren't animated.
+///
+/// Grain is only drawn by the UI gradient pipeline, it isn't applied by [`GradientMaterial`] or to CPU sampling.
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub struct GradientGrain {
+    /// The strength of the noise. Around `0.01` is enough to hide banding, higher values give a visible film grain effect.
+    /// With a Bayer pattern, the size of the quantization steps, such as `1. / 31.` for 15-bit color.
+    pub intensity: f32,
+    /// The size of the grains or dithering cells in logical pixels
+    pub scale: f32,
+    /// If true, the noise changes every frame
+    pub animated: bool,
+    /// The pattern of the grain
+    pub pattern: GrainPattern,
+}
+
+impl GradientGrain {
+    /// Create a new animated `GradientGrain`
+    pub fn new(intensity: f32, scale: f32) -> Self {
+        Self {
+            intensity,
+            scale,
+            animated: true,
+            pattern: GrainPattern::Noise,
+        }
+    }
+
+    /// Subtle static noise for hiding banding
+    pub fn dither() -> Self {
+        Self {
+            intensity: 0.01,
+            scale: 1.,
+            animated: false,
+            pattern: GrainPattern::Noise,
+        }
+    }
+
+    /// Ordered dithering with a Bayer `pattern`, quantizing each sRGB channel to `levels` levels
+    ///
+    /// ```
+    /// # use bevy_ui_gradients::*;
+    /// // 5 bits per channel, in cells of 2 by 2 logical pixels
+    /// let grain = GradientGrain::bayer(GrainPattern::Bayer4, 32).with_scale(2.);
+    /// assert_eq!(grain.intensity, 1. / 31.);
+    /// ```
+    pub fn bayer(pattern: GrainPattern, levels: u32) -> Self {
+        Self {
+            intensity: 1. / levels.saturating_sub(1).max(1) as f32,
+            scale: 1.,
+            animated: false,
+            pattern,
+        }
+    }
+
+    /// Sets the size of the grains or dithering cells in logical pixels
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the pattern of the grain
+    pub fn with_pattern(mut self, pattern: GrainPattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    /// Sets whether the noise changes every frame
+    pub fn with_animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+}
+
+impl Default for GradientGrain {
+    fn default() -> Self {
+        Self::new(0.05, 1.)
+    }
+}
+
+/// How a gradient layer is composited with what's drawn beneath it, like CSS `background-blend-mode`.
+///
+/// Unlike CSS the layer is blended with everything drawn beneath it, including the node's background color and
+/// image and the nodes behind it, not only the node's other background layers.
+/// Only the modes that can be done with the GPU's fixed function blending are available.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub enum GradientBlendMode {
+    /// The gradient is drawn over what's beneath it
+    #[default]
+    Normal,
+    /// The colors are multiplied, darkening what's beneath the gradient.
+    /// The alpha of what's beneath is left unchanged.
+    Multiply,
+    /// The inverted colors are multiplied, lightening what's beneath the gradient
+    Screen,
+    /// The gradient's colors are added to what's beneath it
+    Add,
+}
+
+/// The quality tier gradients are drawn at, so the same UI scales from integrated mobile GPUs to desktops.
+///
+/// Insert the resource after adding [`UiGradientsPlugin`] to change the quality. Changing it at runtime
+/// specializes new pipelines, which can cause a brief hitch while they compile.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub enum GradientQuality {
+    /// Colors are mixed linearly in linear RGB, ignoring hints and interpolation curves, and grain isn't drawn.
+    ///
+    /// Untiled linear gradients with padded spread, and without custom functions, rotation, scrolling,
+    /// oscillating stops or texture displacement, interpolate their colors per vertex. Each segment is
+    /// drawn over its own band of the node instead of over the whole node.
+    Low,
+    /// Colors are mixed in linear RGB instead of sRGB, skipping the color space conversions
+    Medium,
+    /// Full per-fragment evaluation, with hints, interpolation curves, grain and colors mixed in sRGB space
+    #[default]
+    High,
+}
+
+/// How a [`GradientTexture`] modulates a gradient
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub enum TextureModulation {
+    /// The gradient's alpha is multiplied by the texture, mixed with `1.` by the given strength between `0.` and `1.`
+    Alpha(f32),
+    /// The distance along the gradient line is offset by the texture, by up to half the given amount in
+    /// either direction. The amount is in logical pixels, or radians for conic gradients.
+    Displace(f32),
+    /// The gradient's colors are multiplied by the texture's colors, including their alpha.
+    /// A gradient with a single white stop draws the texture unchanged, see [`Gradient::image`].
+    Multiply,
+}
+
+impl Default for TextureModulation {
+    fn default() -> Self {
+        Self::Alpha(1.)
+    }
+}
+
+/// A texture modulating a gradient layer, for brushed metal, paper grain and scratch effects.
+///
+/// Except with [`TextureModulation::Multiply`] only the texture's red channel is used, so grayscale images work.
+/// The texture is stretched across the node,
+/// or repeated every `tile_size` logical pixels. Repeated textures need an image sampler with
+/// `ImageAddressMode::Repeat`.
+///
+/// Textures are only drawn by the UI gradient pipeline. They aren't applied by [`GradientMaterial`], to the
+/// gradients of [`GradientMask`]s or to CPU sampling.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ui_gradients::*;
+/// # let brushed_metal = Handle::<Image>::default();
+/// let panel = LinearGradient::to_bottom(vec![
+///     Color::srgb(0.7, 0.7, 0.75).into(),
+///     Color::srgb(0.4, 0.4, 0.45).into(),
+/// ])
+/// .with_texture(
+///     GradientTexture::new(brushed_metal, TextureModulation::Displace(12.))
+///         .with_tile_size(Vec2::new(256., 64.)),
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq)]
+pub struct GradientTexture {
+    /// The modulating texture
+    pub image: Handle<Image>,
+    /// How the texture modulates the gradient
+    pub modulation: TextureModulation,
+    /// If set, the texture is repeated every `tile_size` logical pixels instead of being stretched across the node
+    pub tile_size: Option<Vec2>,
+}
+
+impl GradientTexture {
+    /// Modulate a gradient with `image`, stretched across the node
+    pub fn new(image: Handle<Image>, modulation: TextureModulation) -> Self {
+        Self {
+            image,
+            modulation,
+            tile_size: None,
+        }
+    }
+
+    /// Repeats the texture every `tile_size` logical pixels
+    pub fn with_tile_size(mut self, tile_size: Vec2) -> Self {
+        self.tile_size = Some(tile_size);
+        self
+    }
+}
+
+/// Color filters applied to a gradient in the shader, like the CSS `filter` functions of the same names.
+///
+/// The filters are applied in sRGB space after the gradient is interpolated, in the order hue rotation,
+/// saturation, grayscale and brightness. Changing them doesn't change the gradient's color stops, so they
+/// can be animated cheaply for accessibility color modes and flash effects.
+/// Filters aren't applied by [`GradientMaterial`].
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub struct GradientFilter {
+    /// Rotation of the hue of the colors
+    pub hue_rotate: Angle,
+    /// Saturation multiplier. `0.` is fully desaturated and values above `1.` oversaturate.
+    pub saturate: f32,
+    /// Amount of conversion to grayscale, between `0.` and `1.`
+    pub grayscale: f32,
+    /// Brightness multiplier. `0.` is black.
+    pub brightness: f32,
+}
+
+impl GradientFilter {
+    /// Filters that leave the colors unchanged
+    pub const IDENTITY: Self = Self {
+        hue_rotate: Angle::ZERO,
+        saturate: 1.,
+        grayscale: 0.,
+        brightness: 1.,
+    };
+
+    /// Sets the rotation of the hue
+    pub fn with_hue_rotate(mut self, hue_rotate: impl Into<Angle>) -> Self {
+        self.hue_rotate = hue_rotate.into();
+        self
+    }
+
+    /// Sets the saturation multiplier
+    pub fn with_saturate(mut self, saturate: f32) -> Self {
+        self.saturate = saturate;
+        self
+    }
+
+    /// Sets the amount of conversion to grayscale
+    pub fn with_grayscale(mut self, grayscale: f32) -> Self {
+        self.grayscale = grayscale;
+        self
+    }
+
+    /// Sets the brightness multiplier
+    pub fn with_brightness(mut self, brightness: f32) -> Self {
+        self.brightness = brightness;
+        self
+    }
+
+    /// Returns true if the filters leave the colors unchanged
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Applies the filters to a color, as they're applied in the shader
+    pub fn apply(&self, color: LinearRgba) -> LinearRgba {
+        let srgb = Srgba::from(color);
+        let rgb = Vec3::new(srgb.red, srgb.green, srgb.blue);
+        let (s, c) = self.hue_rotate.to_radians().sin_cos();
+        // The CSS `hue-rotate()` matrix
+        let rgb = Vec3::new(
+            rgb.dot(Vec3::new(
+                0.213 + c * 0.787 - s * 0.213,
+                0.715 - c * 0.715 - s * 0.715,
+                0.072 - c * 0.072 + s * 0.928,
+            )),
+            rgb.dot(Vec3::new(
+                0.213 - c * 0.213 + s * 0.143,
+                0.715 + c * 0.285 + s * 0.140,
+                0.072 - c * 0.072 - s * 0.283,
+            )),
+            rgb.dot(Vec3::new(
+                0.213 - c * 0.213 - s * 0.787,
+                0.715 - c * 0.715 + s * 0.715,
+                0.072 + c * 0.928 + s * 0.072,
+            )),
+        );
+        let luma = Vec3::splat(rgb.dot(Vec3::new(0.213, 0.715, 0.072)));
+        let rgb = luma.lerp(rgb, self.saturate);
+        let rgb = rgb.lerp(luma, self.grayscale);
+        let rgb = (rgb * self.brightness).max(Vec3::ZERO);
+        Srgba::new(rgb.x, rgb.y, rgb.z, color.alpha).into()
+    }
+}
+
+impl Default for GradientFilter {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
 impl From<LinearGradient> for Gradient {
     fn from(value: LinearGradient) -> Self {
         Self::Linear(value)
@@ -571,8 +2117,20 @@ impl From<ConicGradient> for Gradient {
     }
 }
 
+impl From<InsetGradient> for Gradient {
+    fn from(value: InsetGradient) -> Self {
+        Self::Inset(value)
+    }
+}
+
+impl From<PlasmaGradient> for Gradient {
+    fn from(value: PlasmaGradient) -> Self {
+        Self::Plasma(value)
+    }
+}
+
 #[derive(Default, Component, Clone, PartialEq, Debug, Reflect)]
-#[reflect(PartialEq)]
+#[reflect(Component, Default, PartialEq, Debug)]
 /// A UI node that displays a gradient
 pub struct BackgroundGradient(pub Vec<Gradient>);
 
@@ -582,8 +2140,43 @@ impl<T: Into<Gradient>> From<T> for BackgroundGradient {
     }
 }
 
-#[derive(Component, Clone, PartialEq, Debug, Reflect)]
-#[reflect(PartialEq)]
+impl From<Vec<Gradient>> for BackgroundGradient {
+    fn from(value: Vec<Gradient>) -> Self {
+        Self(value)
+    }
+}
+
+impl BackgroundGradient {
+    /// A single layer filled with `color`
+    pub fn solid(color: impl Into<Color>) -> Self {
+        Self::from(LinearGradient::to_bottom(vec![color.into().into()]))
+    }
+
+    /// Adds `gradient` as the top layer
+    pub fn with_layer(mut self, gradient: impl Into<Gradient>) -> Self {
+        self.push(gradient);
+        self
+    }
+
+    /// Adds `gradient` as the top layer, drawn over the other layers
+    pub fn push(&mut self, gradient: impl Into<Gradient>) {
+        self.0.push(gradient.into());
+    }
+
+    /// Inserts `gradient` at `index`, the layers at and above `index` are shifted up and drawn over it.
+    /// Indices past the top layer are clamped, so the gradient becomes the top layer.
+    pub fn insert(&mut self, index: usize, gradient: impl Into<Gradient>) {
+        self.0.insert(index.min(self.0.len()), gradient.into());
+    }
+
+    /// Removes and returns the layer at `index`, or `None` if there's no layer at `index`
+    pub fn remove(&mut self, index: usize) -> Option<Gradient> {
+        (index < self.0.len()).then(|| self.0.remove(index))
+    }
+}
+
+#[derive(Default, Component, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq, Debug)]
 /// A UI node border that displays a gradient
 pub struct BorderGradient(pub Vec<Gradient>);
 
@@ -593,6 +2186,38 @@ impl<T: Into<Gradient>> From<T> for BorderGradient {
     }
 }
 
+/// How a node's [`BorderGradient`] is composited with its `BorderColor`.
+///
+/// Nodes with a `BorderGradient` and no `BorderColorBlend` composite the gradient over the border color.
+#[derive(Component, Default, Copy, Clone, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component, Default, PartialEq, Debug)]
+pub enum BorderColorBlend {
+    /// The border color isn't drawn, only the border gradient
+    Replace,
+    /// The border gradient is drawn over the border color.
+    /// The border color shows through transparent parts of the gradient.
+    #[default]
+    Over,
+    /// The border color is multiplied by the border gradient, ignoring the blend modes of its layers
+    Multiply,
+}
+
+#[derive(Component, Clone, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq)]
+/// Masks a UI node's `BackgroundColor` and `ImageNode` with the alpha channel of a gradient,
+/// for fading out the edges of scroll lists, vignette cutoffs and similar effects.
+///
+/// The gradient's colors are ignored, only their alpha is used.
+/// The masked background color and image are drawn by the gradient pipeline in place of the node's
+/// normal background color and image. Sliced and tiled images aren't masked.
+pub struct GradientMask(pub Gradient);
+
+impl<T: Into<Gradient>> From<T> for GradientMask {
+    fn from(value: T) -> Self {
+        Self(value.into())
+    }
+}
+
 #[derive(Default, Copy, Clone, PartialEq, Debug, Reflect)]
 #[reflect(PartialEq, Default)]
 pub enum RadialGradientShape {
@@ -605,10 +2230,52 @@ pub enum RadialGradientShape {
     ClosestCorner,
     /// An ellipse with extents equal to the distance from its center to the farthest corner
     FarthestCorner,
-    /// A circle
+    /// A circle.
+    /// `Val::Auto` resolves to the distance from its center to the farthest corner.
     Circle(Val),
-    /// An ellipse
+    /// An ellipse.
+    /// A `Val::Auto` extent resolves to the distance from its center to the farthest side on that axis,
+    /// matching [`RadialGradientShape::FarthestCorner`].
     Ellipse(Val, Val),
+    /// An end shape sized by an extent keyword, following the CSS `radial-gradient` model.
+    ///
+    /// Unlike the keyword variants above, every extent is available for both circles and ellipses.
+    Extent {
+        /// Whether the end shape is a circle or an ellipse
+        shape: EndShape,
+        /// Which side or corner the end shape reaches
+        extent: RadialExtent,
+    },
+}
+
+/// The end shape of a radial gradient
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub enum EndShape {
+    /// A circle
+    Circle,
+    /// An axis-aligned ellipse
+    #[default]
+    Ellipse,
+}
+
+/// How far the end shape of a radial gradient reaches, relative to the sides and corners of the node
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug, Reflect)]
+#[reflect(PartialEq, Default)]
+pub enum RadialExtent {
+    /// The end shape touches the side closest to its center.
+    /// An ellipse touches the closest side on each axis.
+    ClosestSide,
+    /// The end shape touches the side farthest from its center.
+    /// An ellipse touches the farthest side on each axis.
+    FarthestSide,
+    /// The end shape passes through the corner closest to its center.
+    /// An ellipse keeps the aspect ratio it would have with [`RadialExtent::ClosestSide`].
+    ClosestCorner,
+    /// The end shape passes through the corner farthest from its center.
+    /// An ellipse keeps the aspect ratio it would have with [`RadialExtent::FarthestSide`].
+    #[default]
+    FarthestCorner,
 }
 
 fn close_side(p: f32, h: f32) -> f32 {
@@ -628,7 +2295,28 @@ fn far_side2(p: Vec2, h: Vec2) -> f32 {
 }
 
 impl RadialGradientShape {
+    /// An end shape sized by an extent keyword
+    pub const fn extent(shape: EndShape, extent: RadialExtent) -> Self {
+        Self::Extent { shape, extent }
+    }
+
     /// Resolve the physical dimensions of the end shape of the radial gradient
+    ///
+    /// `scale_factor` is the node's target scale factor, including `UiScale`.
+    /// `physical_target_size` is the size of the viewport of the UI node's camera.
+    ///
+    /// `Val::Auto` radii fall back to farthest-corner sizing, like an omitted size in CSS:
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_gradients::RadialGradientShape;
+    /// let size = Vec2::new(300., 400.);
+    /// let circle = RadialGradientShape::Circle(Val::Auto).resolve(Vec2::ZERO, 1., size, size);
+    /// assert_eq!(circle, Vec2::splat(250.));
+    ///
+    /// let ellipse = RadialGradientShape::Ellipse(Val::Auto, Val::Px(10.)).resolve(Vec2::ZERO, 1., size, size);
+    /// assert_eq!(ellipse, Vec2::new(150., 10.));
+    /// ```
     pub fn resolve(
         self,
         position: Vec2,
@@ -648,19 +2336,65 @@ impl RadialGradientShape {
                 far_side(position.x, half_size.x),
                 far_side(position.y, half_size.y),
             ),
+            RadialGradientShape::Circle(Val::Auto) => Vec2::splat(
+                Vec2::new(
+                    far_side(position.x, half_size.x),
+                    far_side(position.y, half_size.y),
+                )
+                .length(),
+            ),
             RadialGradientShape::Circle(radius) => Vec2::splat(
                 scale_val(radius, scale_factor)
                     .resolve(physical_size.x, physical_target_size)
                     .unwrap_or(0.),
             ),
             RadialGradientShape::Ellipse(x, y) => Vec2::new(
-                scale_val(x, scale_factor)
-                    .resolve(physical_size.x, physical_target_size)
-                    .unwrap_or(0.),
-                scale_val(y, scale_factor)
-                    .resolve(physical_size.y, physical_target_size)
-                    .unwrap_or(0.),
+                match x {
+                    Val::Auto => far_side(position.x, half_size.x),
+                    x => scale_val(x, scale_factor)
+                        .resolve(physical_size.x, physical_target_size)
+                        .unwrap_or(0.),
+                },
+                match y {
+                    Val::Auto => far_side(position.y, half_size.y),
+                    y => scale_val(y, scale_factor)
+                        .resolve(physical_size.y, physical_target_size)
+                        .unwrap_or(0.),
+                },
             ),
+            RadialGradientShape::Extent { shape, extent } => {
+                let closest = Vec2::new(
+                    close_side(position.x, half_size.x),
+                    close_side(position.y, half_size.y),
+                );
+                let farthest = Vec2::new(
+                    far_side(position.x, half_size.x),
+                    far_side(position.y, half_size.y),
+                );
+                match (shape, extent) {
+                    (EndShape::Circle, RadialExtent::ClosestSide) => {
+                        Vec2::splat(closest.min_element())
+                    }
+                    (EndShape::Circle, RadialExtent::FarthestSide) => {
+                        Vec2::splat(farthest.max_element())
+                    }
+                    (EndShape::Circle, RadialExtent::ClosestCorner) => {
+                        Vec2::splat(closest.length())
+                    }
+                    (EndShape::Circle, RadialExtent::FarthestCorner) => {
+                        Vec2::splat(farthest.length())
+                    }
+                    (EndShape::Ellipse, RadialExtent::ClosestSide) => closest,
+                    (EndShape::Ellipse, RadialExtent::FarthestSide) => farthest,
+                    // Scaling the side extents by sqrt(2) makes the ellipse pass through the corner
+                    (EndShape::Ellipse, RadialExtent::ClosestCorner) => {
+                        core::f32::consts::SQRT_2 * closest
+                    }
+                    (EndShape::Ellipse, RadialExtent::FarthestCorner) => {
+                        core::f32::consts::SQRT_2 * farthest
+                    }
+                }
+            }
         }
     }
 }
@@ -670,6 +2404,61 @@ pub struct UiGradientsPlugin;
 impl Plugin for UiGradientsPlugin {
     fn build(&self, app: &mut App) {
         build_gradients_renderer(app);
+        build_gradient_material(app);
+        app.add_plugins(UiMaterialPlugin::<GradientMaterial>::default())
+            .add_systems(
+                PreUpdate,
+                update_gradient_picking.in_set(PickSet::PostInput),
+            )
+            .init_resource::<GradientTheme>()
+            .init_resource::<GradientAccessibility>()
+            .init_resource::<InvalidValuePolicy>()
+            .init_resource::<GradientQuality>()
+            .register_type::<BackgroundGradient>()
+            .register_type::<BorderGradient>()
+            // Stops are stored in `SmallVec`s, which don't register their items
+            .register_type::<GradientLayer>()
+            .register_type::<ColorStop>()
+            .register_type::<AngularColorStop>()
+            .register_type::<BorderColorBlend>()
+            .register_type::<UiOpacity>()
+            .register_type::<GradientButton>()
+            .init_asset::<GradientAsset>()
+            .init_asset_loader::<GradientAssetLoader>()
+            .register_type::<GradientAnimationClip>()
+            .init_asset::<GradientAnimationClip>()
+            .init_asset_loader::<GradientAnimationClipLoader>()
+            .add_systems(
+                Update,
+                (
+                    // These all write `BackgroundGradient`. On nodes with several drivers the
+                    // later ones win: assets set the base gradients, themes replace them,
+                    // interaction states cross-fade them, and timelines and clips animate them.
+                    (
+                        update_gradient_sources,
+                        apply_gradient_theme,
+                        update_gradient_buttons,
+                        update_interaction_gradients,
+                        update_gradient_timelines,
+                        update_gradient_animation_players,
+                    )
+                        .chain(),
+                    update_gradient_legends,
+                    update_saturation_value_boxes,
+                    update_gradient_sliders,
+                    update_gradient_backdrops,
+                    update_sky_gradients,
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    update_gradient_materials.after(UiSystem::Layout),
+                    update_gradient_group_bounds.after(TransformSystem::TransformPropagate),
+                    update_gradient_shadow_colors.after(update_gradient_group_bounds),
+                    update_gradient_fades,
+                ),
+            );
     }
 
     fn finish(&self, app: &mut App) {
@@ -0,0 +1,227 @@
+use crate::*;
+use bevy::{
+    asset::{Asset, Assets, Handle, load_internal_asset, weak_handle},
+    color::ColorToComponents,
+    ecs::system::{Query, ResMut},
+    math::Vec4,
+    reflect::TypePath,
+    render::render_resource::{AsBindGroup, Shader, ShaderRef},
+    ui::{ComputedNode, ComputedNodeTarget, MaterialNode, UiMaterial},
+};
+
+/// The maximum number of color stops a [`GradientMaterial`] can display.
+/// Any further stops are ignored. Smoothed gradients are divided into as many stops as fit.
+pub const MAX_MATERIAL_STOPS: usize = 16;
+
+pub const GRADIENT_FUNCTIONS_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("4a5c2f0e-8d63-4c8b-b7a5-6f2d9e1c3b70");
+
+pub const GRADIENT_MATERIAL_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("b1e0d4c7-2a9f-4e61-8c3d-75a0f9b2e416");
+
+pub(crate) fn build_gradient_material(app: &mut App) {
+    load_internal_asset!(
+        app,
+        GRADIENT_FUNCTIONS_SHADER_HANDLE,
+        "gradient_functions.wgsl",
+        Shader::from_wgsl
+    );
+    load_internal_asset!(
+        app,
+        GRADIENT_MATERIAL_SHADER_HANDLE,
+        "gradient_material.wgsl",
+        Shader::from_wgsl
+    );
+}
+
+/// A [`UiMaterial`] that draws a single gradient.
+///
+/// Spawn with a [`MaterialNode<GradientMaterial>`]. The gradient is resolved against the node's
+/// size after layout, so each node should be given its own material handle.
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone, PartialEq)]
+pub struct GradientMaterial {
+    /// The gradient drawn by the material
+    pub gradient: Gradient,
+    /// The resolved gradient, updated by `update_gradient_materials`
+    #[uniform(0)]
+    pub resolved: GradientMaterialUniform,
+}
+
+impl GradientMaterial {
+    /// Create a new gradient material
+    pub fn new(gradient: impl Into<Gradient>) -> Self {
+        Self {
+            gradient: gradient.into(),
+            resolved: GradientMaterialUniform::default(),
+        }
+    }
+}
+
+impl From<Gradient> for GradientMaterial {
+    fn from(gradient: Gradient) -> Self {
+        Self::new(gradient)
+    }
+}
+
+impl UiMaterial for GradientMaterial {
+    fn fragment_shader() -> ShaderRef {
+        GRADIENT_MATERIAL_SHADER_HANDLE.into()
+    }
+}
+
+pub mod gradient_material_kind {
+    pub const LINEAR: u32 = 0;
+    pub const RADIAL: u32 = 1;
+    pub const CONIC: u32 = 2;
+    pub const INSET: u32 = 3;
+    pub const PLASMA: u32 = 4;
+}
+
+pub mod gradient_material_spread {
+    pub const PAD: u32 = 0;
+    pub const REPEAT: u32 = 1;
+    pub const REFLECT: u32 = 2;
+}
+
+pub use uniform::GradientMaterialUniform;
+
+// `ShaderType` generates layout checks that trip the dead code lint, so the uniform lives in its own module.
+#[allow(dead_code)]
+mod uniform {
+    use super::MAX_MATERIAL_STOPS;
+    use bevy::{
+        math::{Vec2, Vec4},
+        render::render_resource::ShaderType,
+    };
+
+    /// A gradient resolved for a node of a particular size, in the layout expected by the
+    /// `bevy_ui_gradients::gradient_functions` shader import.
+    ///
+    /// Custom [`UiMaterial`](bevy::ui::UiMaterial)s can embed this as a uniform and call `gradient_color` from their own shaders.
+    #[derive(Default, Debug, Clone, Copy, PartialEq, ShaderType)]
+    pub struct GradientMaterialUniform {
+        /// One of the constants in [`gradient_material_kind`](crate::gradient_material_kind)
+        pub kind: u32,
+        /// The number of stops in use
+        pub stops_len: u32,
+        /// Start point of a linear gradient or the center of a radial or conic gradient,
+        /// relative to the center of the node.
+        pub start: Vec2,
+        /// Linear: the direction of the gradient line.
+        /// Radial: `x` is the ratio of the ellipse's width to its height.
+        /// Conic: `x` is the start angle, `y` is the ratio of the ellipse's width to its height.
+        /// Inset: the size of the box, which isn't rounded.
+        /// Plasma: `x` is the scale, the plasma isn't animated.
+        pub dir: Vec2,
+        /// One of the constants in [`gradient_material_spread`](crate::gradient_material_spread)
+        pub spread: u32,
+        /// Stop colors in linear RGBA
+        pub colors: [Vec4; MAX_MATERIAL_STOPS],
+        /// `x`: the physical distance of the stop along the gradient line, `y`: the hint,
+        /// `zw`: the packed [`Interpolation`] to the following stop
+        pub stops: [Vec4; MAX_MATERIAL_STOPS],
+    }
+}
+
+impl GradientMaterialUniform {
+    /// Resolve a gradient for a node with the given physical size.
+    /// `scale_factor` should include `UiScale`, as `ComputedNodeTarget::scale_factor` does.
+    pub fn new(
+        gradient: &Gradient,
+        scale_factor: f32,
+        physical_size: Vec2,
+        physical_target_size: Vec2,
+    ) -> Self {
+        // Smoothed gradients are subdivided to fit in the material's stops
+        let smoothing = gradient.layer().smoothing;
+        let mut physical = PhysicalGradient::new(
+            &gradient.clone().with_smoothing(GradientSmoothing::None),
+            scale_factor,
+            physical_size,
+            physical_target_size,
+        );
+        let segments = physical.stops.len().saturating_sub(1).max(1);
+        smoothing.smooth_stops(&mut physical.stops, 0, (MAX_MATERIAL_STOPS - 1) / segments);
+        let mut uniform = Self::default();
+
+        match physical.shape {
+            PhysicalGradientShape::Linear { start, dir } => {
+                uniform.kind = gradient_material_kind::LINEAR;
+                uniform.start = start;
+                uniform.dir = dir;
+            }
+            PhysicalGradientShape::Radial { center, ratio, .. } => {
+                uniform.kind = gradient_material_kind::RADIAL;
+                uniform.start = center;
+                uniform.dir = Vec2::splat(ratio);
+            }
+            PhysicalGradientShape::Conic {
+                center,
+                start,
+                aspect_ratio,
+                ..
+            } => {
+                uniform.kind = gradient_material_kind::CONIC;
+                uniform.start = center;
+                uniform.dir = Vec2::new(start, aspect_ratio);
+            }
+            PhysicalGradientShape::Inset { center, size, .. } => {
+                uniform.kind = gradient_material_kind::INSET;
+                uniform.start = center;
+                uniform.dir = size;
+            }
+            PhysicalGradientShape::Plasma { scale } => {
+                uniform.kind = gradient_material_kind::PLASMA;
+                uniform.dir = Vec2::new(scale, 0.);
+            }
+        }
+
+        uniform.spread = match physical.spread {
+            SpreadMethod::Pad => gradient_material_spread::PAD,
+            SpreadMethod::Repeat => gradient_material_spread::REPEAT,
+            SpreadMethod::Reflect => gradient_material_spread::REFLECT,
+        };
+
+        for (i, (color, point, hint, interpolation, _)) in physical
+            .stops
+            .into_iter()
+            .take(MAX_MATERIAL_STOPS)
+            .enumerate()
+        {
+            uniform.colors[i] = Vec4::from_array(color.to_f32_array());
+            let [a, b] = interpolation.pack();
+            uniform.stops[i] = Vec4::new(point, hint, a, b);
+            uniform.stops_len = i as u32 + 1;
+        }
+
+        uniform
+    }
+}
+
+/// Resolves the gradients of [`GradientMaterial`]s against the sizes of their nodes.
+pub fn update_gradient_materials(
+    mut materials: ResMut<Assets<GradientMaterial>>,
+    query: Query<(
+        &MaterialNode<GradientMaterial>,
+        &ComputedNode,
+        &ComputedNodeTarget,
+    )>,
+) {
+    for (material_node, node, target) in &query {
+        let Some(material) = materials.get(material_node.id()) else {
+            continue;
+        };
+        let resolved = GradientMaterialUniform::new(
+            &material.gradient,
+            target.scale_factor(),
+            node.size(),
+            target.physical_size().as_vec2(),
+        );
+        // Only mutate the asset when the resolved gradient changes, to avoid re-preparing it every frame
+        if material.resolved != resolved {
+            if let Some(material) = materials.get_mut(material_node.id()) {
+                material.resolved = resolved;
+            }
+        }
+    }
+}
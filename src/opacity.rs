@@ -0,0 +1,43 @@
+use crate::*;
+use bevy::{
+    ecs::{entity::Entity, hierarchy::ChildOf, system::Query},
+    prelude::ReflectComponent,
+};
+
+/// The opacity of a UI node's gradients and the gradients of its descendants.
+///
+/// Opacities are inherited, a node's gradients are drawn with the product of its opacity and the
+/// opacities of its ancestors, so a whole panel can be faded out without changing the color stops of its gradients.
+/// Background and border colors of nodes with gradients are faded too, as they're drawn by the gradient pipeline.
+/// Bevy's own background colors, images and text aren't affected.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct UiOpacity(pub f32);
+
+impl Default for UiOpacity {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+impl From<f32> for UiOpacity {
+    fn from(opacity: f32) -> Self {
+        Self(opacity)
+    }
+}
+
+/// The product of the [`UiOpacity`]s of `entity` and its ancestors, clamped between `0.` and `1.`
+pub fn inherited_ui_opacity(
+    entity: Entity,
+    nodes: &Query<(Option<&UiOpacity>, Option<&ChildOf>)>,
+) -> f32 {
+    let mut opacity = 1.;
+    let mut current = Some(entity);
+    while let Some(Ok((node_opacity, child_of))) = current.map(|entity| nodes.get(entity)) {
+        opacity *= node_opacity
+            .map(|opacity| opacity.0.clamp(0., 1.))
+            .unwrap_or(1.);
+        current = child_of.map(ChildOf::parent);
+    }
+    opacity
+}
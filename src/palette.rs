@@ -0,0 +1,316 @@
+use crate::*;
+use bevy::{
+    asset::{Handle, RenderAssetUsages},
+    color::{ColorToPacked, Hsva, Laba},
+    image::{Image, ImageSampler},
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use core::fmt;
+use std::path::Path;
+
+/// An error returned when importing a [`Palette`] fails
+#[derive(Debug)]
+pub enum PaletteError {
+    /// The palette file couldn't be read
+    Io(std::io::Error),
+    /// A line in a hex list that isn't a hex color. Lines are numbered from 1.
+    InvalidHex { line: usize, text: String },
+    /// The data ended before the end of the swatch file
+    UnexpectedEof,
+    /// The data isn't a swatch file of the expected format
+    InvalidFormat,
+    /// A swatch file color in a color space that isn't supported
+    UnsupportedColorSpace(String),
+}
+
+impl fmt::Display for PaletteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read palette: {error}"),
+            Self::InvalidHex { line, text } => {
+                write!(f, "invalid hex color `{text}` on line {line}")
+            }
+            Self::UnexpectedEof => write!(f, "unexpected end of swatch data"),
+            Self::InvalidFormat => write!(f, "invalid swatch file"),
+            Self::UnsupportedColorSpace(space) => {
+                write!(f, "unsupported swatch color space `{space}`")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PaletteError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for PaletteError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// A list of colors imported from a design tool, used to build evenly spaced gradients.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Palette(pub Vec<Color>);
+
+impl Palette {
+    /// Parses a list of hex colors with one color per line, like lospec `.hex` palettes.
+    ///
+    /// The `#` prefix is optional. Blank lines and lines starting with `;` or `//` are ignored.
+    pub fn from_hex_list(text: &str) -> Result<Self, PaletteError> {
+        text.lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| {
+                !(line.is_empty() || line.starts_with(';') || line.starts_with("//"))
+            })
+            .map(|(line, text)| {
+                Srgba::hex(text.trim_start_matches('#'))
+                    .map(Color::from)
+                    .map_err(|_| PaletteError::InvalidHex {
+                        line,
+                        text: text.to_string(),
+                    })
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    /// Parses an Adobe Swatch Exchange (`.ase`) file.
+    ///
+    /// Groups are flattened, and the colors are returned in the order they appear in the file.
+    pub fn from_ase(bytes: &[u8]) -> Result<Self, PaletteError> {
+        let mut reader = Reader(bytes);
+        if reader.take(4)? != b"ASEF" {
+            return Err(PaletteError::InvalidFormat);
+        }
+        // Version
+        reader.take(4)?;
+        let blocks = reader.u32()?;
+
+        let mut colors = vec![];
+        for _ in 0..blocks {
+            let kind = reader.u16()?;
+            let len = reader.u32()? as usize;
+            let mut block = Reader(reader.take(len)?);
+            // Group start and end blocks don't contain colors
+            if kind != 0x0001 {
+                continue;
+            }
+            let name_len = block.u16()? as usize;
+            block.take(2 * name_len)?;
+            let model = block.take(4)?;
+            let color = match model {
+                b"RGB " => Srgba::new(block.f32()?, block.f32()?, block.f32()?, 1.).into(),
+                b"CMYK" => cmyk(block.f32()?, block.f32()?, block.f32()?, block.f32()?),
+                b"LAB " => {
+                    Laba::new(block.f32()?, block.f32()? / 100., block.f32()? / 100., 1.).into()
+                }
+                b"Gray" => {
+                    let value = block.f32()?;
+                    Srgba::new(value, value, value, 1.).into()
+                }
+                _ => {
+                    return Err(PaletteError::UnsupportedColorSpace(
+                        String::from_utf8_lossy(model).trim().to_string(),
+                    ));
+                }
+            };
+            colors.push(color);
+        }
+        Ok(Self(colors))
+    }
+
+    /// Parses an Adobe Photoshop color swatch (`.aco`) file.
+    pub fn from_aco(bytes: &[u8]) -> Result<Self, PaletteError> {
+        let mut reader = Reader(bytes);
+        // Version 1 and version 2 files both begin with a version 1 section listing every color
+        if !matches!(reader.u16()?, 1 | 2) {
+            return Err(PaletteError::InvalidFormat);
+        }
+        let count = reader.u16()?;
+
+        let mut colors = vec![];
+        for _ in 0..count {
+            let space = reader.u16()?;
+            let w = reader.u16()? as f32;
+            let x = reader.u16()? as f32;
+            let y = reader.u16()? as f32;
+            let z = reader.u16()? as f32;
+            let color = match space {
+                0 => Srgba::new(w / 65535., x / 65535., y / 65535., 1.).into(),
+                1 => Hsva::new(w / 65535. * 360., x / 65535., y / 65535., 1.).into(),
+                // CMYK values are stored inverted, with 0 being full ink
+                2 => cmyk(
+                    1. - w / 65535.,
+                    1. - x / 65535.,
+                    1. - y / 65535.,
+                    1. - z / 65535.,
+                ),
+                // Lab values are in hundredths, with signed a and b
+                7 => Laba::new(
+                    w / 10000.,
+                    (x as u16 as i16) as f32 / 10000.,
+                    (y as u16 as i16) as f32 / 10000.,
+                    1.,
+                )
+                .into(),
+                // Grayscale is stored as the amount of black
+                8 => {
+                    let value = 1. - w / 10000.;
+                    Srgba::new(value, value, value, 1.).into()
+                }
+                space => return Err(PaletteError::UnsupportedColorSpace(space.to_string())),
+            };
+            colors.push(color);
+        }
+        Ok(Self(colors))
+    }
+
+    /// Loads a palette from a file.
+    ///
+    /// Files with the extensions `ase` and `aco` are parsed as swatch files, anything else as a hex list.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PaletteError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("ase") => Self::from_ase(&std::fs::read(path)?),
+            Some("aco") => Self::from_aco(&std::fs::read(path)?),
+            _ => Self::from_hex_list(&std::fs::read_to_string(path)?),
+        }
+    }
+
+    /// The palette's colors as evenly spaced color stops
+    pub fn color_stops(&self) -> Vec<ColorStop> {
+        self.0.iter().copied().map(ColorStop::auto).collect()
+    }
+
+    /// The palette's colors as evenly spaced angular color stops
+    pub fn angular_color_stops(&self) -> Vec<AngularColorStop> {
+        self.0.iter().copied().map(AngularColorStop::auto).collect()
+    }
+
+    /// A linear gradient with the palette's colors evenly spaced along the gradient line
+    pub fn linear_gradient(&self, angle: impl Into<Angle>) -> LinearGradient {
+        LinearGradient::new(angle, self.color_stops())
+    }
+
+    /// A radial gradient with the palette's colors evenly spaced from the center to the end shape
+    pub fn radial_gradient(
+        &self,
+        position: Position,
+        shape: RadialGradientShape,
+    ) -> RadialGradient {
+        RadialGradient::new(position, shape, self.color_stops())
+    }
+
+    /// A conic gradient with the palette's colors evenly spaced around the center
+    pub fn conic_gradient(&self) -> ConicGradient {
+        ConicGradient::new(self.angular_color_stops())
+    }
+
+    /// The palette as an sRGB image one texel high with a texel for each color, for [`GradientPalette`]
+    pub fn to_image(&self) -> Image {
+        let mut image = Image::new(
+            Extent3d {
+                width: self.0.len().max(1) as u32,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            if self.0.is_empty() {
+                vec![0; 4]
+            } else {
+                self.0
+                    .iter()
+                    .flat_map(|&color| Srgba::from(color).to_u8_array())
+                    .collect()
+            },
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        image.sampler = ImageSampler::nearest();
+        image
+    }
+}
+
+/// Quantizes the colors of a node's gradients to a palette, so they fit a strict retro palette
+/// without hand-authored stepped stops.
+///
+/// Each pixel of the node's [`BackgroundGradient`], [`SharedBackgroundGradient`], [`BorderGradient`] and
+/// [`GradientLut`] is replaced with the nearest palette color in sRGB space, keeping its alpha.
+/// Every texel of the image is a palette color and is compared for each pixel, so palettes should be small.
+/// Create the image from a [`Palette`] with [`Palette::to_image`].
+///
+/// With `dither`, the colors are offset by a 4x4 ordered dithering pattern in physical pixels before they're
+/// quantized, by up to the average distance between the colors of the palette.
+///
+/// The palette is only applied by the UI gradient pipeline, not by [`GradientMaterial`] or to CPU sampling.
+#[derive(Component, Clone, PartialEq, Debug, Reflect)]
+#[reflect(PartialEq)]
+pub struct GradientPalette {
+    /// An image with a palette color in each texel
+    pub image: Handle<Image>,
+    /// If true, the colors are dithered between the nearest palette colors
+    pub dither: bool,
+}
+
+impl GradientPalette {
+    /// Quantize to the colors of `image`, without dithering
+    pub fn new(image: Handle<Image>) -> Self {
+        Self {
+            image,
+            dither: false,
+        }
+    }
+
+    /// Sets whether the colors are dithered between the nearest palette colors
+    pub fn with_dither(mut self, dither: bool) -> Self {
+        self.dither = dither;
+        self
+    }
+}
+
+fn cmyk(c: f32, m: f32, y: f32, k: f32) -> Color {
+    Srgba::new(
+        (1. - c) * (1. - k),
+        (1. - m) * (1. - k),
+        (1. - y) * (1. - k),
+        1.,
+    )
+    .into()
+}
+
+/// Reads big-endian values from swatch data
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], PaletteError> {
+        if self.0.len() < len {
+            return Err(PaletteError::UnexpectedEof);
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn u16(&mut self) -> Result<u16, PaletteError> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, PaletteError> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn f32(&mut self) -> Result<f32, PaletteError> {
+        Ok(f32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+}
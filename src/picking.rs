@@ -0,0 +1,160 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        entity::Entity,
+        query::With,
+        system::{Query, Single},
+    },
+    picking::{Pickable, pointer::PointerLocation},
+    prelude::{DetectChanges, DetectChangesMut},
+    render::{camera::Camera, view::InheritedVisibility},
+    transform::components::GlobalTransform,
+    ui::{ComputedNode, ComputedNodeTarget},
+    window::PrimaryWindow,
+};
+
+/// Controls how a gradient node responds to picking.
+///
+/// With [`GradientPickingPolicy::AlphaThreshold`] the composited alpha of the node's
+/// [`BackgroundGradient`] and [`BorderGradient`] is evaluated on the CPU at the pointer's position,
+/// and areas that are too transparent don't count as hits.
+///
+/// This works by masking off the node's [`Pickable`] while the pointers are over transparent areas,
+/// before the picking backends run. The node's own `Pickable` settings are kept and restored, changes
+/// made to them while they're masked off are picked up the next frame.
+/// If more than one pointer is over the node, it's pickable if any of them is over an opaque area.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+#[require(Pickable, GradientPickingState)]
+pub enum GradientPickingPolicy {
+    /// The whole node counts as a hit, regardless of the gradient's alpha.
+    Rect,
+    /// Only areas where the alpha of the gradients is greater than the threshold count as hits.
+    AlphaThreshold(f32),
+}
+
+impl Default for GradientPickingPolicy {
+    fn default() -> Self {
+        Self::AlphaThreshold(0.)
+    }
+}
+
+/// The node's own [`Pickable`] settings, kept while a [`GradientPickingPolicy`] masks them off.
+/// Added automatically with the policy.
+#[derive(Component, Debug, Clone, Default, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct GradientPickingState {
+    /// The node's `Pickable` before masking
+    base: Option<Pickable>,
+}
+
+impl GradientPickingState {
+    /// The node's own `Pickable` settings, or `None` before the policy is first applied
+    pub fn base(&self) -> Option<&Pickable> {
+        self.base.as_ref()
+    }
+}
+
+/// Updates the [`Pickable`] of nodes with a [`GradientPickingPolicy`] from the alpha of their gradients under the pointers.
+#[expect(
+    clippy::type_complexity,
+    reason = "it's a system that needs a lot of components"
+)]
+pub fn update_gradient_picking(
+    pointers: Query<&PointerLocation>,
+    cameras: Query<&Camera>,
+    primary_window: Option<Single<Entity, With<PrimaryWindow>>>,
+    mut nodes: Query<(
+        &GradientPickingPolicy,
+        &mut GradientPickingState,
+        &mut Pickable,
+        &ComputedNode,
+        &ComputedNodeTarget,
+        &GlobalTransform,
+        &InheritedVisibility,
+        Option<&GradientGroupBounds>,
+        Option<&BackgroundGradient>,
+        Option<&SharedBackgroundGradient>,
+        Option<&BorderGradient>,
+    )>,
+) {
+    let primary_window = primary_window.map(|window| *window);
+    for (
+        policy,
+        mut state,
+        mut pickable,
+        node,
+        target,
+        transform,
+        visibility,
+        group,
+        background,
+        shared,
+        border,
+    ) in nodes.iter_mut()
+    {
+        // Changes made since this system last ran weren't made by it, keep them as the base
+        if pickable.is_changed() || state.base.is_none() {
+            state.base = Some(pickable.clone());
+        }
+        let base = state.base.clone().unwrap_or_default();
+
+        let GradientPickingPolicy::AlphaThreshold(threshold) = *policy else {
+            // Restore the base settings in case they were masked off
+            pickable.set_if_neq(base);
+            continue;
+        };
+
+        if !visibility.get() {
+            continue;
+        }
+
+        let Some(camera) = target.camera().and_then(|camera| cameras.get(camera).ok()) else {
+            continue;
+        };
+        let Some(camera_target) = camera.target.normalize(primary_window) else {
+            continue;
+        };
+
+        let inverse_transform = transform.affine().inverse();
+        let half_size = 0.5 * node.size();
+
+        let mut over_node = false;
+        let mut opaque = false;
+        for location in pointers
+            .iter()
+            .filter_map(|pointer| pointer.location())
+            .filter(|location| location.target == camera_target)
+        {
+            let mut position = location.position * camera.target_scaling_factor().unwrap_or(1.);
+            if let Some(viewport) = camera.physical_viewport_rect() {
+                position -= viewport.min.as_vec2();
+            }
+            // The pointer relative to the center of the node, undoing its rotation and scale
+            let point = inverse_transform
+                .transform_point3(position.extend(0.))
+                .truncate();
+            if half_size.x < point.x.abs() || half_size.y < point.y.abs() {
+                continue;
+            }
+            over_node = true;
+            if threshold
+                < sample_node(
+                    point, node, target, transform, group, background, shared, border,
+                )
+                .alpha
+            {
+                opaque = true;
+                break;
+            }
+        }
+
+        // Leave the node's settings unchanged when no pointers are over it
+        let pickable_state = !over_node || opaque;
+        let masked = Pickable {
+            should_block_lower: base.should_block_lower && pickable_state,
+            is_hoverable: base.is_hoverable && pickable_state,
+        };
+        pickable.set_if_neq(masked);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::*;
+use bevy::{
+    color::{ColorToComponents, Oklaba},
+    math::FloatExt,
+};
+
+/// The size of the node in logical pixels that the stops of a quantized gradient are resolved against
+pub const QUANTIZE_REFERENCE_SIZE: Vec2 = Vec2::splat(100.);
+
+/// The number of samples used to measure the perceptual distances along a gradient
+pub const QUANTIZE_PERCEPTUAL_SAMPLES: usize = 256;
+
+/// How the colors returned by [`Gradient::quantize_with`] are spaced along the gradient
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum QuantizeSpacing {
+    /// The colors are sampled at even distances between the first and last stops
+    #[default]
+    Even,
+    /// The colors are sampled at even perceptual distances (in Oklab space, including alpha) between the first
+    /// and last stops, so parts of the gradient with rapid color changes contribute more colors
+    Perceptual,
+}
+
+impl Gradient {
+    /// `n` representative colors of the gradient, sampled at even distances from its first stop to its last stop,
+    /// for reusing a UI gradient's palette in particles, text or sprites.
+    ///
+    /// The gradient's shape and spread are ignored, and its filters are applied to the colors.
+    /// Stops with lengths in pixels or viewport units are resolved against a node with a size of
+    /// [`QUANTIZE_REFERENCE_SIZE`].
+    ///
+    /// ```
+    /// # use bevy_ui_gradients::{ColorStop, LinearGradient, Gradient, Palette};
+    /// # use bevy::color::palettes::css::{RED, BLUE};
+    /// let gradient: Gradient = LinearGradient::to_right(vec![
+    ///     ColorStop::auto(RED),
+    ///     ColorStop::auto(BLUE),
+    /// ])
+    /// .into();
+    /// let palette = Palette(gradient.quantize(5));
+    /// assert_eq!(palette.0.len(), 5);
+    /// ```
+    pub fn quantize(&self, n: usize) -> Vec<Color> {
+        self.quantize_with(n, QuantizeSpacing::Even)
+    }
+
+    /// `n` representative colors of the gradient with the given spacing, see [`Gradient::quantize`]
+    pub fn quantize_with(&self, n: usize, spacing: QuantizeSpacing) -> Vec<Color> {
+        let gradient =
+            PhysicalGradient::new(self, 1., QUANTIZE_REFERENCE_SIZE, QUANTIZE_REFERENCE_SIZE);
+        let (Some(first), Some(last)) = (gradient.stops.first(), gradient.stops.last()) else {
+            return vec![];
+        };
+        let (start, end) = (first.1, last.1);
+        let color_at = |t: f32| {
+            let color = interpolate_stops(&gradient.stops, start.lerp(end, t));
+            match gradient.filter {
+                Some(filter) => filter.apply(color),
+                None => color,
+            }
+        };
+
+        // The fractions of the distance from the first stop to the last stop to sample at
+        let fractions = (0..n).map(|i| {
+            if n == 1 {
+                0.5
+            } else {
+                i as f32 / (n - 1) as f32
+            }
+        });
+
+        match spacing {
+            QuantizeSpacing::Even => fractions.map(|t| color_at(t).into()).collect(),
+            QuantizeSpacing::Perceptual => {
+                // The cumulative perceptual distance along the gradient at each sample
+                let last_sample = QUANTIZE_PERCEPTUAL_SAMPLES - 1;
+                let mut lengths = Vec::with_capacity(QUANTIZE_PERCEPTUAL_SAMPLES);
+                let mut previous = Oklaba::from(color_at(0.)).to_vec4();
+                let mut length = 0.;
+                for i in 0..=last_sample {
+                    let color = Oklaba::from(color_at(i as f32 / last_sample as f32)).to_vec4();
+                    length += color.distance(previous);
+                    lengths.push(length);
+                    previous = color;
+                }
+                if length <= 0. {
+                    return fractions.map(|t| color_at(t).into()).collect();
+                }
+
+                fractions
+                    .map(|t| {
+                        let target = t * length;
+                        let i = lengths
+                            .partition_point(|&l| l < target)
+                            .clamp(1, last_sample);
+                        let (a, b) = (lengths[i - 1], lengths[i]);
+                        let s = if a < b { (target - a) / (b - a) } else { 0. };
+                        color_at((i - 1) as f32 / last_sample as f32 + s / last_sample as f32)
+                            .into()
+                    })
+                    .collect()
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::sample::mix_linear_rgb_in_srgb_space;
+use crate::*;
+use bevy::{
+    color::{ColorToComponents, LinearRgba},
+    image::{Image, TextureAccessError},
+};
+
+/// A line of pixels through an image, sampled by [`LinearGradient::from_image_ramp`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageRamp {
+    /// A row of pixels from left to right, at a normalized distance from the top of the image
+    Row(f32),
+    /// A column of pixels from top to bottom, at a normalized distance from the left of the image
+    Column(f32),
+    /// The diagonal from the top-left corner to the bottom-right corner
+    Diagonal,
+    /// The diagonal from the bottom-left corner to the top-right corner
+    AntiDiagonal,
+}
+
+impl ImageRamp {
+    /// The direction of a linear gradient matching the ramp
+    pub fn angle(self) -> Angle {
+        match self {
+            ImageRamp::Row(_) => LinearGradient::TO_RIGHT,
+            ImageRamp::Column(_) => LinearGradient::TO_BOTTOM,
+            ImageRamp::Diagonal => LinearGradient::TO_BOTTOM_RIGHT,
+            ImageRamp::AntiDiagonal => LinearGradient::TO_TOP_RIGHT,
+        }
+    }
+
+    /// The pixel coordinates along the ramp, in order
+    fn pixels(self, width: u32, height: u32) -> Vec<(u32, u32)> {
+        let at = |t: f32, len: u32| (t * (len - 1) as f32).round() as u32;
+        let count = match self {
+            ImageRamp::Row(_) => width,
+            ImageRamp::Column(_) => height,
+            ImageRamp::Diagonal | ImageRamp::AntiDiagonal => width.max(height),
+        };
+        (0..count)
+            .map(|i| {
+                let t = i as f32 / (count - 1).max(1) as f32;
+                match self {
+                    ImageRamp::Row(y) => (at(t, width), at(y.clamp(0., 1.), height)),
+                    ImageRamp::Column(x) => (at(x.clamp(0., 1.), width), at(t, height)),
+                    ImageRamp::Diagonal => (at(t, width), at(t, height)),
+                    ImageRamp::AntiDiagonal => (at(t, width), at(1. - t, height)),
+                }
+            })
+            .collect()
+    }
+}
+
+impl LinearGradient {
+    /// Builds a linear gradient approximating the colors along a line of pixels in `image`,
+    /// for turning concept art color keys into UI gradients.
+    ///
+    /// Every pixel along the ramp is sampled, then stops are added at the pixels where the
+    /// gradient differs most from the image until there are `max_stops` stops, or the gradient
+    /// matches the ramp exactly.
+    pub fn from_image_ramp(
+        image: &Image,
+        ramp: ImageRamp,
+        max_stops: usize,
+    ) -> Result<Self, TextureAccessError> {
+        if image.width() == 0 || image.height() == 0 {
+            return Ok(LinearGradient::new(ramp.angle(), vec![]));
+        }
+
+        let samples = ramp
+            .pixels(image.width(), image.height())
+            .into_iter()
+            .map(|(x, y)| image.get_color_at(x, y).map(LinearRgba::from))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let last = samples.len() - 1;
+        let mut selected = vec![0, last];
+        selected.dedup();
+        while selected.len() < max_stops {
+            let Some((error, index)) = selected
+                .windows(2)
+                .flat_map(|window| {
+                    let (start, end) = (window[0], window[1]);
+                    let samples = &samples;
+                    (start + 1..end).map(move |i| {
+                        let t = (i - start) as f32 / (end - start) as f32;
+                        let approximation =
+                            mix_linear_rgb_in_srgb_space(samples[start], samples[end], t);
+                        let error =
+                            (samples[i].to_vec4() - approximation.to_vec4()).length_squared();
+                        (error, i)
+                    })
+                })
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+            else {
+                break;
+            };
+            if error <= f32::EPSILON {
+                break;
+            }
+            let position = selected.partition_point(|&i| i < index);
+            selected.insert(position, index);
+        }
+
+        let stops = selected.into_iter().map(|i| {
+            let point = if last == 0 {
+                0.
+            } else {
+                100. * i as f32 / last as f32
+            };
+            ColorStop::new(samples[i], Val::Percent(point))
+        });
+        Ok(LinearGradient::new(ramp.angle(), stops))
+    }
+}
@@ -1,3 +1,8 @@
+//! The gradient render pipeline and the extracted and prepared gradient data it draws.
+//!
+//! This module is only public with the `render-internals` feature, for custom render phases and
+//! post-process effects that need the resolved gradients. It has no stability guarantees.
+
 use core::{
     f32::consts::{FRAC_PI_2, TAU},
     hash::Hash,
@@ -8,11 +13,12 @@ use crate::*;
 use bevy::{
     app::App,
     asset::*,
-    color::{ColorToComponents, LinearRgba},
+    color::{ColorToComponents, LinearRgba, Mix},
     ecs::{
         component::Component,
         entity::Entity,
-        query::{AnyOf, With},
+        hierarchy::ChildOf,
+        query::{AnyOf, Or, With},
         resource::Resource,
         schedule::IntoScheduleConfigs,
         system::{
@@ -22,24 +28,35 @@ use bevy::{
         world::{FromWorld, World},
     },
     image::BevyDefault,
+    image::Image,
+    log::info_span,
     math::{
-        FloatOrd, Mat4, Rect, Vec2, Vec3, Vec3Swizzles, Vec4Swizzles,
+        FloatOrd, Mat4, Rect, UVec2, Vec2, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles,
         ops::{cos, sin},
     },
+    platform::collections::{HashMap, HashSet},
     render::{
         Extract, ExtractSchedule, Render, RenderApp, RenderSet,
+        globals::{GlobalsBuffer, GlobalsUniform},
+        render_asset::RenderAssets,
         render_phase::*,
-        render_resource::{binding_types::uniform_buffer, *},
+        render_resource::{
+            binding_types::{sampler, texture_2d, uniform_buffer},
+            *,
+        },
         renderer::{RenderDevice, RenderQueue},
         sync_world::{MainEntity, TemporaryRenderEntity},
+        texture::GpuImage,
         view::*,
     },
-    sprite::BorderRect,
+    sprite::{BorderRect, SpriteAssetEvents},
     transform::prelude::GlobalTransform,
     ui::{
-        CalculatedClip, ComputedNode, ComputedNodeTarget, ExtractedUiItem, ExtractedUiNode,
+        BackgroundColor, CalculatedClip, ComputedNode, ComputedNodeTarget, DrawUi, ExtractedUiItem,
         ExtractedUiNodes, NodeType, RenderUiSystem, ResolvedBorderRadius, TransparentUi,
-        UiAntiAlias, UiCameraMap, UiCameraView, extract_uinode_background_colors, shader_flags,
+        UiAntiAlias, UiCameraMap, UiCameraView, extract_uinode_background_colors,
+        extract_uinode_borders, extract_uinode_images, queue_uinodes, shader_flags,
+        widget::ImageNode,
     },
 };
 use bytemuck::{Pod, Zeroable};
@@ -49,6 +66,20 @@ pub mod gradient_shader_flags {
     pub const FILL_START: u32 = 32;
     pub const FILL_END: u32 = 64;
     pub const CONIC: u32 = 128;
+    pub const MASK: u32 = 256;
+    pub const SPREAD_REPEAT: u32 = 512;
+    pub const SPREAD_REFLECT: u32 = 1024;
+    pub const TILED: u32 = 2048;
+    pub const FILTER: u32 = 4096;
+    pub const CUSTOM_FUNCTION: u32 = 8192;
+    pub const LUT: u32 = 16384;
+    pub const INSET: u32 = 32768;
+    pub const PLASMA: u32 = 65536;
+    pub const GLOW: u32 = 131072;
+    pub const OSCILLATE: u32 = 262144;
+    pub const PALETTE: u32 = 524288;
+    /// The id passed to the custom gradient function is stored in the high 12 bits of the flags
+    pub const CUSTOM_FUNCTION_ID_SHIFT: u32 = 20;
 }
 
 pub(crate) const QUAD_VERTEX_POSITIONS: [Vec3; 4] = [
@@ -58,12 +89,51 @@ pub(crate) const QUAD_VERTEX_POSITIONS: [Vec3; 4] = [
     Vec3::new(-0.5, 0.5, 0.0),
 ];
 
-pub(crate) const QUAD_INDICES: [usize; 6] = [0, 2, 3, 0, 1, 2];
-
+/// The gradient shader, `gradient.wgsl`
 pub const UI_GRADIENT_SHADER_HANDLE: Handle<Shader> =
     weak_handle!("10116113-aac4-47fa-91c8-35cbe80dddcb");
 
-pub const GRADIENT_Z_OFFSET: f32 = 0.1;
+// Bevy draws a node's background color, border, image and text at `stack_z_offsets::NODE`, and the next
+// node in the stack starts at the following stack index. The gradient draws are sorted between the node's
+// box shadow and its border and text, with its background color and image beneath its gradients.
+// Stack indices follow bevy's UI stack, so gradients are ordered by `ZIndex`, `GlobalZIndex` and the
+// node hierarchy exactly like the nodes' backgrounds.
+
+/// Glows are drawn over the node's box shadow, behind its background color.
+pub const GLOW_Z_OFFSET: f32 = -0.08;
+
+/// The background colors of nodes with a [`BackgroundGradient`] are drawn by the gradient pipeline instead of
+/// bevy's, so they can be sorted beneath the node's gradients.
+pub const BACKGROUND_COLOR_Z_OFFSET: f32 = -0.06;
+
+/// The images of nodes with a [`BackgroundGradient`] are moved beneath the node's gradients.
+/// Sliced and tiled images are drawn by bevy's texture slice pipeline and aren't moved.
+pub const IMAGE_Z_OFFSET: f32 = -0.05;
+
+/// Gradients baked into lookup textures are drawn beneath the node's other gradients.
+pub const LUT_Z_OFFSET: f32 = -0.04;
+
+/// Background gradients are drawn over the node's background color and image, beneath its border and text.
+pub const GRADIENT_Z_OFFSET: f32 = -0.03;
+
+/// The border colors of nodes with a [`BorderGradient`] are drawn by the gradient pipeline instead of bevy's,
+/// so they can be sorted beneath the node's border gradients.
+pub const BORDER_COLOR_Z_OFFSET: f32 = -0.025;
+
+/// Border gradients are drawn over the node's background gradients and border color.
+pub const BORDER_GRADIENT_Z_OFFSET: f32 = -0.02;
+
+/// Masked background colors replace the node's background color.
+pub const MASK_BACKGROUND_Z_OFFSET: f32 = BACKGROUND_COLOR_Z_OFFSET;
+
+/// Masked images replace the node's image.
+pub const MASK_IMAGE_Z_OFFSET: f32 = IMAGE_Z_OFFSET;
+
+/// Strokes are drawn over the node's gradients, border, image and text, beneath its material.
+pub const STROKE_Z_OFFSET: f32 = 0.15;
+
+/// The maximum distance in physical pixels between an arc and the lines it's drawn with.
+pub const STROKE_ARC_TOLERANCE: f32 = 0.25;
 
 pub fn build_gradients_renderer(app: &mut App) {
     load_internal_asset!(
@@ -79,17 +149,40 @@ pub fn build_gradients_renderer(app: &mut App) {
             .init_resource::<ExtractedGradients>()
             .init_resource::<ExtractedColorStops>()
             .init_resource::<GradientMeta>()
+            .init_resource::<GradientImageBindGroups>()
             .init_resource::<SpecializedRenderPipelines<GradientPipeline>>()
             .add_systems(
                 ExtractSchedule,
-                extract_gradients
-                    .in_set(RenderUiSystem::ExtractBackgrounds)
-                    .after(extract_uinode_background_colors),
+                (
+                    (
+                        extract_gradient_quality,
+                        extract_gradient_masks,
+                        extract_gradient_background_colors,
+                        extract_gradients,
+                        extract_gradient_luts,
+                        extract_gradient_strokes,
+                        extract_gradient_glows,
+                    )
+                        .chain()
+                        .in_set(RenderUiSystem::ExtractBackgrounds)
+                        .after(extract_uinode_background_colors),
+                    remove_masked_images
+                        .in_set(RenderUiSystem::ExtractImages)
+                        .after(extract_uinode_images)
+                        .after(extract_gradient_masks),
+                    (extract_gradient_border_colors, extract_gradient_opacity)
+                        .chain()
+                        .in_set(RenderUiSystem::ExtractBorders)
+                        .after(extract_uinode_borders),
+                ),
             )
             .add_systems(
                 Render,
                 (
                     queue_gradient.in_set(RenderSet::Queue),
+                    queue_gradient_node_images
+                        .in_set(RenderSet::Queue)
+                        .after(queue_uinodes),
                     prepare_gradient.in_set(RenderSet::PrepareBindGroups),
                 ),
             );
@@ -97,21 +190,62 @@ pub fn build_gradients_renderer(app: &mut App) {
 }
 
 pub fn finish_gradients_renderer(app: &mut App) {
+    let shader_override = app
+        .world()
+        .get_resource::<GradientShaderOverride>()
+        .cloned();
     if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+        if let Some(shader_override) = shader_override {
+            render_app.insert_resource(shader_override);
+        }
         render_app.init_resource::<GradientPipeline>();
     }
 }
 
+/// Overrides the shader of the UI gradient pipeline, for driver-specific workarounds and custom effects
+/// without forking the crate.
+///
+/// Insert it before the app runs, the pipeline reads it once when it's created.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ui_gradients::*;
+/// let mut app = App::new();
+/// app.insert_resource(GradientShaderOverride {
+///     shader: None,
+///     shader_defs: vec!["NO_FMA_WORKAROUND".into()],
+/// });
+/// ```
+#[derive(Resource, Clone, Debug, Default)]
+pub struct GradientShaderOverride {
+    /// Replaces [`UI_GRADIENT_SHADER_HANDLE`]. The shader must have the `vertex` and `fragment` entry points,
+    /// vertex layout and bind groups of `gradient.wgsl`, and should handle the shader defs set for each
+    /// [`UiGradientPipelineKey`].
+    pub shader: Option<Handle<Shader>>,
+    /// Shader defs added to every specialization of the pipeline
+    pub shader_defs: Vec<ShaderDefVal>,
+}
+
 #[derive(Component)]
 pub struct GradientBatch {
     pub range: Range<u32>,
+    pub image: AssetId<Image>,
+    /// `AssetId::default()` if there's no palette
+    pub palette: AssetId<Image>,
 }
 
+/// The bind groups of the images and palettes of the gradients
+#[derive(Resource, Default)]
+pub struct GradientImageBindGroups {
+    pub values: HashMap<(AssetId<Image>, AssetId<Image>), BindGroup>,
+}
+
+/// The vertex and index buffers of the prepared gradients
 #[derive(Resource)]
 pub struct GradientMeta {
-    vertices: RawBufferVec<UiGradientVertex>,
-    indices: RawBufferVec<u32>,
-    view_bind_group: Option<BindGroup>,
+    pub vertices: RawBufferVec<UiGradientVertex>,
+    pub indices: RawBufferVec<u32>,
+    pub view_bind_group: Option<BindGroup>,
 }
 
 impl Default for GradientMeta {
@@ -127,6 +261,13 @@ impl Default for GradientMeta {
 #[derive(Resource)]
 pub struct GradientPipeline {
     pub view_layout: BindGroupLayout,
+    pub image_layout: BindGroupLayout,
+    /// True if a custom gradient function is registered with [`CustomGradientFunctionPlugin`]
+    pub custom_function: bool,
+    /// The shader, [`UI_GRADIENT_SHADER_HANDLE`] unless it's replaced by a [`GradientShaderOverride`]
+    pub shader: Handle<Shader>,
+    /// Shader defs added by a [`GradientShaderOverride`]
+    pub shader_defs: Vec<ShaderDefVal>,
 }
 
 impl FromWorld for GradientPipeline {
@@ -135,13 +276,40 @@ impl FromWorld for GradientPipeline {
 
         let view_layout = render_device.create_bind_group_layout(
             "ui_gradient_view_layout",
-            &BindGroupLayoutEntries::single(
+            &BindGroupLayoutEntries::sequential(
                 ShaderStages::VERTEX_FRAGMENT,
-                uniform_buffer::<ViewUniform>(true),
+                (
+                    uniform_buffer::<ViewUniform>(true),
+                    uniform_buffer::<GlobalsUniform>(false),
+                ),
+            ),
+        );
+
+        let image_layout = render_device.create_bind_group_layout(
+            "ui_gradient_image_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                    // The palette of a `GradientPalette`
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                ),
             ),
         );
 
-        GradientPipeline { view_layout }
+        let shader_override = world
+            .get_resource::<GradientShaderOverride>()
+            .cloned()
+            .unwrap_or_default();
+
+        GradientPipeline {
+            view_layout,
+            image_layout,
+            custom_function: world.contains_resource::<CustomGradientFunction>(),
+            shader: shader_override.shader.unwrap_or(UI_GRADIENT_SHADER_HANDLE),
+            shader_defs: shader_override.shader_defs,
+        }
     }
 }
 
@@ -165,10 +333,49 @@ pub fn compute_gradient_line_length(angle: f32, size: Vec2) -> f32 {
     (t_pos - t_neg).abs()
 }
 
-#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+/// The point where a linear gradient's line starts, relative to the center of the node.
+/// This is the corner of the node opposite the gradient's direction.
+pub fn compute_linear_gradient_start(angle: f32, size: Vec2) -> Vec2 {
+    let corner_index = (angle - FRAC_PI_2).rem_euclid(TAU) / FRAC_PI_2;
+    QUAD_VERTEX_POSITIONS[corner_index as usize].xy() * size
+}
+
+/// Resolves the start, direction and length of a linear gradient's line for a node with the given physical size.
+pub fn resolve_linear_gradient_line(
+    gradient: &LinearGradient,
+    scale_factor: f32,
+    physical_size: Vec2,
+    physical_target_size: Vec2,
+) -> (Vec2, Vec2, f32) {
+    if let Some(GradientEndpoints { start, end }) = gradient.endpoints {
+        let start = start.resolve(scale_factor, physical_size, physical_target_size);
+        let end = end.resolve(scale_factor, physical_size, physical_target_size);
+        let line = end - start;
+        (start, line.normalize_or_zero(), line.length())
+    } else {
+        let angle = gradient.angle.to_radians();
+        (
+            compute_linear_gradient_start(angle, physical_size),
+            // CSS angles increase in a clockwise direction
+            Vec2::new(sin(angle), -cos(angle)),
+            compute_gradient_line_length(angle, physical_size),
+        )
+    }
+}
+
+/// The specialization key of the UI gradient pipeline
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
 pub struct UiGradientPipelineKey {
-    anti_alias: bool,
+    /// If true, the edges of nodes, rings and glows are anti-aliased. Follows the camera's `UiAntiAlias`,
+    /// and sets the `ANTI_ALIAS` shader def.
+    pub anti_alias: bool,
+    /// If true, the pipeline draws to an HDR target
     pub hdr: bool,
+    /// The blend mode of the gradients. Sets the `BLEND_MULTIPLY` or `BLEND_SCREEN` shader defs.
+    pub blend_mode: GradientBlendMode,
+    /// The quality tier. [`GradientQuality::Medium`] sets the `LINEAR_COLOR_MIX` shader def, and
+    /// [`GradientQuality::Low`] sets `LINEAR_COLOR_MIX`, `NO_HINTS`, `NO_GRAIN` and `VERTEX_COLORS`.
+    pub quality: GradientQuality,
 }
 
 impl SpecializedRenderPipeline for GradientPipeline {
@@ -178,51 +385,78 @@ impl SpecializedRenderPipeline for GradientPipeline {
         let vertex_layout = VertexBufferLayout::from_vertex_formats(
             VertexStepMode::Vertex,
             vec![
-                // position
-                VertexFormat::Float32x3,
-                // uv
-                VertexFormat::Float32x2,
-                // flags
-                VertexFormat::Uint32,
+                // position, conic outer radius
+                VertexFormat::Float32x4,
+                // uv, conic sweep, radial falloff or conic inner radius
+                VertexFormat::Float32x4,
+                // flags, texture modulation amount
+                VertexFormat::Uint32x2,
                 // radius
                 VertexFormat::Float32x4,
                 // border
                 VertexFormat::Float32x4,
-                // size
-                VertexFormat::Float32x2,
-                // point
-                VertexFormat::Float32x2,
-                // start_point
-                VertexFormat::Float32x2,
-                // dir
-                VertexFormat::Float32x2,
+                // size, tile size
+                VertexFormat::Float32x4,
+                // point, point relative to the first tile
+                VertexFormat::Float32x4,
+                // gradient line: start point, dir
+                VertexFormat::Float32x4,
                 // start_color
                 VertexFormat::Float32x4,
-                // start_len
-                VertexFormat::Float32,
-                // end_len
-                VertexFormat::Float32,
+                // segment: start_len, end_len, hint, interpolation
+                VertexFormat::Float32x4,
                 // end color
                 VertexFormat::Float32x4,
-                // hint
-                VertexFormat::Float32,
+                // mask color or stop oscillations
+                VertexFormat::Uint32x4,
+                // animation
+                VertexFormat::Float32x4,
+                // grain, interpolation parameter
+                VertexFormat::Float32x4,
+                // spread period: first and last stop distances, tile period
+                VertexFormat::Float32x4,
+                // filter
+                VertexFormat::Float32x4,
             ],
         );
-        let shader_defs = if key.anti_alias {
-            vec!["ANTI_ALIAS".into()]
-        } else {
-            Vec::new()
-        };
+        let mut shader_defs = self.shader_defs.clone();
+        if key.anti_alias {
+            shader_defs.push("ANTI_ALIAS".into());
+        }
+        if self.custom_function {
+            shader_defs.push("CUSTOM_GRADIENT_FUNCTION".into());
+        }
+        match key.quality {
+            GradientQuality::Low => shader_defs.extend([
+                "LINEAR_COLOR_MIX".into(),
+                "NO_HINTS".into(),
+                "NO_GRAIN".into(),
+                "VERTEX_COLORS".into(),
+            ]),
+            GradientQuality::Medium => shader_defs.push("LINEAR_COLOR_MIX".into()),
+            GradientQuality::High => {}
+        }
+        if cfg!(feature = "deterministic") {
+            shader_defs.push("DETERMINISTIC".into());
+        }
+        match key.blend_mode {
+            GradientBlendMode::Multiply => shader_defs.push("BLEND_MULTIPLY".into()),
+            GradientBlendMode::Screen => shader_defs.push("BLEND_SCREEN".into()),
+            GradientBlendMode::Normal | GradientBlendMode::Add => {}
+        }
+        // WebGL2 requires uniform structs to be 16 byte aligned
+        #[cfg(target_arch = "wasm32")]
+        shader_defs.push("SIXTEEN_BYTE_ALIGNMENT".into());
 
         RenderPipelineDescriptor {
             vertex: VertexState {
-                shader: UI_GRADIENT_SHADER_HANDLE,
+                shader: self.shader.clone(),
                 entry_point: "vertex".into(),
                 shader_defs: shader_defs.clone(),
                 buffers: vec![vertex_layout],
             },
             fragment: Some(FragmentState {
-                shader: UI_GRADIENT_SHADER_HANDLE,
+                shader: self.shader.clone(),
                 shader_defs,
                 entry_point: "fragment".into(),
                 targets: vec![Some(ColorTargetState {
@@ -231,11 +465,11 @@ impl SpecializedRenderPipeline for GradientPipeline {
                     } else {
                         TextureFormat::bevy_default()
                     },
-                    blend: Some(BlendState::ALPHA_BLENDING),
+                    blend: Some(blend_state(key.blend_mode)),
                     write_mask: ColorWrites::ALL,
                 })],
             }),
-            layout: vec![self.view_layout.clone()],
+            layout: vec![self.view_layout.clone(), self.image_layout.clone()],
             push_constant_ranges: Vec::new(),
             primitive: PrimitiveState {
                 front_face: FrontFace::Ccw,
@@ -258,14 +492,111 @@ impl SpecializedRenderPipeline for GradientPipeline {
     }
 }
 
+/// The blend state for a blend mode. The fragment shader outputs colors multiplied by their alpha for screen,
+/// and colors mixed with white by their alpha for multiply.
+fn blend_state(blend_mode: GradientBlendMode) -> BlendState {
+    let over_alpha = BlendComponent {
+        src_factor: BlendFactor::One,
+        dst_factor: BlendFactor::OneMinusSrcAlpha,
+        operation: BlendOperation::Add,
+    };
+    match blend_mode {
+        GradientBlendMode::Normal => BlendState::ALPHA_BLENDING,
+        GradientBlendMode::Multiply => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::Zero,
+                dst_factor: BlendFactor::Src,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::Zero,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+        },
+        GradientBlendMode::Screen => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::OneMinusSrc,
+                operation: BlendOperation::Add,
+            },
+            alpha: over_alpha,
+        },
+        GradientBlendMode::Add => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+            alpha: over_alpha,
+        },
+    }
+}
+
+/// The geometry of a gradient in physical pixels, relative to the center of its node
+#[derive(Clone, Copy)]
 pub enum ResolvedGradient {
-    Linear { angle: f32 },
-    Conic { center: Vec2, start: f32 },
-    Radial { center: Vec2, size: Vec2 },
+    Linear {
+        /// The start of the gradient line
+        start: Vec2,
+        /// The direction of the gradient line
+        dir: Vec2,
+    },
+    Conic {
+        center: Vec2,
+        start: f32,
+        aspect_ratio: f32,
+        /// The angle the full turn of the stops is mapped onto, negative counter-clockwise
+        sweep: f32,
+        /// The gradient is only drawn between the inner and outer radii, an outer radius of zero is unbounded
+        inner_radius: f32,
+        outer_radius: f32,
+    },
+    Radial {
+        center: Vec2,
+        size: Vec2,
+        /// The exponent of the falloff from the center
+        falloff: f32,
+    },
+    /// A gradient measured inward from the edges of a box
+    Inset {
+        /// The center of the box
+        center: Vec2,
+        size: Vec2,
+    },
+    /// An animated plasma
+    Plasma {
+        /// The wavelength of the plasma's waves
+        scale: f32,
+        speed: f32,
+    },
+    /// A gradient measured outward from the border of a node, drawn on a quad expanded by `spread`
+    Glow {
+        /// How far the quad extends beyond the node's border
+        spread: f32,
+    },
+    /// A gradient drawn from a lookup texture of positions along its gradient line
+    Lut {
+        image: AssetId<Image>,
+        /// The length of the gradient line
+        length: f32,
+    },
+}
+
+/// The background color or image of a node with a [`GradientMask`]
+pub struct GradientMaskLayer {
+    /// `AssetId::default()` for background colors
+    pub image: AssetId<Image>,
+    pub color: LinearRgba,
+    pub flip_x: bool,
+    pub flip_y: bool,
 }
 
+/// A gradient layer extracted from a UI node, with its color stops in [`ExtractedColorStops`]
 pub struct ExtractedGradient {
     pub stack_index: u32,
+    /// Offset added to the stack index to give the sort key
+    pub z_offset: f32,
     pub transform: Mat4,
     pub rect: Rect,
     pub clip: Option<Rect>,
@@ -282,20 +613,53 @@ pub struct ExtractedGradient {
     /// Ordering: left, top, right, bottom.
     pub border: BorderRect,
     pub resolved_gradient: ResolvedGradient,
+    /// If set, the gradient's alpha masks this layer instead of the gradient being drawn
+    pub mask: Option<GradientMaskLayer>,
+    /// The image of the node's [`GradientPalette`] and whether it's dithered
+    pub palette: Option<(AssetId<Image>, bool)>,
+    /// The gradient's [`GradientTexture`], with its displacement and tile size in physical pixels
+    pub texture: Option<(AssetId<Image>, TextureModulation, Option<Vec2>)>,
+    /// Animation parameters: angular velocity, scroll velocity in physical pixels (radians for conic gradients),
+    /// pulse amplitude and pulse frequency. Zero if not animated.
+    pub animation: Vec4,
+    /// Film grain parameters: intensity, scale in physical pixels, and `1.` if animated, `4.` for a 4x4 Bayer pattern
+    /// or `8.` for an 8x8 Bayer pattern. Zero if there's no grain.
+    pub grain: Vec3,
+    pub spread: SpreadMethod,
+    pub tiling: Option<PhysicalTiling>,
+    pub filter: Option<GradientFilter>,
+    /// The id passed to the custom gradient function, if it's used
+    pub custom_function: Option<u32>,
+    pub blend_mode: GradientBlendMode,
 }
 
 #[derive(Resource, Default)]
 pub struct ExtractedGradients {
     pub items: Vec<ExtractedGradient>,
+    /// The nodes whose images are sorted beneath their gradients
+    pub image_nodes: HashSet<MainEntity>,
+    /// The nodes whose images are drawn masked by the gradient pipeline instead of by bevy
+    pub masked_images: HashSet<MainEntity>,
+    /// The quality the gradients are drawn at
+    pub quality: GradientQuality,
 }
 
+/// The resolved color stops of the extracted gradients: color, position, hint and the interpolation to the following stop.
+/// Positions are in physical pixels along the gradient line, or radians for conic gradients.
 #[derive(Resource, Default)]
-pub struct ExtractedColorStops(pub Vec<(LinearRgba, f32, f32)>);
+pub struct ExtractedColorStops(pub Vec<(LinearRgba, f32, f32, Interpolation, StopOscillation)>);
 
 // Interpolate implicit stops (where position is `f32::NAN`)
 // If the first and last stops are implicit set them to the `min` and `max` values
 // so that we always have explicit start and end points to interpolate between.
-fn interpolate_color_stops(stops: &mut [(LinearRgba, f32, f32)], min: f32, max: f32) {
+fn interpolate_color_stops(
+    stops: &mut [(LinearRgba, f32, f32, Interpolation, StopOscillation)],
+    min: f32,
+    max: f32,
+) {
+    if stops.is_empty() {
+        return;
+    }
     if stops[0].1.is_nan() {
         stops[0].1 = min;
     }
@@ -326,57 +690,626 @@ fn interpolate_color_stops(stops: &mut [(LinearRgba, f32, f32)], min: f32, max:
     }
 }
 
-fn compute_color_stops(
+// Sorts the explicit stops by position, with a stable insertion sort.
+// Implicit stops (where position is `f32::NAN`) keep their places in the list.
+fn sort_explicit_color_stops(
+    stops: &mut [(LinearRgba, f32, f32, f32, Interpolation, StopOscillation)],
+) {
+    for i in 0..stops.len() {
+        if stops[i].1.is_nan() {
+            continue;
+        }
+        let mut j = i;
+        while let Some(k) = (0..j).rev().find(|&k| !stops[k].1.is_nan()) {
+            if stops[k].1 <= stops[j].1 {
+                break;
+            }
+            stops.swap(j, k);
+            j = k;
+        }
+    }
+}
+
+// Sorts `scratch`, then moves it into `extracted_color_stops` and interpolates its implicit stops.
+// Implicit stops at the ends are placed at `min` and `max`, or at the first and last explicit stops if they're outside of that range.
+// The fourth field of each stop is the position of its hint, which is normalized between the stop and the following stop.
+// The fifth is the interpolation to the following stop.
+fn resolve_color_stops(
+    scratch: &mut Vec<(LinearRgba, f32, f32, f32, Interpolation, StopOscillation)>,
+    extracted_color_stops: &mut Vec<(LinearRgba, f32, f32, Interpolation, StopOscillation)>,
+    min: f32,
+    max: f32,
+) {
+    let _span = info_span!("resolve_color_stops", stops = scratch.len()).entered();
+    sort_explicit_color_stops(scratch);
+
+    let mut explicit_points = scratch
+        .iter()
+        .map(|(_, point, ..)| *point)
+        .filter(|point| !point.is_nan());
+    let first = explicit_points.next();
+    let last = explicit_points.next_back().or(first);
+    let min = first.unwrap_or(min).min(min);
+    let max = last.unwrap_or(max).max(max);
+
+    let range_start = extracted_color_stops.len();
+    extracted_color_stops.extend(scratch.iter().map(
+        |&(color, point, hint, _, interpolation, oscillation)| {
+            (color, point, hint, interpolation, oscillation)
+        },
+    ));
+    let stops = &mut extracted_color_stops[range_start..];
+    interpolate_color_stops(stops, min, max);
+
+    for (i, (_, _, _, hint_point, ..)) in scratch.drain(..).enumerate() {
+        if hint_point.is_nan() {
+            continue;
+        }
+        if let Some(&(_, end, ..)) = stops.get(i + 1) {
+            let start = stops[i].1;
+            stops[i].2 = if start < end {
+                ((hint_point - start) / (end - start)).clamp(0., 1.)
+            } else {
+                0.5
+            };
+        }
+    }
+}
+
+/// Resolves `stops` along a gradient line of `length` physical pixels into `extracted_color_stops`.
+/// Stops with two positions are expanded into pairs, explicit stops are sorted by position,
+/// implicit positions are interpolated and hint positions are normalized.
+/// Positions outside of the gradient line are kept, so the colors at its ends are interpolated like CSS.
+/// `scratch` is working memory, it must be empty and is left empty.
+pub fn compute_color_stops(
     stops: &[ColorStop],
     scale_factor: f32,
     length: f32,
     target_size: Vec2,
-    scratch: &mut Vec<(LinearRgba, f32, f32)>,
-    extracted_color_stops: &mut Vec<(LinearRgba, f32, f32)>,
+    scratch: &mut Vec<(LinearRgba, f32, f32, f32, Interpolation, StopOscillation)>,
+    extracted_color_stops: &mut Vec<(LinearRgba, f32, f32, Interpolation, StopOscillation)>,
 ) {
-    // resolve the physical distances of explicit stops and sort them
-    scratch.extend(stops.iter().filter_map(|stop| {
-        scale_val(stop.point, scale_factor)
+    // resolve the physical distances of the stops and their hints, unresolved stops are implicit
+    let resolve = |point: Val| {
+        scale_val(point, scale_factor)
             .resolve(length, target_size)
-            .ok()
-            .map(|physical_point| (stop.color.to_linear(), physical_point, stop.hint))
-    }));
-    scratch.sort_by_key(|(_, point, _)| FloatOrd(*point));
+            .unwrap_or(f32::NAN)
+    };
+    scratch.extend(
+        stops
+            .iter()
+            .copied()
+            .flat_map(ColorStop::expand)
+            .map(|stop| {
+                (
+                    stop.color.to_linear(),
+                    resolve(stop.point),
+                    stop.hint,
+                    stop.hint_point.map(resolve).unwrap_or(f32::NAN),
+                    stop.interpolation,
+                    stop.oscillation
+                        .map(|oscillation| StopOscillation {
+                            position: oscillation.position * scale_factor,
+                            ..oscillation
+                        })
+                        .unwrap_or_default(),
+                )
+            }),
+    );
 
-    let min = scratch
-        .first()
-        .map(|(_, min, _)| *min)
-        .unwrap_or(0.)
-        .min(0.);
+    resolve_color_stops(scratch, extracted_color_stops, 0., length);
+}
 
-    // get the position of the last explicit stop and use the full length of the gradient if no explicit stops
-    let max = scratch
-        .last()
-        .map(|(_, max, _)| *max)
-        .unwrap_or(length)
-        .max(length);
+/// Resolves the angular `stops` of a conic gradient into `extracted_color_stops`.
+/// Explicit stops are sorted by angle and implicit positions are interpolated.
+/// Angles outside of the full turn are kept, so the colors at its ends are interpolated like CSS.
+/// `scratch` is working memory, it must be empty and is left empty.
+pub fn compute_angular_color_stops(
+    stops: &[AngularColorStop],
+    scratch: &mut Vec<(LinearRgba, f32, f32, f32, Interpolation, StopOscillation)>,
+    extracted_color_stops: &mut Vec<(LinearRgba, f32, f32, Interpolation, StopOscillation)>,
+) {
+    scratch.extend(stops.iter().map(|stop| {
+        let angle = stop.angle.resolve().unwrap_or(f32::NAN);
+        (
+            stop.color.to_linear(),
+            angle,
+            stop.hint,
+            f32::NAN,
+            stop.interpolation,
+            stop.oscillation.unwrap_or_default(),
+        )
+    }));
 
-    let mut sorted_stops_drain = scratch.drain(..);
+    resolve_color_stops(scratch, extracted_color_stops, 0., TAU);
+}
 
+/// Resolves the stops of `gradient` for the node into `extracted_color_stops`
+/// and returns its resolved geometry and tiling.
+pub fn resolve_gradient(
+    gradient: &Gradient,
+    uinode: &ComputedNode,
+    target: &ComputedNodeTarget,
+    transform: &GlobalTransform,
+    group: Option<&GradientGroupBounds>,
+    scratch: &mut Vec<(LinearRgba, f32, f32, f32, Interpolation, StopOscillation)>,
+    extracted_color_stops: &mut Vec<(LinearRgba, f32, f32, Interpolation, StopOscillation)>,
+) -> (ResolvedGradient, Option<PhysicalTiling>) {
     let range_start = extracted_color_stops.len();
+    // The gradient's geometry is resolved against its origin box or the viewport,
+    // or the size of its tiles if it's tiled
+    let (box_size, box_offset) = gradient.resolve_box(
+        uinode,
+        transform.translation().truncate(),
+        group,
+        target.physical_size().as_vec2(),
+    );
+    let tiling = gradient.layer().tiling.map(|tiling| {
+        tiling.resolve(
+            box_size,
+            box_offset,
+            target.scale_factor(),
+            target.physical_size().as_vec2(),
+        )
+    });
+    let (size, offset) = match tiling {
+        Some(tiling) => (tiling.size, Vec2::ZERO),
+        None => (box_size, box_offset),
+    };
+    let resolved_gradient = match gradient {
+        Gradient::Linear(gradient) => {
+            let (start, dir, length) = resolve_linear_gradient_line(
+                gradient,
+                target.scale_factor(),
+                size,
+                target.physical_size().as_vec2(),
+            );
 
-    // Fill the extracted color stops buffer
-    extracted_color_stops.extend(stops.iter().map(|stop| {
-        if stop.point == Val::Auto {
-            (stop.color.to_linear(), f32::NAN, stop.hint)
-        } else {
-            sorted_stops_drain.next().unwrap()
+            compute_color_stops(
+                &gradient.stops,
+                target.scale_factor(),
+                length,
+                target.physical_size().as_vec2(),
+                scratch,
+                extracted_color_stops,
+            );
+
+            ResolvedGradient::Linear {
+                start: start + offset,
+                dir,
+            }
         }
-    }));
+        Gradient::Radial(RadialGradient {
+            position: center,
+            shape,
+            falloff,
+            stops,
+            ..
+        }) => {
+            let c = center.resolve(
+                target.scale_factor(),
+                size,
+                target.physical_size().as_vec2(),
+            );
+
+            let shape_size = shape.resolve(
+                c,
+                target.scale_factor(),
+                size,
+                target.physical_size().as_vec2(),
+            );
+
+            let length = shape_size.x;
+
+            compute_color_stops(
+                stops,
+                target.scale_factor(),
+                length,
+                target.physical_size().as_vec2(),
+                scratch,
+                extracted_color_stops,
+            );
+
+            ResolvedGradient::Radial {
+                center: c + offset,
+                size: shape_size,
+                falloff: *falloff,
+            }
+        }
+        Gradient::Conic(
+            gradient @ ConicGradient {
+                start,
+                position: center,
+                aspect_ratio,
+                stops,
+                ..
+            },
+        ) => {
+            let g_start = center.resolve(
+                target.scale_factor(),
+                size,
+                target.physical_size().as_vec2(),
+            );
+
+            compute_angular_color_stops(stops, scratch, extracted_color_stops);
+
+            let resolve_ring_radius = |radius: Val| {
+                scale_val(radius, target.scale_factor())
+                    .resolve(0.5 * size.min_element(), target.physical_size().as_vec2())
+                    .unwrap_or(0.)
+                    .max(0.)
+            };
+
+            ResolvedGradient::Conic {
+                center: g_start + offset,
+                start: start.to_radians(),
+                aspect_ratio: *aspect_ratio,
+                sweep: gradient.sweep(),
+                inner_radius: resolve_ring_radius(gradient.inner_radius),
+                outer_radius: resolve_ring_radius(gradient.outer_radius),
+            }
+        }
+        Gradient::Inset(gradient) => {
+            compute_color_stops(
+                &gradient.stops,
+                target.scale_factor(),
+                0.5 * size.min_element(),
+                target.physical_size().as_vec2(),
+                scratch,
+                extracted_color_stops,
+            );
+
+            ResolvedGradient::Inset {
+                center: offset,
+                size,
+            }
+        }
+        Gradient::Plasma(gradient) => {
+            let scale = (gradient.scale * target.scale_factor()).max(1.);
+
+            compute_color_stops(
+                &gradient.stops,
+                target.scale_factor(),
+                scale,
+                target.physical_size().as_vec2(),
+                scratch,
+                extracted_color_stops,
+            );
+
+            ResolvedGradient::Plasma {
+                scale,
+                speed: gradient.speed,
+            }
+        }
+    };
+    gradient.layer().smoothing.smooth_stops(
+        extracted_color_stops,
+        range_start,
+        SMOOTHING_SUBDIVISIONS,
+    );
+    (resolved_gradient, tiling)
+}
 
-    interpolate_color_stops(&mut extracted_color_stops[range_start..], min, max);
+/// The inset of the area a gradient is drawn in from the edges of the node.
+/// Border gradients are always drawn in the border.
+fn clip_inset(gradient: &Gradient, node_type: NodeType, uinode: &ComputedNode) -> BorderRect {
+    match node_type {
+        NodeType::Rect => gradient.layer().clip.inset(uinode.border, uinode.padding),
+        NodeType::Border => uinode.border,
+    }
 }
 
-pub fn extract_gradients(
+/// Replaces the background colors of nodes with a [`BackgroundGradient`] or [`GradientLut`] with draws in the
+/// gradient pipeline, and records the nodes so their images can be sorted beneath their gradients.
+///
+/// Bevy draws a node's background color with the same sort key as its border, image and text, so the node's
+/// gradients couldn't otherwise be sorted over its background color and beneath the rest.
+/// Runs after the background colors and masks are extracted, before the gradients.
+#[expect(
+    clippy::type_complexity,
+    reason = "it's a system that needs a lot of components"
+)]
+pub fn extract_gradient_background_colors(
+    mut commands: Commands,
+    mut extracted_gradients: ResMut<ExtractedGradients>,
+    mut extracted_color_stops: ResMut<ExtractedColorStops>,
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    nodes_query: Extract<
+        Query<
+            (Entity, &ComputedNode, &ComputedNodeTarget, &GlobalTransform),
+            Or<(
+                With<BackgroundGradient>,
+                With<SharedBackgroundGradient>,
+                With<GradientLut>,
+            )>,
+        >,
+    >,
+) {
+    let _span = info_span!("extract_ui_gradient_background_colors").entered();
+    let mut sorted_stops = vec![];
+
+    extracted_gradients.image_nodes.extend(
+        nodes_query
+            .iter()
+            .map(|(entity, ..)| MainEntity::from(entity)),
+    );
+
+    let mut background_colors = vec![];
+    extracted_uinodes.uinodes.retain(|uinode| {
+        let is_background_color = uinode.image == AssetId::default()
+            && matches!(
+                uinode.item,
+                ExtractedUiItem::Node {
+                    node_type: NodeType::Rect,
+                    ..
+                }
+            );
+        if is_background_color && nodes_query.contains(uinode.main_entity.id()) {
+            background_colors.push((
+                uinode.main_entity,
+                uinode.color,
+                uinode.clip,
+                uinode.extracted_camera_entity,
+            ));
+            return false;
+        }
+        true
+    });
+
+    for (main_entity, color, clip, extracted_camera_entity) in background_colors {
+        let Ok((entity, uinode, target, transform)) = nodes_query.get(main_entity.id()) else {
+            continue;
+        };
+
+        let range_start = extracted_color_stops.0.len();
+        let (resolved_gradient, tiling) = resolve_gradient(
+            &LinearGradient::to_bottom(vec![Color::from(color).into()]).into(),
+            uinode,
+            target,
+            transform,
+            None,
+            &mut sorted_stops,
+            &mut extracted_color_stops.0,
+        );
+        // Duplicate the stop so there's a segment to draw
+        let stop = extracted_color_stops.0[range_start];
+        extracted_color_stops.0.push(stop);
+
+        extracted_gradients.items.push(ExtractedGradient {
+            render_entity: commands.spawn(TemporaryRenderEntity).id(),
+            stack_index: uinode.stack_index,
+            z_offset: BACKGROUND_COLOR_Z_OFFSET,
+            transform: transform.compute_matrix(),
+            stops_range: range_start..extracted_color_stops.0.len(),
+            rect: Rect {
+                min: Vec2::ZERO,
+                max: uinode.size,
+            },
+            clip,
+            extracted_camera_entity,
+            main_entity: entity.into(),
+            node_type: NodeType::Rect,
+            border_radius: uinode.border_radius,
+            border: uinode.border,
+            resolved_gradient,
+            mask: None,
+            palette: None,
+            texture: None,
+            animation: Vec4::ZERO,
+            grain: Vec3::ZERO,
+            spread: SpreadMethod::Pad,
+            tiling,
+            filter: None,
+            custom_function: None,
+            blend_mode: GradientBlendMode::Normal,
+        });
+    }
+}
+
+/// Replaces the border colors of nodes with a [`BorderGradient`] with draws in the gradient pipeline,
+/// composited with the node's border gradients according to its [`BorderColorBlend`].
+///
+/// Bevy draws a node's border color over its border gradients otherwise.
+/// Runs after the borders are extracted.
+#[expect(
+    clippy::type_complexity,
+    reason = "it's a system that needs a lot of components"
+)]
+pub fn extract_gradient_border_colors(
     mut commands: Commands,
     mut extracted_gradients: ResMut<ExtractedGradients>,
     mut extracted_color_stops: ResMut<ExtractedColorStops>,
     mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    nodes_query: Extract<
+        Query<
+            (
+                Entity,
+                &ComputedNode,
+                &ComputedNodeTarget,
+                &GlobalTransform,
+                Option<&BorderColorBlend>,
+            ),
+            With<BorderGradient>,
+        >,
+    >,
+) {
+    let _span = info_span!("extract_ui_gradient_border_colors").entered();
+    let mut sorted_stops = vec![];
+
+    let mut border_colors = vec![];
+    extracted_uinodes.uinodes.retain(|uinode| {
+        let ExtractedUiItem::Node {
+            node_type: NodeType::Border,
+            border,
+            ..
+        } = uinode.item
+        else {
+            return true;
+        };
+        let Ok((.., node, _, _, blend)) = nodes_query.get(uinode.main_entity.id()) else {
+            return true;
+        };
+        // Outlines are extracted as borders too, but with the outline's width and size
+        if uinode.image != AssetId::default()
+            || border != node.border
+            || uinode.rect.size() != node.size
+        {
+            return true;
+        }
+        if blend.copied().unwrap_or_default() != BorderColorBlend::Replace {
+            border_colors.push((
+                uinode.main_entity,
+                uinode.color,
+                uinode.clip,
+                uinode.extracted_camera_entity,
+            ));
+        }
+        false
+    });
+
+    for (main_entity, color, clip, extracted_camera_entity) in border_colors {
+        let Ok((entity, uinode, target, transform, _)) = nodes_query.get(main_entity.id()) else {
+            continue;
+        };
+
+        let range_start = extracted_color_stops.0.len();
+        let (resolved_gradient, tiling) = resolve_gradient(
+            &LinearGradient::to_bottom(vec![Color::from(color).into()]).into(),
+            uinode,
+            target,
+            transform,
+            None,
+            &mut sorted_stops,
+            &mut extracted_color_stops.0,
+        );
+        // Duplicate the stop so there's a segment to draw
+        let stop = extracted_color_stops.0[range_start];
+        extracted_color_stops.0.push(stop);
+
+        extracted_gradients.items.push(ExtractedGradient {
+            render_entity: commands.spawn(TemporaryRenderEntity).id(),
+            stack_index: uinode.stack_index,
+            z_offset: BORDER_COLOR_Z_OFFSET,
+            transform: transform.compute_matrix(),
+            stops_range: range_start..extracted_color_stops.0.len(),
+            rect: Rect {
+                min: Vec2::ZERO,
+                max: uinode.size,
+            },
+            clip,
+            extracted_camera_entity,
+            main_entity: entity.into(),
+            node_type: NodeType::Border,
+            border_radius: uinode.border_radius,
+            border: uinode.border,
+            resolved_gradient,
+            mask: None,
+            palette: None,
+            texture: None,
+            animation: Vec4::ZERO,
+            grain: Vec3::ZERO,
+            spread: SpreadMethod::Pad,
+            tiling,
+            filter: None,
+            custom_function: None,
+            blend_mode: GradientBlendMode::Normal,
+        });
+    }
+}
+
+/// Multiplies the alpha of the extracted gradients by the inherited [`UiOpacity`] of their nodes.
+/// Runs after all of the gradients are extracted.
+pub fn extract_gradient_opacity(
+    extracted_gradients: Res<ExtractedGradients>,
+    mut extracted_color_stops: ResMut<ExtractedColorStops>,
+    opacity_query: Extract<Query<(), With<UiOpacity>>>,
+    nodes_query: Extract<Query<(Option<&UiOpacity>, Option<&ChildOf>)>>,
+) {
+    if opacity_query.is_empty() {
+        return;
+    }
+    let _span = info_span!("extract_ui_gradient_opacity").entered();
+
+    for gradient in &extracted_gradients.items {
+        let opacity = inherited_ui_opacity(gradient.main_entity.id(), &nodes_query);
+        if opacity < 1. {
+            for stop in &mut extracted_color_stops.0[gradient.stops_range.clone()] {
+                stop.0.alpha *= opacity;
+            }
+        }
+    }
+}
+
+/// Returns true if the node's bounds lie entirely outside its target's viewport or its clipping rect,
+/// so nothing drawn inside the node would be visible.
+pub fn is_node_culled(
+    uinode: &ComputedNode,
+    target: &ComputedNodeTarget,
+    transform: &GlobalTransform,
+    clip: Option<&CalculatedClip>,
+) -> bool {
+    // The axis-aligned bounds of the node, including any rotation or scaling
+    let affine = transform.affine();
+    let half_size = 0.5 * uinode.size();
+    let half_extents = Vec2::new(
+        affine.matrix3.x_axis.x.abs() * half_size.x + affine.matrix3.y_axis.x.abs() * half_size.y,
+        affine.matrix3.x_axis.y.abs() * half_size.x + affine.matrix3.y_axis.y.abs() * half_size.y,
+    );
+    let bounds = Rect::from_center_half_size(affine.translation.xy(), half_extents);
+
+    let mut visible = Rect::from_corners(Vec2::ZERO, target.physical_size().as_vec2());
+    if let Some(clip) = clip {
+        visible = visible.intersect(clip.clip);
+    }
+    bounds.intersect(visible).is_empty()
+}
+
+/// Clips a convex polygon to `clip` with the Sutherland-Hodgman algorithm. Each corner is a position
+/// paired with the node's local point at that position, which are interpolated together.
+fn clip_polygon(polygon: &mut Vec<(Vec3, Vec2)>, scratch: &mut Vec<(Vec3, Vec2)>, clip: Rect) {
+    // The distances of a position inside each edge of the clipping rect
+    let edges: [fn(Vec3, Rect) -> f32; 4] = [
+        |position, clip| position.x - clip.min.x,
+        |position, clip| clip.max.x - position.x,
+        |position, clip| position.y - clip.min.y,
+        |position, clip| clip.max.y - position.y,
+    ];
+    for inside in edges {
+        clip_polygon_to_half_plane(polygon, scratch, |(position, _)| inside(position, clip));
+    }
+}
+
+/// Clips a convex polygon to the half-plane where `inside` is positive, one step of the
+/// Sutherland-Hodgman algorithm. `inside` must be linear in the corners.
+fn clip_polygon_to_half_plane(
+    polygon: &mut Vec<(Vec3, Vec2)>,
+    scratch: &mut Vec<(Vec3, Vec2)>,
+    inside: impl Fn((Vec3, Vec2)) -> f32,
+) {
+    scratch.clear();
+    for i in 0..polygon.len() {
+        let current = polygon[i];
+        let next = polygon[(i + 1) % polygon.len()];
+        let (d0, d1) = (inside(current), inside(next));
+        if 0. <= d0 {
+            scratch.push(current);
+        }
+        if (0. <= d0) != (0. <= d1) {
+            let t = d0 / (d0 - d1);
+            scratch.push((current.0.lerp(next.0, t), current.1.lerp(next.1, t)));
+        }
+    }
+    core::mem::swap(polygon, scratch);
+}
+
+#[expect(
+    clippy::type_complexity,
+    reason = "it's a system that needs a lot of components"
+)]
+pub fn extract_gradients(
+    mut commands: Commands,
+    mut extracted_gradients: ResMut<ExtractedGradients>,
+    mut extracted_color_stops: ResMut<ExtractedColorStops>,
     gradients_query: Extract<
         Query<(
             Entity,
@@ -385,13 +1318,34 @@ pub fn extract_gradients(
             &GlobalTransform,
             &InheritedVisibility,
             Option<&CalculatedClip>,
-            AnyOf<(&BackgroundGradient, &BorderGradient)>,
+            Option<&GradientAnimation>,
+            Option<&GradientGroupBounds>,
+            Option<&BorderColorBlend>,
+            Option<&GradientPalette>,
+            AnyOf<(
+                &BackgroundGradient,
+                &BorderGradient,
+                &SharedBackgroundGradient,
+            )>,
         )>,
     >,
     camera_map: Extract<UiCameraMap>,
+    accessibility: Extract<Res<GradientAccessibility>>,
+    invalid_value_policy: Extract<Res<InvalidValuePolicy>>,
 ) {
+    let _span = info_span!("extract_ui_gradients").entered();
     let mut camera_mapper = camera_map.get_mapper();
     let mut sorted_stops = vec![];
+    // The shared gradients resolved so far, reused for the other nodes sharing them with the same layout
+    let mut resolved_shared: HashMap<
+        *const Gradient,
+        (
+            (Vec2, BorderRect, BorderRect, f32, UVec2),
+            ResolvedGradient,
+            Option<PhysicalTiling>,
+            Range<usize>,
+        ),
+    > = HashMap::default();
 
     for (
         entity,
@@ -400,11 +1354,19 @@ pub fn extract_gradients(
         transform,
         inherited_visibility,
         clip,
-        (gradient, gradient_border),
+        animation,
+        group,
+        border_color_blend,
+        palette,
+        (gradient, gradient_border, shared_gradient),
     ) in &gradients_query
     {
-        // Skip invisible images
-        if !inherited_visibility.get() {
+        // Skip hidden nodes, nodes with no size like nodes with `Display::None`, and nodes that
+        // are scrolled or positioned out of view
+        if !inherited_visibility.get()
+            || uinode.is_empty()
+            || is_node_culled(uinode, target, transform, clip)
+        {
             continue;
         }
 
@@ -412,190 +1374,696 @@ pub fn extract_gradients(
             continue;
         };
 
-        for (gradients, node_type) in [
-            (gradient.map(|g| &g.0), NodeType::Rect),
-            (gradient_border.map(|g| &g.0), NodeType::Border),
-        ]
-        .iter()
-        .filter_map(|(g, n)| g.map(|g| (g, *n)))
-        {
-            for gradient in gradients.iter() {
-                if gradient.is_empty() {
-                    continue;
-                }
-                if let Some(color) = gradient.get_single() {
-                    // With a single color stop there's no gradient, fill the node with the color
-                    extracted_uinodes.uinodes.push(ExtractedUiNode {
-                        stack_index: uinode.stack_index,
-                        color: color.into(),
-                        rect: Rect {
-                            min: Vec2::ZERO,
-                            max: uinode.size,
-                        },
-                        image: AssetId::default(),
-                        clip: clip.map(|clip| clip.clip),
-                        extracted_camera_entity,
-                        item: ExtractedUiItem::Node {
-                            atlas_scaling: None,
-                            flip_x: false,
-                            flip_y: false,
-                            border_radius: uinode.border_radius,
-                            border: uinode.border,
-                            node_type,
-                            transform: transform.compute_matrix(),
-                        },
-                        main_entity: entity.into(),
-                        render_entity: commands.spawn(TemporaryRenderEntity).id(),
-                    });
-                    continue;
+        // Gradients are resolved every frame against the target's current scale factor (which
+        // includes `UiScale`), so changes to the window's scale factor apply without the gradient
+        // components needing to change.
+
+        let layers = gradient
+            .into_iter()
+            .flat_map(|gradient| {
+                gradient
+                    .0
+                    .iter()
+                    .map(|gradient| (gradient, NodeType::Rect, GRADIENT_Z_OFFSET, false))
+            })
+            .chain(shared_gradient.into_iter().flat_map(|gradient| {
+                gradient
+                    .0
+                    .iter()
+                    .map(|gradient| (gradient, NodeType::Rect, GRADIENT_Z_OFFSET, true))
+            }))
+            .chain(gradient_border.into_iter().flat_map(|gradient| {
+                gradient
+                    .0
+                    .iter()
+                    .map(|gradient| (gradient, NodeType::Border, BORDER_GRADIENT_Z_OFFSET, false))
+            }));
+
+        for (gradient, node_type, z_offset, shared) in layers {
+            // Transparent layers draw nothing, unless the minimum alpha makes them visible
+            if gradient.is_empty() || (gradient.is_transparent() && accessibility.min_alpha <= 0.) {
+                continue;
+            }
+            let original = gradient;
+            let sanitized = invalid_value_policy.sanitize(gradient);
+            let gradient = sanitized.as_ref().unwrap_or(gradient);
+            let layer = gradient.layer();
+            let grain = layer.grain.filter(|grain| 0. < grain.intensity);
+            let filter = layer.filter.filter(|filter| !filter.is_identity());
+
+            let range_start = extracted_color_stops.0.len();
+
+            // Fixed and grouped gradients are resolved against the node's position, so they can't be reused
+            let layout = (shared
+                && group.is_none()
+                && layer.attachment == GradientAttachment::Scroll)
+                .then(|| {
+                    (
+                        uinode.size(),
+                        uinode.border(),
+                        uinode.padding(),
+                        target.scale_factor(),
+                        target.physical_size(),
+                    )
+                });
+            let reusable = layout.and_then(|layout| {
+                resolved_shared
+                    .get(&(original as *const Gradient))
+                    .filter(|(resolved_layout, ..)| *resolved_layout == layout)
+            });
+            let (resolved_gradient, tiling) = match reusable {
+                Some((_, resolved_gradient, tiling, range)) => {
+                    extracted_color_stops.0.extend_from_within(range.clone());
+                    (*resolved_gradient, *tiling)
                 }
-                match gradient {
-                    Gradient::Linear(LinearGradient { angle, stops }) => {
-                        let length = compute_gradient_line_length(*angle, uinode.size);
-
-                        let range_start = extracted_color_stops.0.len();
-
-                        compute_color_stops(
-                            stops,
-                            target.scale_factor(),
-                            length,
-                            target.physical_size().as_vec2(),
-                            &mut sorted_stops,
-                            &mut extracted_color_stops.0,
-                        );
-
-                        extracted_gradients.items.push(ExtractedGradient {
-                            render_entity: commands.spawn(TemporaryRenderEntity).id(),
-                            stack_index: uinode.stack_index,
-                            transform: transform.compute_matrix(),
-                            stops_range: range_start..extracted_color_stops.0.len(),
-                            rect: Rect {
-                                min: Vec2::ZERO,
-                                max: uinode.size,
-                            },
-                            clip: clip.map(|clip| clip.clip),
-                            extracted_camera_entity,
-                            main_entity: entity.into(),
-                            node_type,
-                            border_radius: uinode.border_radius,
-                            border: uinode.border,
-                            resolved_gradient: ResolvedGradient::Linear { angle: *angle },
-                        });
+                _ => {
+                    let (mut resolved_gradient, tiling) = resolve_gradient(
+                        gradient,
+                        uinode,
+                        target,
+                        transform,
+                        group,
+                        &mut sorted_stops,
+                        &mut extracted_color_stops.0,
+                    );
+                    if accessibility.reduced_motion {
+                        if let ResolvedGradient::Plasma { speed, .. } = &mut resolved_gradient {
+                            *speed = 0.;
+                        }
                     }
-                    Gradient::Radial(RadialGradient {
-                        position: center,
-                        shape,
-                        stops,
-                    }) => {
-                        let c = center.resolve(
-                            target.scale_factor(),
-                            uinode.size,
-                            target.physical_size().as_vec2(),
-                        );
-
-                        let size = shape.resolve(
-                            c,
-                            target.scale_factor(),
-                            uinode.size,
-                            target.physical_size().as_vec2(),
-                        );
-
-                        let length = size.x;
-
-                        let range_start = extracted_color_stops.0.len();
-                        compute_color_stops(
-                            stops,
-                            target.scale_factor(),
-                            length,
-                            target.physical_size().as_vec2(),
-                            &mut sorted_stops,
-                            &mut extracted_color_stops.0,
-                        );
-
-                        extracted_gradients.items.push(ExtractedGradient {
-                            render_entity: commands.spawn(TemporaryRenderEntity).id(),
-                            stack_index: uinode.stack_index,
-                            transform: transform.compute_matrix(),
-                            stops_range: range_start..extracted_color_stops.0.len(),
-                            rect: Rect {
-                                min: Vec2::ZERO,
-                                max: uinode.size,
-                            },
-                            clip: clip.map(|clip| clip.clip),
-                            extracted_camera_entity,
-                            main_entity: entity.into(),
-                            node_type,
-                            border_radius: uinode.border_radius,
-                            border: uinode.border,
-                            resolved_gradient: ResolvedGradient::Radial { center: c, size },
-                        });
+                    if extracted_color_stops.0.len() - range_start == 1 {
+                        // A single stop fills the node with its color, duplicate it so there's a segment to draw
+                        let stop = extracted_color_stops.0[range_start];
+                        extracted_color_stops.0.push(stop);
                     }
-                    Gradient::Conic(ConicGradient {
-                        start,
-                        position: center,
-                        stops,
-                    }) => {
-                        let g_start = center.resolve(
-                            target.scale_factor(),
-                            uinode.size,
-                            target.physical_size().as_vec2(),
-                        );
-                        let range_start = extracted_color_stops.0.len();
-
-                        // sort the explicit stops
-                        sorted_stops.extend(stops.iter().filter_map(|stop| {
-                            stop.angle.map(|angle| {
-                                (stop.color.to_linear(), angle.clamp(0., TAU), stop.hint)
-                            })
-                        }));
-                        sorted_stops.sort_by_key(|(_, angle, _)| FloatOrd(*angle));
-                        let mut sorted_stops_drain = sorted_stops.drain(..);
-
-                        // fill the extracted stops buffer
-                        extracted_color_stops.0.extend(stops.iter().map(|stop| {
-                            if stop.angle.is_none() {
-                                (stop.color.to_linear(), f32::NAN, stop.hint)
-                            } else {
-                                sorted_stops_drain.next().unwrap()
-                            }
-                        }));
-
-                        interpolate_color_stops(
-                            &mut extracted_color_stops.0[range_start..],
-                            0.,
-                            TAU,
-                        );
-
-                        extracted_gradients.items.push(ExtractedGradient {
-                            render_entity: commands.spawn(TemporaryRenderEntity).id(),
-                            stack_index: uinode.stack_index(),
-                            transform: transform.compute_matrix(),
-                            stops_range: range_start..extracted_color_stops.0.len(),
-                            rect: Rect {
-                                min: Vec2::ZERO,
-                                max: uinode.size(),
-                            },
-                            clip: clip.map(|clip| clip.clip),
-                            extracted_camera_entity,
-                            main_entity: entity.into(),
-                            node_type,
-                            border_radius: uinode.border_radius(),
-                            border: uinode.border(),
-                            resolved_gradient: ResolvedGradient::Conic {
-                                center: g_start,
-                                start: *start,
-                            },
-                        });
+                    for stop in &mut extracted_color_stops.0[range_start..] {
+                        stop.0 = accessibility.apply_min_alpha(stop.0);
+                        if accessibility.reduced_motion {
+                            stop.4 = StopOscillation::default();
+                        }
                     }
+                    (resolved_gradient, tiling)
                 }
+            };
+            if let Some(layout) = layout.filter(|_| reusable.is_none()) {
+                resolved_shared.insert(
+                    original,
+                    (
+                        layout,
+                        resolved_gradient,
+                        tiling,
+                        range_start..extracted_color_stops.0.len(),
+                    ),
+                );
             }
-        }
-    }
-}
 
-#[expect(
-    clippy::too_many_arguments,
-    reason = "it's a system that needs a lot of them"
+            extracted_gradients.items.push(ExtractedGradient {
+                render_entity: commands.spawn(TemporaryRenderEntity).id(),
+                stack_index: uinode.stack_index,
+                z_offset,
+                transform: transform.compute_matrix(),
+                stops_range: range_start..extracted_color_stops.0.len(),
+                rect: Rect {
+                    min: Vec2::ZERO,
+                    max: uinode.size,
+                },
+                clip: clip.map(|clip| clip.clip),
+                extracted_camera_entity,
+                main_entity: entity.into(),
+                node_type,
+                border_radius: uinode.border_radius,
+                border: clip_inset(gradient, node_type, uinode),
+                animation: animation
+                    .filter(|_| !accessibility.reduced_motion)
+                    .map(|animation| animation.resolve(gradient, target.scale_factor()))
+                    .unwrap_or(Vec4::ZERO),
+                grain: grain
+                    .map(|grain| {
+                        Vec3::new(
+                            grain.intensity,
+                            grain.scale * target.scale_factor(),
+                            match grain.pattern {
+                                GrainPattern::Noise
+                                    if grain.animated && !accessibility.reduced_motion =>
+                                {
+                                    1.
+                                }
+                                GrainPattern::Noise => 0.,
+                                GrainPattern::Bayer4 => 4.,
+                                GrainPattern::Bayer8 => 8.,
+                            },
+                        )
+                    })
+                    .unwrap_or(Vec3::ZERO),
+                spread: layer.spread,
+                tiling,
+                filter,
+                custom_function: layer.custom_function,
+                blend_mode: if node_type == NodeType::Border
+                    && border_color_blend == Some(&BorderColorBlend::Multiply)
+                {
+                    GradientBlendMode::Multiply
+                } else {
+                    layer.blend_mode
+                },
+                resolved_gradient,
+                mask: None,
+                palette: palette.map(|palette| (palette.image.id(), palette.dither)),
+                texture: layer.texture.as_ref().map(|texture| {
+                    let scale_factor = target.scale_factor();
+                    let modulation = match texture.modulation {
+                        TextureModulation::Displace(amount)
+                            if !matches!(gradient, Gradient::Conic(_)) =>
+                        {
+                            TextureModulation::Displace(amount * scale_factor)
+                        }
+                        modulation => modulation,
+                    };
+                    (
+                        texture.image.id(),
+                        modulation,
+                        texture.tile_size.map(|tile_size| tile_size * scale_factor),
+                    )
+                }),
+            });
+        }
+    }
+}
+
+/// Extracts the [`GradientQuality`]
+pub fn extract_gradient_quality(
+    mut extracted_gradients: ResMut<ExtractedGradients>,
+    quality: Extract<Res<GradientQuality>>,
+) {
+    extracted_gradients.quality = **quality;
+}
+
+/// Extracts the gradients of nodes with a [`GradientLut`]
+#[expect(
+    clippy::type_complexity,
+    reason = "it's a system that needs a lot of components"
+)]
+pub fn extract_gradient_luts(
+    mut commands: Commands,
+    mut extracted_gradients: ResMut<ExtractedGradients>,
+    mut extracted_color_stops: ResMut<ExtractedColorStops>,
+    luts_query: Extract<
+        Query<(
+            Entity,
+            &ComputedNode,
+            &ComputedNodeTarget,
+            &GlobalTransform,
+            &InheritedVisibility,
+            Option<&CalculatedClip>,
+            &GradientLut,
+            Option<&GradientPalette>,
+        )>,
+    >,
+    camera_map: Extract<UiCameraMap>,
+    accessibility: Extract<Res<GradientAccessibility>>,
+) {
+    let _span = info_span!("extract_ui_gradient_luts").entered();
+    let mut camera_mapper = camera_map.get_mapper();
+    let mut sorted_stops = vec![];
+
+    for (entity, uinode, target, transform, inherited_visibility, clip, lut, palette) in &luts_query
+    {
+        if !inherited_visibility.get()
+            || lut.stops.is_empty()
+            || uinode.is_empty()
+            || is_node_culled(uinode, target, transform, clip)
+        {
+            continue;
+        }
+
+        let Some(extracted_camera_entity) = camera_mapper.map(target) else {
+            continue;
+        };
+
+        let range_start = extracted_color_stops.0.len();
+        compute_color_stops(
+            &lut.stops,
+            target.scale_factor(),
+            uinode.size.x,
+            target.physical_size().as_vec2(),
+            &mut sorted_stops,
+            &mut extracted_color_stops.0,
+        );
+        if extracted_color_stops.0.len() - range_start == 1 {
+            // A single stop fills the whole node, duplicate it so there's a segment to draw
+            let stop = extracted_color_stops.0[range_start];
+            extracted_color_stops.0.push(stop);
+        }
+        for stop in &mut extracted_color_stops.0[range_start..] {
+            stop.0 = accessibility.apply_min_alpha(stop.0);
+            if accessibility.reduced_motion {
+                stop.4 = StopOscillation::default();
+            }
+        }
+
+        extracted_gradients.items.push(ExtractedGradient {
+            render_entity: commands.spawn(TemporaryRenderEntity).id(),
+            stack_index: uinode.stack_index,
+            z_offset: LUT_Z_OFFSET,
+            transform: transform.compute_matrix(),
+            stops_range: range_start..extracted_color_stops.0.len(),
+            rect: Rect {
+                min: Vec2::ZERO,
+                max: uinode.size,
+            },
+            clip: clip.map(|clip| clip.clip),
+            extracted_camera_entity,
+            main_entity: entity.into(),
+            node_type: NodeType::Rect,
+            border_radius: uinode.border_radius,
+            border: uinode.border,
+            resolved_gradient: ResolvedGradient::Lut {
+                image: lut.image.id(),
+                length: uinode.size.x,
+            },
+            mask: None,
+            palette: palette.map(|palette| (palette.image.id(), palette.dither)),
+            texture: None,
+            animation: Vec4::ZERO,
+            grain: Vec3::ZERO,
+            spread: lut.spread,
+            tiling: None,
+            filter: None,
+            custom_function: None,
+            blend_mode: GradientBlendMode::Normal,
+        });
+    }
+}
+
+/// Extracts [`GradientGlow`]s as quads expanded by the glow's spread, behind their nodes.
+/// The quads' corner radii are expanded by the spread too, so the distance from their rounded edges
+/// is the distance from the node's border offset by the spread.
+#[expect(
+    clippy::type_complexity,
+    reason = "it's a system that needs a lot of components"
+)]
+pub fn extract_gradient_glows(
+    mut commands: Commands,
+    mut extracted_gradients: ResMut<ExtractedGradients>,
+    mut extracted_color_stops: ResMut<ExtractedColorStops>,
+    glows_query: Extract<
+        Query<(
+            Entity,
+            &ComputedNode,
+            &ComputedNodeTarget,
+            &GlobalTransform,
+            &InheritedVisibility,
+            Option<&CalculatedClip>,
+            &GradientGlow,
+        )>,
+    >,
+    camera_map: Extract<UiCameraMap>,
+    accessibility: Extract<Res<GradientAccessibility>>,
+) {
+    let _span = info_span!("extract_ui_gradient_glows").entered();
+    let mut camera_mapper = camera_map.get_mapper();
+    let mut sorted_stops = vec![];
+
+    for (entity, uinode, target, transform, inherited_visibility, clip, glow) in &glows_query {
+        if !inherited_visibility.get() || glow.stops.is_empty() || glow.spread <= 0. {
+            continue;
+        }
+
+        let Some(extracted_camera_entity) = camera_mapper.map(target) else {
+            continue;
+        };
+
+        let spread = glow.spread * target.scale_factor();
+        let range_start = extracted_color_stops.0.len();
+        compute_color_stops(
+            &glow.stops,
+            target.scale_factor(),
+            spread,
+            target.physical_size().as_vec2(),
+            &mut sorted_stops,
+            &mut extracted_color_stops.0,
+        );
+        if extracted_color_stops.0.len() - range_start == 1 {
+            // A single stop fills the whole glow, duplicate it so there's a segment to draw
+            let stop = extracted_color_stops.0[range_start];
+            extracted_color_stops.0.push(stop);
+        }
+        for stop in &mut extracted_color_stops.0[range_start..] {
+            stop.0 = accessibility.apply_min_alpha(stop.0);
+            if accessibility.reduced_motion {
+                stop.4 = StopOscillation::default();
+            }
+        }
+
+        let radius = uinode.border_radius;
+        extracted_gradients.items.push(ExtractedGradient {
+            render_entity: commands.spawn(TemporaryRenderEntity).id(),
+            stack_index: uinode.stack_index,
+            z_offset: GLOW_Z_OFFSET,
+            transform: transform.compute_matrix(),
+            stops_range: range_start..extracted_color_stops.0.len(),
+            rect: Rect {
+                min: Vec2::ZERO,
+                max: uinode.size + 2. * spread,
+            },
+            clip: clip.map(|clip| clip.clip),
+            extracted_camera_entity,
+            main_entity: entity.into(),
+            node_type: NodeType::Rect,
+            border_radius: ResolvedBorderRadius {
+                top_left: radius.top_left + spread,
+                top_right: radius.top_right + spread,
+                bottom_right: radius.bottom_right + spread,
+                bottom_left: radius.bottom_left + spread,
+            },
+            border: BorderRect::ZERO,
+            resolved_gradient: ResolvedGradient::Glow { spread },
+            mask: None,
+            palette: None,
+            texture: None,
+            animation: Vec4::ZERO,
+            grain: Vec3::ZERO,
+            spread: SpreadMethod::Pad,
+            tiling: None,
+            filter: None,
+            custom_function: None,
+            blend_mode: GradientBlendMode::Normal,
+        });
+    }
+}
+
+/// Extracts each line of a [`GradientStroke`]'s path as a rotated quad with round ends, filled with
+/// a linear gradient along the line. The lines share the stroke's resolved color stops.
+#[expect(
+    clippy::type_complexity,
+    reason = "it's a system that needs a lot of components"
+)]
+pub fn extract_gradient_strokes(
+    mut commands: Commands,
+    mut extracted_gradients: ResMut<ExtractedGradients>,
+    mut extracted_color_stops: ResMut<ExtractedColorStops>,
+    strokes_query: Extract<
+        Query<(
+            Entity,
+            &ComputedNode,
+            &ComputedNodeTarget,
+            &GlobalTransform,
+            &InheritedVisibility,
+            Option<&CalculatedClip>,
+            &GradientStroke,
+        )>,
+    >,
+    camera_map: Extract<UiCameraMap>,
+    accessibility: Extract<Res<GradientAccessibility>>,
+) {
+    let _span = info_span!("extract_ui_gradient_strokes").entered();
+    let mut camera_mapper = camera_map.get_mapper();
+    let mut sorted_stops = vec![];
+
+    for (entity, uinode, target, transform, inherited_visibility, clip, stroke) in &strokes_query {
+        if !inherited_visibility.get() || stroke.stops.is_empty() || stroke.width <= 0. {
+            continue;
+        }
+
+        let Some(extracted_camera_entity) = camera_mapper.map(target) else {
+            continue;
+        };
+
+        let points = stroke.flatten(target.scale_factor(), STROKE_ARC_TOLERANCE);
+        let length: f32 = points.windows(2).map(|w| w[0].distance(w[1])).sum();
+        let visible_length = length * stroke.progress.clamp(0., 1.);
+        if visible_length <= 0. {
+            continue;
+        }
+
+        let range_start = extracted_color_stops.0.len();
+        compute_color_stops(
+            &stroke.stops,
+            target.scale_factor(),
+            length,
+            target.physical_size().as_vec2(),
+            &mut sorted_stops,
+            &mut extracted_color_stops.0,
+        );
+        if extracted_color_stops.0.len() - range_start == 1 {
+            // A single stop fills the whole stroke, duplicate it so there's a segment to draw
+            let stop = extracted_color_stops.0[range_start];
+            extracted_color_stops.0.push(stop);
+        }
+        for stop in &mut extracted_color_stops.0[range_start..] {
+            stop.0 = accessibility.apply_min_alpha(stop.0);
+            if accessibility.reduced_motion {
+                stop.4 = StopOscillation::default();
+            }
+        }
+        let stops_range = range_start..extracted_color_stops.0.len();
+
+        let width = stroke.width * target.scale_factor();
+        let node_transform = transform.compute_matrix();
+        // The path's coordinates are relative to the top-left corner of the node
+        let offset = -0.5 * uinode.size;
+        let mut distance = 0.;
+        for line in points.windows(2) {
+            if visible_length <= distance {
+                break;
+            }
+            let start = line[0];
+            let mut end = line[1];
+            let line_length = start.distance(end);
+            if line_length <= 0. {
+                continue;
+            }
+            let drawn_length = line_length.min(visible_length - distance);
+            let dir = (end - start) / line_length;
+            end = start + dir * drawn_length;
+
+            let center = offset + 0.5 * (start + end);
+            extracted_gradients.items.push(ExtractedGradient {
+                render_entity: commands.spawn(TemporaryRenderEntity).id(),
+                stack_index: uinode.stack_index,
+                z_offset: STROKE_Z_OFFSET,
+                transform: node_transform
+                    * Mat4::from_translation(center.extend(0.))
+                    * Mat4::from_rotation_z(dir.to_angle()),
+                stops_range: stops_range.clone(),
+                rect: Rect {
+                    min: Vec2::ZERO,
+                    max: Vec2::new(drawn_length + width, width),
+                },
+                clip: clip.map(|clip| clip.clip),
+                extracted_camera_entity,
+                main_entity: entity.into(),
+                node_type: NodeType::Rect,
+                border_radius: ResolvedBorderRadius {
+                    top_left: 0.5 * width,
+                    top_right: 0.5 * width,
+                    bottom_right: 0.5 * width,
+                    bottom_left: 0.5 * width,
+                },
+                border: BorderRect::ZERO,
+                resolved_gradient: ResolvedGradient::Linear {
+                    start: Vec2::new(-0.5 * drawn_length - distance, 0.),
+                    dir: Vec2::X,
+                },
+                mask: None,
+                palette: None,
+                texture: None,
+                animation: Vec4::ZERO,
+                grain: Vec3::ZERO,
+                spread: SpreadMethod::Pad,
+                tiling: None,
+                filter: None,
+                custom_function: None,
+                blend_mode: GradientBlendMode::Normal,
+            });
+            distance += line_length;
+        }
+    }
+}
+
+/// Replaces the background colors and images of nodes with a [`GradientMask`] with masked draws
+/// in the gradient pipeline.
+///
+/// Runs after the background colors are extracted, before the gradients.
+#[expect(
+    clippy::type_complexity,
+    reason = "it's a system that needs a lot of components"
+)]
+pub fn extract_gradient_masks(
+    mut commands: Commands,
+    mut extracted_gradients: ResMut<ExtractedGradients>,
+    mut extracted_color_stops: ResMut<ExtractedColorStops>,
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    masks_query: Extract<
+        Query<(
+            Entity,
+            &ComputedNode,
+            &ComputedNodeTarget,
+            &GlobalTransform,
+            &InheritedVisibility,
+            Option<&CalculatedClip>,
+            &GradientMask,
+            AnyOf<(&BackgroundColor, &ImageNode)>,
+        )>,
+    >,
+    camera_map: Extract<UiCameraMap>,
+    invalid_value_policy: Extract<Res<InvalidValuePolicy>>,
+) {
+    let _span = info_span!("extract_ui_gradient_masks").entered();
+    let mut camera_mapper = camera_map.get_mapper();
+    let mut sorted_stops = vec![];
+    // The nodes whose background colors are drawn masked
+    let mut masked_backgrounds = HashSet::new();
+
+    for (
+        entity,
+        uinode,
+        target,
+        transform,
+        inherited_visibility,
+        clip,
+        GradientMask(mask),
+        (background_color, image),
+    ) in &masks_query
+    {
+        if !inherited_visibility.get() || mask.is_empty() || uinode.is_empty() {
+            continue;
+        }
+
+        let Some(extracted_camera_entity) = camera_mapper.map(target) else {
+            continue;
+        };
+
+        let sanitized = invalid_value_policy.sanitize(mask);
+        let mask = sanitized.as_ref().unwrap_or(mask);
+
+        let layers = background_color
+            .map(|background_color| {
+                (
+                    AssetId::default(),
+                    background_color.0.to_linear(),
+                    MASK_BACKGROUND_Z_OFFSET,
+                    false,
+                    false,
+                )
+            })
+            .into_iter()
+            .chain(
+                image
+                    .filter(|image| !image.image_mode.uses_slices())
+                    .map(|image| {
+                        (
+                            image.image.id(),
+                            image.color.to_linear(),
+                            MASK_IMAGE_Z_OFFSET,
+                            image.flip_x,
+                            image.flip_y,
+                        )
+                    }),
+            );
+
+        for (image, color, z_offset, flip_x, flip_y) in layers {
+            if color.alpha <= 0. {
+                continue;
+            }
+
+            let range_start = extracted_color_stops.0.len();
+            let (resolved_gradient, tiling) = resolve_gradient(
+                mask,
+                uinode,
+                target,
+                transform,
+                None,
+                &mut sorted_stops,
+                &mut extracted_color_stops.0,
+            );
+            if extracted_color_stops.0.len() - range_start == 1 {
+                // A single stop masks the whole node uniformly, duplicate it so there's a segment to draw
+                let stop = extracted_color_stops.0[range_start];
+                extracted_color_stops.0.push(stop);
+            }
+
+            extracted_gradients.items.push(ExtractedGradient {
+                render_entity: commands.spawn(TemporaryRenderEntity).id(),
+                stack_index: uinode.stack_index,
+                z_offset,
+                transform: transform.compute_matrix(),
+                stops_range: range_start..extracted_color_stops.0.len(),
+                rect: Rect {
+                    min: Vec2::ZERO,
+                    max: uinode.size,
+                },
+                clip: clip.map(|clip| clip.clip),
+                extracted_camera_entity,
+                main_entity: entity.into(),
+                node_type: NodeType::Rect,
+                border_radius: uinode.border_radius,
+                border: uinode.border,
+                resolved_gradient,
+                mask: Some(GradientMaskLayer {
+                    image,
+                    color,
+                    flip_x,
+                    flip_y,
+                }),
+                palette: None,
+                texture: None,
+                animation: Vec4::ZERO,
+                grain: Vec3::ZERO,
+                spread: mask.layer().spread,
+                tiling,
+                filter: None,
+                custom_function: None,
+                blend_mode: GradientBlendMode::Normal,
+            });
+
+            if image == AssetId::default() {
+                masked_backgrounds.insert(MainEntity::from(entity));
+            } else {
+                extracted_gradients
+                    .masked_images
+                    .insert(MainEntity::from(entity));
+            }
+        }
+    }
+
+    if masked_backgrounds.is_empty() {
+        return;
+    }
+    // Remove the unmasked background colors
+    extracted_uinodes.uinodes.retain(|uinode| {
+        !(masked_backgrounds.contains(&uinode.main_entity)
+            && uinode.image == AssetId::default()
+            && matches!(
+                uinode.item,
+                ExtractedUiItem::Node {
+                    node_type: NodeType::Rect,
+                    ..
+                }
+            ))
+    });
+}
+
+/// Removes the unmasked images of nodes with a [`GradientMask`] whose masked images were extracted by
+/// [`extract_gradient_masks`], they are drawn by the gradient pipeline instead.
+pub fn remove_masked_images(
+    extracted_gradients: Res<ExtractedGradients>,
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+) {
+    if extracted_gradients.masked_images.is_empty() {
+        return;
+    }
+    extracted_uinodes.uinodes.retain(|uinode| {
+        !(extracted_gradients
+            .masked_images
+            .contains(&uinode.main_entity)
+            && uinode.image != AssetId::default())
+    });
+}
+
+#[expect(
+    clippy::too_many_arguments,
+    reason = "it's a system that needs a lot of them"
 )]
 pub fn queue_gradient(
     extracted_gradients: ResMut<ExtractedGradients>,
@@ -630,6 +2098,8 @@ pub fn queue_gradient(
             UiGradientPipelineKey {
                 anti_alias: matches!(ui_anti_alias, None | Some(UiAntiAlias::On)),
                 hdr: view.hdr,
+                blend_mode: gradient.blend_mode,
+                quality: extracted_gradients.quality,
             },
         );
 
@@ -637,7 +2107,7 @@ pub fn queue_gradient(
             draw_function,
             pipeline,
             entity: (gradient.render_entity, gradient.main_entity),
-            sort_key: FloatOrd(gradient.stack_index as f32 + GRADIENT_Z_OFFSET),
+            sort_key: FloatOrd(gradient.stack_index as f32 + gradient.z_offset),
             batch_range: 0..0,
             extra_index: PhaseItemExtraIndex::None,
             index,
@@ -646,25 +2116,119 @@ pub fn queue_gradient(
     }
 }
 
+/// Moves the images of nodes with gradients beneath the gradients, bevy queues them with the same sort key as
+/// the node's border and text.
+pub fn queue_gradient_node_images(
+    extracted_gradients: Res<ExtractedGradients>,
+    extracted_uinodes: Res<ExtractedUiNodes>,
+    draw_functions: Res<DrawFunctions<TransparentUi>>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<TransparentUi>>,
+) {
+    if extracted_gradients.image_nodes.is_empty() {
+        return;
+    }
+    let Some(draw_function) = draw_functions.read().get_id::<DrawUi>() else {
+        return;
+    };
+    for transparent_phase in transparent_render_phases.values_mut() {
+        for item in &mut transparent_phase.items {
+            if item.draw_function != draw_function {
+                continue;
+            }
+            let Some(uinode) = extracted_uinodes
+                .uinodes
+                .get(item.index)
+                .filter(|uinode| uinode.render_entity == item.entity.0)
+            else {
+                continue;
+            };
+            let is_image = uinode.image != AssetId::default()
+                && matches!(
+                    uinode.item,
+                    ExtractedUiItem::Node {
+                        node_type: NodeType::Rect,
+                        ..
+                    }
+                );
+            if is_image
+                && extracted_gradients
+                    .image_nodes
+                    .contains(&uinode.main_entity)
+            {
+                item.sort_key = FloatOrd(uinode.stack_index as f32 + IMAGE_Z_OFFSET);
+            }
+        }
+    }
+}
+
 #[repr(C)]
+/// The vertex format of the gradient shader
 #[derive(Copy, Clone, Pod, Zeroable)]
-struct UiGradientVertex {
-    position: [f32; 3],
-    uv: [f32; 2],
-    flags: u32,
-    radius: [f32; 4],
-    border: [f32; 4],
-    size: [f32; 2],
-    point: [f32; 2],
-    g_start: [f32; 2],
-    g_dir: [f32; 2],
-    start_color: [f32; 4],
-    start_len: f32,
-    end_len: f32,
-    end_color: [f32; 4],
-    hint: f32,
+pub struct UiGradientVertex {
+    /// xyz: position, w: the outer radius of conic gradients
+    pub position: [f32; 4],
+    /// xy: texture coordinates, z: the sweep of conic gradients,
+    /// w: the falloff of radial gradients or the inner radius of conic gradients
+    pub uv: [f32; 4],
+    /// x: flags, y: the bits of the modulation amount of the gradient's texture
+    pub flags: [u32; 2],
+    pub radius: [f32; 4],
+    pub border: [f32; 4],
+    /// xy: node size, zw: tile size
+    pub size: [f32; 4],
+    /// xy: position relative to the center of the node, zw: position relative to the first tile
+    pub point: [f32; 4],
+    /// xy: start point or center, zw: direction or shape parameters
+    pub g_line: [f32; 4],
+    pub start_color: [f32; 4],
+    /// start_len, end_len, hint, packed interpolation
+    pub segment: [f32; 4],
+    pub end_color: [f32; 4],
+    /// The bits of the masked color with the mask flag,
+    /// or the stop oscillations packed into pairs of half floats with the oscillate flag
+    pub mask_or_oscillation: [u32; 4],
+    pub animation: [f32; 4],
+    /// xyz: grain, w: packed interpolation parameter
+    pub grain: [f32; 4],
+    /// xy: first and last stop distances, zw: tile period
+    pub spread: [f32; 4],
+    /// hue rotation, saturation, grayscale, brightness
+    pub filter: [f32; 4],
+}
+
+/// Packs two floats into the bits of a pair of half floats, like WGSL's `pack2x16float`
+fn pack_half2(a: f32, b: f32) -> u32 {
+    f32_to_f16_bits(a) | (f32_to_f16_bits(b) << 16)
 }
 
+/// Converts a float to the bits of a half float, rounding towards zero.
+/// Values too large for a half float become infinite and values too small become zero.
+fn f32_to_f16_bits(value: f32) -> u32 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) & 0x8000;
+    if value.is_nan() {
+        return sign | 0x7e00;
+    }
+    let exponent = ((bits >> 23) & 0xff) as i32 - 127 + 15;
+    let mantissa = bits & 0x7f_ffff;
+    if 0x1f <= exponent {
+        sign | 0x7c00
+    } else if exponent <= 0 {
+        if exponent < -10 {
+            sign
+        } else {
+            // Subnormal, with the implicit leading bit shifted into the mantissa
+            sign | ((mantissa | 0x80_0000) >> (14 - exponent))
+        }
+    } else {
+        sign | ((exponent as u32) << 10) | (mantissa >> 13)
+    }
+}
+
+#[expect(
+    clippy::too_many_arguments,
+    reason = "it's a system that needs a lot of them"
+)]
 pub fn prepare_gradient(
     mut commands: Commands,
     render_device: Res<RenderDevice>,
@@ -673,11 +2237,28 @@ pub fn prepare_gradient(
     mut extracted_gradients: ResMut<ExtractedGradients>,
     mut extracted_color_stops: ResMut<ExtractedColorStops>,
     view_uniforms: Res<ViewUniforms>,
+    globals_buffer: Res<GlobalsBuffer>,
     gradients_pipeline: Res<GradientPipeline>,
+    mut image_bind_groups: ResMut<GradientImageBindGroups>,
+    gpu_images: Res<RenderAssets<GpuImage>>,
+    events: Res<SpriteAssetEvents>,
     mut phases: ResMut<ViewSortedRenderPhases<TransparentUi>>,
     mut previous_len: Local<usize>,
+    stats: Option<Res<GradientRenderStats>>,
 ) {
-    if let Some(view_binding) = view_uniforms.uniforms.binding() {
+    // If an image has changed, the GpuImage has (probably) changed
+    for event in &events.images {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            image_bind_groups
+                .values
+                .retain(|(image, palette), _| image != id && palette != id);
+        }
+    }
+
+    if let (Some(view_binding), Some(globals_binding)) = (
+        view_uniforms.uniforms.binding(),
+        globals_buffer.buffer.binding(),
+    ) {
         let mut batches: Vec<(Entity, GradientBatch)> = Vec::with_capacity(*previous_len);
 
         ui_meta.vertices.clear();
@@ -685,13 +2266,20 @@ pub fn prepare_gradient(
         ui_meta.view_bind_group = Some(render_device.create_bind_group(
             "gradient_view_bind_group",
             &gradients_pipeline.view_layout,
-            &BindGroupEntries::single(view_binding),
+            &BindGroupEntries::sequential((view_binding, globals_binding)),
         ));
 
         // Buffer indexes
         let mut vertices_index = 0;
         let mut indices_index = 0;
-
+        // The corners of the current node's clipped quad, and their local points
+        let mut polygon = Vec::with_capacity(8);
+        let mut clip_scratch = Vec::with_capacity(8);
+        // The corners of a segment's band, for gradients with vertex colors
+        let mut band = Vec::with_capacity(10);
+        let quality = extracted_gradients.quality;
+
+        let prepare_span = info_span!("prepare_gradient_vertices").entered();
         for ui_phase in phases.values_mut() {
             for item_index in 0..ui_phase.items.len() {
                 let item = &mut ui_phase.items[item_index];
@@ -700,6 +2288,40 @@ pub fn prepare_gradient(
                     .get(item.index)
                     .filter(|n| item.entity() == n.render_entity)
                 {
+                    let image = match (
+                        &gradient.mask,
+                        &gradient.resolved_gradient,
+                        &gradient.texture,
+                    ) {
+                        (Some(mask), ..) => mask.image,
+                        (None, ResolvedGradient::Lut { image, .. }, _) => *image,
+                        (None, _, Some((image, ..))) => *image,
+                        _ => AssetId::default(),
+                    };
+                    let palette = gradient
+                        .palette
+                        .map_or(AssetId::default(), |(palette, _)| palette);
+                    let (Some(gpu_image), Some(gpu_palette)) =
+                        (gpu_images.get(image), gpu_images.get(palette))
+                    else {
+                        // The image or palette isn't loaded yet
+                        continue;
+                    };
+                    image_bind_groups
+                        .values
+                        .entry((image, palette))
+                        .or_insert_with(|| {
+                            render_device.create_bind_group(
+                                "ui_gradient_image_bind_group",
+                                &gradients_pipeline.image_layout,
+                                &BindGroupEntries::sequential((
+                                    &gpu_image.texture_view,
+                                    &gpu_image.sampler,
+                                    &gpu_palette.texture_view,
+                                )),
+                            )
+                        });
+
                     *item.batch_range_mut() = item_index as u32..item_index as u32 + 1;
                     let uinode_rect = gradient.rect;
 
@@ -710,63 +2332,34 @@ pub fn prepare_gradient(
                         .map(|pos| (gradient.transform * (pos * rect_size).extend(1.)).xyz());
                     let corner_points = QUAD_VERTEX_POSITIONS.map(|pos| pos.xy() * rect_size.xy());
 
-                    // Calculate the effect of clipping
-                    // Note: this won't work with rotation/scaling, but that's much more complex (may need more that 2 quads)
-                    let positions_diff = if let Some(clip) = gradient.clip {
-                        [
-                            Vec2::new(
-                                f32::max(clip.min.x - positions[0].x, 0.),
-                                f32::max(clip.min.y - positions[0].y, 0.),
-                            ),
-                            Vec2::new(
-                                f32::min(clip.max.x - positions[1].x, 0.),
-                                f32::max(clip.min.y - positions[1].y, 0.),
-                            ),
-                            Vec2::new(
-                                f32::min(clip.max.x - positions[2].x, 0.),
-                                f32::min(clip.max.y - positions[2].y, 0.),
-                            ),
-                            Vec2::new(
-                                f32::max(clip.min.x - positions[3].x, 0.),
-                                f32::min(clip.max.y - positions[3].y, 0.),
-                            ),
-                        ]
-                    } else {
-                        [Vec2::ZERO; 4]
-                    };
-
-                    let positions_clipped = [
-                        positions[0] + positions_diff[0].extend(0.),
-                        positions[1] + positions_diff[1].extend(0.),
-                        positions[2] + positions_diff[2].extend(0.),
-                        positions[3] + positions_diff[3].extend(0.),
-                    ];
-
-                    let points = [
-                        corner_points[0] + positions_diff[0],
-                        corner_points[1] + positions_diff[1],
-                        corner_points[2] + positions_diff[2],
-                        corner_points[3] + positions_diff[3],
-                    ];
-
-                    let transformed_rect_size = gradient.transform.transform_vector3(rect_size);
-
-                    // Don't try to cull nodes that have a rotation
-                    // In a rotation around the Z-axis, this value is 0.0 for an angle of 0.0 or π
-                    // In those two cases, the culling check can proceed normally as corners will be on
-                    // horizontal / vertical lines
-                    // For all other angles, bypass the culling check
-                    // This does not properly handles all rotations on all axis
-                    if gradient.transform.x_axis[1] == 0.0 {
+                    // Clip the node's quad to its clipping rect. Rotated and scaled nodes can be
+                    // clipped to polygons with up to eight corners, which are drawn as triangle fans.
+                    polygon.clear();
+                    polygon.extend(positions.into_iter().zip(corner_points));
+                    if let Some(clip) = gradient.clip {
+                        clip_polygon(&mut polygon, &mut clip_scratch, clip);
                         // Cull nodes that are completely clipped
-                        if positions_diff[0].x - positions_diff[1].x >= transformed_rect_size.x
-                            || positions_diff[1].y - positions_diff[2].y >= transformed_rect_size.y
-                        {
+                        if polygon.len() < 3 {
                             continue;
                         }
                     }
 
-                    let uvs = { [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y] };
+                    // Image uvs for masked images and textures, adjusted for clipping
+                    let uv_at = |point: Vec2| {
+                        if let Some((_, _, Some(tile_size))) = gradient.texture {
+                            return (point + 0.5 * rect_size.xy()) / tile_size;
+                        }
+                        let mut uv = point / rect_size.xy() + 0.5;
+                        if let Some(mask) = gradient.mask.as_ref() {
+                            if mask.flip_x {
+                                uv.x = 1. - uv.x;
+                            }
+                            if mask.flip_y {
+                                uv.y = 1. - uv.y;
+                            }
+                        }
+                        uv
+                    };
 
                     let mut flags = if gradient.node_type == NodeType::Border {
                         shader_flags::BORDER
@@ -774,30 +2367,166 @@ pub fn prepare_gradient(
                         0
                     };
 
+                    let mask_color = gradient.mask.as_ref().map(|mask| {
+                        flags |= gradient_shader_flags::MASK;
+                        mask.color.to_f32_array().map(f32::to_bits)
+                    });
+
                     let (g_start, g_dir, g_flags) = match gradient.resolved_gradient {
-                        ResolvedGradient::Linear { angle } => {
-                            let corner_index = (angle - FRAC_PI_2).rem_euclid(TAU) / FRAC_PI_2;
-                            (
-                                corner_points[corner_index as usize].into(),
-                                // CSS angles increase in a clockwise direction
-                                [sin(angle), -cos(angle)],
-                                0,
-                            )
-                        }
-                        ResolvedGradient::Conic { center, start } => {
-                            (center.into(), [start, 0.], gradient_shader_flags::CONIC)
-                        }
-                        ResolvedGradient::Radial { center, size } => (
-                            center.into(),
-                            Vec2::splat(if size.y != 0. { size.x / size.y } else { 1. }).into(),
+                        ResolvedGradient::Linear { start, dir } => (start, dir, 0),
+                        ResolvedGradient::Conic {
+                            center,
+                            start,
+                            aspect_ratio,
+                            ..
+                        } => (
+                            center,
+                            Vec2::new(start, aspect_ratio),
+                            gradient_shader_flags::CONIC,
+                        ),
+                        ResolvedGradient::Radial { center, size, .. } => (
+                            center,
+                            Vec2::new(if size.y != 0. { size.x / size.y } else { 1. }, size.x),
                             gradient_shader_flags::RADIAL,
                         ),
+                        ResolvedGradient::Inset { center, size } => {
+                            (center, size, gradient_shader_flags::INSET)
+                        }
+                        ResolvedGradient::Plasma { scale, speed } => (
+                            Vec2::new(scale, speed),
+                            Vec2::ZERO,
+                            gradient_shader_flags::PLASMA,
+                        ),
+                        ResolvedGradient::Glow { spread } => (
+                            Vec2::new(spread, 0.),
+                            Vec2::ZERO,
+                            gradient_shader_flags::GLOW,
+                        ),
+                        ResolvedGradient::Lut { length, .. } => (
+                            Vec2::ZERO,
+                            Vec2::new(length, 0.),
+                            gradient_shader_flags::LUT,
+                        ),
                     };
+                    let g_line = [g_start.x, g_start.y, g_dir.x, g_dir.y];
 
                     flags |= g_flags;
 
+                    let filter = match gradient.filter {
+                        Some(filter) => {
+                            flags |= gradient_shader_flags::FILTER;
+                            [
+                                filter.hue_rotate.to_radians(),
+                                filter.saturate,
+                                filter.grayscale,
+                                filter.brightness,
+                            ]
+                        }
+                        None => [0., 1., 0., 1.],
+                    };
+
+                    // The grain's third component holds its pattern and animation flags, plus `2.` if the palette is
+                    // dithered, and `16.`, `32.` or `64.` if a texture modulates the alpha, displaces the distance or
+                    // multiplies the colors. Segments with vertex colors add `128.`.
+                    let mut grain = gradient.grain;
+                    if let Some((_, dither)) = gradient.palette {
+                        flags |= gradient_shader_flags::PALETTE;
+                        if dither {
+                            grain.z += 2.;
+                        }
+                    }
+
+                    // The texture's mode is stored in the grain's flags and its amount in the second flags
+                    let texture_amount = match gradient.texture {
+                        Some((_, TextureModulation::Alpha(strength), _)) => {
+                            grain.z += 16.;
+                            strength.to_bits()
+                        }
+                        Some((_, TextureModulation::Displace(amount), _)) => {
+                            grain.z += 32.;
+                            amount.to_bits()
+                        }
+                        Some((_, TextureModulation::Multiply, _)) => {
+                            grain.z += 64.;
+                            0
+                        }
+                        None => 0,
+                    };
+
+                    if let Some(id) = gradient.custom_function {
+                        flags |= gradient_shader_flags::CUSTOM_FUNCTION
+                            | (id & 0xfff) << gradient_shader_flags::CUSTOM_FUNCTION_ID_SHIFT;
+                    }
+
+                    // Repeated and reflected gradients are drawn within the period between the
+                    // first and last stops, which the shader wraps the distance into
+                    let period = [
+                        extracted_color_stops.0[gradient.stops_range.start].1,
+                        extracted_color_stops.0[gradient.stops_range.end - 1].1,
+                    ];
+                    // Gradients with a zero length period can't repeat and are padded instead
+                    let pad = match gradient.spread {
+                        _ if period[1] <= period[0] => true,
+                        SpreadMethod::Pad => true,
+                        SpreadMethod::Repeat => {
+                            flags |= gradient_shader_flags::SPREAD_REPEAT;
+                            false
+                        }
+                        SpreadMethod::Reflect => {
+                            flags |= gradient_shader_flags::SPREAD_REFLECT;
+                            false
+                        }
+                    };
+
+                    // The shape parameters that don't fit in the gradient line are passed with the
+                    // positions and texture coordinates
+                    let shape = match gradient.resolved_gradient {
+                        ResolvedGradient::Conic {
+                            sweep,
+                            inner_radius,
+                            outer_radius,
+                            ..
+                        } => Vec3::new(sweep, inner_radius, outer_radius),
+                        ResolvedGradient::Radial { falloff, .. } => Vec3::new(0., falloff, 0.),
+                        _ => Vec3::ZERO,
+                    };
+
+                    // Tiled gradients are resolved relative to the center of a tile.
+                    // Axes that don't repeat have a zero period in the shader.
+                    let (tile_start, tile_size, tile_period) = match gradient.tiling {
+                        Some(tiling) => {
+                            flags |= gradient_shader_flags::TILED;
+                            (
+                                tiling.start,
+                                tiling.size,
+                                Vec2::select(
+                                    tiling.period.is_finite_mask(),
+                                    tiling.period,
+                                    Vec2::ZERO,
+                                ),
+                            )
+                        }
+                        None => (Vec2::ZERO, Vec2::ZERO, Vec2::ZERO),
+                    };
+
+                    // At low quality, untiled linear gradients whose distances aren't changed per fragment
+                    // have each segment drawn over its band of the node, with its colors interpolated between
+                    // the band's corners. Low quality mixes colors linearly and ignores hints, so this matches
+                    // the per-fragment colors without drawing every segment over the whole node.
+                    let vertex_colors = quality == GradientQuality::Low
+                        && pad
+                        && matches!(gradient.resolved_gradient, ResolvedGradient::Linear { .. })
+                        && gradient.tiling.is_none()
+                        && gradient.custom_function.is_none()
+                        && gradient.animation.xy() == Vec2::ZERO
+                        && !matches!(
+                            gradient.texture,
+                            Some((_, TextureModulation::Displace(_), _))
+                        );
+
                     let range = gradient.stops_range.start..gradient.stops_range.end - 1;
                     let mut segment_count = 0;
+                    let mut vertices_count = 0;
 
                     for stop_index in range {
                         let mut start_stop = extracted_color_stops.0[stop_index];
@@ -813,21 +2542,79 @@ pub fn prepare_gradient(
                         }
                         let start_color = start_stop.0.to_f32_array();
                         let end_color = end_stop.0.to_f32_array();
+                        let [interpolation, interpolation_parameter] = start_stop.3.pack();
                         let mut stop_flags = flags;
-                        if 0. < start_stop.1
-                            && (stop_index == gradient.stops_range.start || segment_count == 0)
-                        {
+                        if pad && (stop_index == gradient.stops_range.start || segment_count == 0) {
                             stop_flags |= gradient_shader_flags::FILL_START;
                         }
-                        if stop_index == gradient.stops_range.end - 2 {
+                        if pad && stop_index == gradient.stops_range.end - 2 {
                             stop_flags |= gradient_shader_flags::FILL_END;
                         }
+                        let mask_or_oscillation = mask_color.unwrap_or_else(|| {
+                            let [start, end] = [start_stop.4, end_stop.4];
+                            if start.position != 0.
+                                || start.brightness != 0.
+                                || end.position != 0.
+                                || end.brightness != 0.
+                            {
+                                stop_flags |= gradient_shader_flags::OSCILLATE;
+                            }
+                            [
+                                pack_half2(start.position, start.brightness),
+                                pack_half2(start.frequency, start.phase),
+                                pack_half2(end.position, end.brightness),
+                                pack_half2(end.frequency, end.phase),
+                            ]
+                        });
 
-                        for i in 0..4 {
+                        // Segments between stops at the same distance are hard edges, which are drawn per fragment
+                        let use_vertex_colors = vertex_colors
+                            && start_stop.1 < end_stop.1
+                            && stop_flags & gradient_shader_flags::OSCILLATE == 0;
+                        let segment_polygon = if use_vertex_colors {
+                            band.clear();
+                            band.extend_from_slice(&polygon);
+                            let distance = |(_, point): (Vec3, Vec2)| (point - g_start).dot(g_dir);
+                            if stop_flags & gradient_shader_flags::FILL_START == 0 {
+                                clip_polygon_to_half_plane(
+                                    &mut band,
+                                    &mut clip_scratch,
+                                    |corner| distance(corner) - start_stop.1,
+                                );
+                            }
+                            if stop_flags & gradient_shader_flags::FILL_END == 0 {
+                                clip_polygon_to_half_plane(
+                                    &mut band,
+                                    &mut clip_scratch,
+                                    |corner| end_stop.1 - distance(corner),
+                                );
+                            }
+                            &band
+                        } else {
+                            &polygon
+                        };
+                        if segment_polygon.len() < 3 {
+                            // The band lies outside the node
+                            segment_count += 1;
+                            continue;
+                        }
+
+                        for &(position, point) in segment_polygon {
+                            let uv = uv_at(point);
+                            let (start_color, end_color, grain) = if use_vertex_colors {
+                                let t = ((point - g_start).dot(g_dir) - start_stop.1)
+                                    / (end_stop.1 - start_stop.1);
+                                let color = start_stop.0.mix(&end_stop.0, t.clamp(0., 1.));
+                                let mut grain = grain;
+                                grain.z += 128.;
+                                (color.to_f32_array(), color.to_f32_array(), grain)
+                            } else {
+                                (start_color, end_color, grain)
+                            };
                             ui_meta.vertices.push(UiGradientVertex {
-                                position: positions_clipped[i].into(),
-                                uv: uvs[i].into(),
-                                flags: stop_flags | shader_flags::CORNERS[i],
+                                position: position.extend(shape.z).into(),
+                                uv: [uv.x, uv.y, shape.x, shape.y],
+                                flags: [stop_flags, texture_amount],
                                 radius: [
                                     gradient.border_radius.top_left,
                                     gradient.border_radius.top_right,
@@ -840,32 +2627,44 @@ pub fn prepare_gradient(
                                     gradient.border.right,
                                     gradient.border.bottom,
                                 ],
-                                size: rect_size.xy().into(),
-                                g_start,
-                                g_dir,
-                                point: points[i].into(),
+                                size: [rect_size.x, rect_size.y, tile_size.x, tile_size.y],
+                                g_line,
+                                point: [
+                                    point.x,
+                                    point.y,
+                                    point.x - tile_start.x,
+                                    point.y - tile_start.y,
+                                ],
                                 start_color,
-                                start_len: start_stop.1,
-                                end_len: end_stop.1,
+                                segment: [start_stop.1, end_stop.1, start_stop.2, interpolation],
                                 end_color,
-                                hint: start_stop.2,
+                                mask_or_oscillation,
+                                animation: gradient.animation.to_array(),
+                                grain: grain.extend(interpolation_parameter).to_array(),
+                                spread: [period[0], period[1], tile_period.x, tile_period.y],
+                                filter,
                             });
                         }
 
-                        for &i in &QUAD_INDICES {
-                            ui_meta.indices.push(indices_index + i as u32);
+                        for i in 1..segment_polygon.len() as u32 - 1 {
+                            ui_meta.indices.extend([
+                                indices_index,
+                                indices_index + i,
+                                indices_index + i + 1,
+                            ]);
                         }
-                        indices_index += 4;
+                        indices_index += segment_polygon.len() as u32;
+                        vertices_count += 3 * (segment_polygon.len() as u32 - 2);
                         segment_count += 1;
                     }
 
-                    if 0 < segment_count {
-                        let vertices_count = 6 * segment_count;
-
+                    if 0 < vertices_count {
                         batches.push((
                             item.entity(),
                             GradientBatch {
                                 range: vertices_index..(vertices_index + vertices_count),
+                                image,
+                                palette,
                             },
                         ));
 
@@ -874,16 +2673,41 @@ pub fn prepare_gradient(
                 }
             }
         }
+        drop(prepare_span);
+
+        let write_span = info_span!(
+            "write_gradient_buffers",
+            vertices = ui_meta.vertices.len(),
+            indices = ui_meta.indices.len()
+        )
+        .entered();
         ui_meta.vertices.write_buffer(&render_device, &render_queue);
         ui_meta.indices.write_buffer(&render_device, &render_queue);
+        drop(write_span);
         *previous_len = batches.len();
+        if let Some(stats) = stats {
+            stats.record(
+                extracted_gradients.items.len(),
+                extracted_color_stops.0.len(),
+                batches.len(),
+                ui_meta.vertices.len() * size_of::<UiGradientVertex>()
+                    + ui_meta.indices.len() * size_of::<u32>(),
+            );
+        }
         commands.try_insert_batch(batches);
     }
     extracted_gradients.items.clear();
+    extracted_gradients.image_nodes.clear();
+    extracted_gradients.masked_images.clear();
     extracted_color_stops.0.clear();
 }
 
-pub type DrawGradientFns = (SetItemPipeline, SetGradientViewBindGroup<0>, DrawGradient);
+pub type DrawGradientFns = (
+    SetItemPipeline,
+    SetGradientViewBindGroup<0>,
+    SetGradientTextureBindGroup<1>,
+    DrawGradient,
+);
 
 pub struct SetGradientViewBindGroup<const I: usize>;
 impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetGradientViewBindGroup<I> {
@@ -906,6 +2730,33 @@ impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetGradientViewBindGroup
     }
 }
 
+pub struct SetGradientTextureBindGroup<const I: usize>;
+impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetGradientTextureBindGroup<I> {
+    type Param = SRes<GradientImageBindGroups>;
+    type ViewQuery = ();
+    type ItemQuery = Read<GradientBatch>;
+
+    #[inline]
+    fn render<'w>(
+        _item: &P,
+        _view: (),
+        batch: Option<&'w GradientBatch>,
+        image_bind_groups: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let image_bind_groups = image_bind_groups.into_inner();
+        let Some(batch) = batch else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(image_bind_group) = image_bind_groups.values.get(&(batch.image, batch.palette))
+        else {
+            return RenderCommandResult::Failure("image bind group not available");
+        };
+        pass.set_bind_group(I, image_bind_group, &[]);
+        RenderCommandResult::Success
+    }
+}
+
 pub struct DrawGradient;
 impl<P: PhaseItem> RenderCommand<P> for DrawGradient {
     type Param = SRes<GradientMeta>;
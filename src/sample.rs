@@ -0,0 +1,710 @@
+use crate::render::{
+    compute_angular_color_stops, compute_color_stops, resolve_linear_gradient_line,
+};
+use crate::*;
+use bevy::{
+    color::LinearRgba,
+    ecs::{
+        entity::Entity,
+        system::{Query, Res, SystemParam},
+    },
+    math::{
+        Vec4,
+        ops::{atan2, cos, powf, sin},
+    },
+    sprite::BorderRect,
+    transform::components::GlobalTransform,
+    ui::{ComputedNode, ComputedNodeTarget, ResolvedBorderRadius, UiScale},
+};
+use core::f32::consts::PI;
+
+/// The geometry of a gradient resolved for a node of a particular size.
+/// All points are in physical pixels, relative to the center of the node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhysicalGradientShape {
+    Linear {
+        /// The start of the gradient line
+        start: Vec2,
+        /// The direction of the gradient line
+        dir: Vec2,
+    },
+    Radial {
+        center: Vec2,
+        /// The ratio of the width of the end shape to its height
+        ratio: f32,
+        /// The horizontal radius of the end shape
+        radius: f32,
+        /// The exponent of the falloff from the center
+        falloff: f32,
+    },
+    Conic {
+        center: Vec2,
+        /// The starting angle
+        start: f32,
+        /// The ratio of the width to the height of the ellipse the angular sweep follows
+        aspect_ratio: f32,
+        /// The angle the full turn of the stops is mapped onto, negative counter-clockwise
+        sweep: f32,
+        /// The gradient is only drawn between the inner and outer radii, an outer radius of zero is unbounded
+        inner_radius: f32,
+        outer_radius: f32,
+    },
+    Inset {
+        /// The center of the box the gradient is measured inward from
+        center: Vec2,
+        size: Vec2,
+        /// The corner radii of the box
+        radius: ResolvedBorderRadius,
+    },
+    Plasma {
+        /// The wavelength of the plasma's waves
+        scale: f32,
+    },
+}
+
+/// A [`GradientTiling`] resolved for a node of a particular size.
+/// All values are in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhysicalTiling {
+    /// The top-left corner of the first tile, relative to the center of the node
+    pub start: Vec2,
+    /// The size of a tile
+    pub size: Vec2,
+    /// The distance between the starts of adjacent tiles. Infinite on axes that don't repeat.
+    pub period: Vec2,
+}
+
+impl PhysicalTiling {
+    /// Maps `point`, relative to the center of the node, to a point relative to the center of the tile it's in.
+    /// Returns `None` if the point isn't inside a tile.
+    pub fn tile_point(&self, point: Vec2) -> Option<Vec2> {
+        let q = point - self.start;
+        let wrap = |q: f32, period: f32| {
+            if period.is_finite() {
+                q.rem_euclid(period)
+            } else {
+                q
+            }
+        };
+        let local = Vec2::new(wrap(q.x, self.period.x), wrap(q.y, self.period.y));
+        (local.cmpge(Vec2::ZERO).all() && local.cmplt(self.size).all())
+            .then(|| local - 0.5 * self.size)
+    }
+}
+
+/// A gradient resolved for a node of a particular size, used to sample the gradient's colors on the CPU.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhysicalGradient {
+    pub shape: PhysicalGradientShape,
+    /// Resolved color stops: color, distance along the gradient line, hint and the interpolation to the following stop
+    pub stops: Vec<(LinearRgba, f32, f32, Interpolation, StopOscillation)>,
+    /// How the areas before the first stop and after the last stop are filled
+    pub spread: SpreadMethod,
+    /// If set, the gradient's geometry is relative to the center of a tile and it's repeated across the node
+    pub tiling: Option<PhysicalTiling>,
+    /// Color filters applied to the interpolated colors
+    pub filter: Option<GradientFilter>,
+}
+
+impl PhysicalGradient {
+    /// Resolve a gradient for a node with the given physical size.
+    /// `scale_factor` is the node's target scale factor, including `UiScale`.
+    /// The gradient's origin box and tiling are ignored, use [`PhysicalGradient::for_node`] to resolve them for a node.
+    pub fn new(
+        gradient: &Gradient,
+        scale_factor: f32,
+        physical_size: Vec2,
+        physical_target_size: Vec2,
+    ) -> Self {
+        let mut scratch = vec![];
+        let mut stops = vec![];
+        let shape = match gradient {
+            Gradient::Linear(gradient) => {
+                let (start, dir, length) = resolve_linear_gradient_line(
+                    gradient,
+                    scale_factor,
+                    physical_size,
+                    physical_target_size,
+                );
+                compute_color_stops(
+                    &gradient.stops,
+                    scale_factor,
+                    length,
+                    physical_target_size,
+                    &mut scratch,
+                    &mut stops,
+                );
+                PhysicalGradientShape::Linear { start, dir }
+            }
+            Gradient::Radial(RadialGradient {
+                position,
+                shape,
+                falloff,
+                stops: s,
+                ..
+            }) => {
+                let center = position.resolve(scale_factor, physical_size, physical_target_size);
+                let size = shape.resolve(center, scale_factor, physical_size, physical_target_size);
+                compute_color_stops(
+                    s,
+                    scale_factor,
+                    size.x,
+                    physical_target_size,
+                    &mut scratch,
+                    &mut stops,
+                );
+                PhysicalGradientShape::Radial {
+                    center,
+                    ratio: if size.y != 0. { size.x / size.y } else { 1. },
+                    radius: size.x,
+                    falloff: *falloff,
+                }
+            }
+            Gradient::Conic(
+                conic @ ConicGradient {
+                    start,
+                    position,
+                    aspect_ratio,
+                    stops: s,
+                    ..
+                },
+            ) => {
+                compute_angular_color_stops(s, &mut scratch, &mut stops);
+                let resolve_ring_radius = |radius: Val| {
+                    scale_val(radius, scale_factor)
+                        .resolve(0.5 * physical_size.min_element(), physical_target_size)
+                        .unwrap_or(0.)
+                        .max(0.)
+                };
+                PhysicalGradientShape::Conic {
+                    center: position.resolve(scale_factor, physical_size, physical_target_size),
+                    start: start.to_radians(),
+                    aspect_ratio: *aspect_ratio,
+                    sweep: conic.sweep(),
+                    inner_radius: resolve_ring_radius(conic.inner_radius),
+                    outer_radius: resolve_ring_radius(conic.outer_radius),
+                }
+            }
+            Gradient::Inset(gradient) => {
+                compute_color_stops(
+                    &gradient.stops,
+                    scale_factor,
+                    0.5 * physical_size.min_element(),
+                    physical_target_size,
+                    &mut scratch,
+                    &mut stops,
+                );
+                PhysicalGradientShape::Inset {
+                    center: Vec2::ZERO,
+                    size: physical_size,
+                    radius: ResolvedBorderRadius::ZERO,
+                }
+            }
+            Gradient::Plasma(gradient) => {
+                let scale = (gradient.scale * scale_factor).max(1.);
+                compute_color_stops(
+                    &gradient.stops,
+                    scale_factor,
+                    scale,
+                    physical_target_size,
+                    &mut scratch,
+                    &mut stops,
+                );
+                PhysicalGradientShape::Plasma { scale }
+            }
+        };
+        gradient
+            .layer()
+            .smoothing
+            .smooth_stops(&mut stops, 0, SMOOTHING_SUBDIVISIONS);
+        Self {
+            shape,
+            stops,
+            spread: gradient.layer().spread,
+            tiling: None,
+            filter: gradient.layer().filter,
+        }
+    }
+
+    /// Resolve a gradient for `node`, against the gradient's origin box, attachment and tiling.
+    pub fn for_node(
+        gradient: &Gradient,
+        node: &ComputedNode,
+        target: &ComputedNodeTarget,
+        transform: &GlobalTransform,
+        group: Option<&GradientGroupBounds>,
+    ) -> Self {
+        let target_size = target.physical_size().as_vec2();
+        let (box_size, offset) =
+            gradient.resolve_box(node, transform.translation().truncate(), group, target_size);
+        let tiling = gradient
+            .layer()
+            .tiling
+            .map(|tiling| tiling.resolve(box_size, offset, target.scale_factor(), target_size));
+        let size = tiling.map(|tiling| tiling.size).unwrap_or(box_size);
+        let mut physical_gradient = Self::new(gradient, target.scale_factor(), size, target_size);
+        if tiling.is_none() {
+            physical_gradient.translate(offset);
+            if let PhysicalGradientShape::Inset { size, radius, .. } = &mut physical_gradient.shape
+            {
+                // Round the box like the node's corners at the same inset, if it's inside the node
+                let margin = 0.5 * (node.size() - *size);
+                let inset = BorderRect {
+                    left: margin.x + offset.x,
+                    right: margin.x - offset.x,
+                    top: margin.y + offset.y,
+                    bottom: margin.y - offset.y,
+                };
+                if 0. <= inset.left.min(inset.right).min(inset.top).min(inset.bottom) {
+                    *radius = inset_radius(node.border_radius(), inset, *size);
+                }
+            }
+        }
+        physical_gradient.tiling = tiling;
+        physical_gradient
+    }
+
+    /// Moves the gradient's geometry by `offset`
+    pub fn translate(&mut self, offset: Vec2) {
+        match &mut self.shape {
+            PhysicalGradientShape::Linear { start: point, .. }
+            | PhysicalGradientShape::Radial { center: point, .. }
+            | PhysicalGradientShape::Conic { center: point, .. }
+            | PhysicalGradientShape::Inset { center: point, .. } => *point += offset,
+            // The plasma has no origin
+            PhysicalGradientShape::Plasma { .. } => {}
+        }
+    }
+
+    /// Distance in gradient space from the start of the gradient to `point`
+    pub fn distance(&self, point: Vec2) -> f32 {
+        match self.shape {
+            PhysicalGradientShape::Linear { start, dir } => (point - start).dot(dir),
+            PhysicalGradientShape::Radial {
+                center,
+                ratio,
+                radius,
+                falloff,
+            } => {
+                let d = point - center;
+                radial_falloff(Vec2::new(d.x, d.y * ratio).length(), radius, falloff)
+            }
+            PhysicalGradientShape::Conic {
+                center,
+                start,
+                aspect_ratio,
+                sweep,
+                ..
+            } => {
+                let d = point - center;
+                let d = Vec2::new(d.x, d.y * aspect_ratio);
+                let angle = atan2(-d.x, d.y) + PI;
+                sweep_distance((angle - start).rem_euclid(TAU), sweep)
+            }
+            PhysicalGradientShape::Inset {
+                center,
+                size,
+                radius,
+            } => -sd_rounded_box(point - center, size, radius),
+            PhysicalGradientShape::Plasma { scale } => plasma(point, scale, 0.) * scale,
+        }
+    }
+
+    /// Wraps `distance` into the period between the first and last stops for repeated and reflected gradients.
+    /// See `spread_distance` in `gradient.wgsl`.
+    fn spread_distance(&self, distance: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return distance;
+        };
+        let length = last.1 - first.1;
+        if length <= 0. {
+            return distance;
+        }
+        let d = distance - first.1;
+        match self.spread {
+            SpreadMethod::Pad => distance,
+            SpreadMethod::Repeat => first.1 + d.rem_euclid(length),
+            SpreadMethod::Reflect => first.1 + length - (d.rem_euclid(2. * length) - length).abs(),
+        }
+    }
+
+    /// The color of the gradient at `point`, in physical pixels relative to the center of the node.
+    /// Points between tiles are transparent.
+    /// The areas before the first stop and after the last stop are filled according to the gradient's [`SpreadMethod`].
+    pub fn color_at(&self, point: Vec2) -> LinearRgba {
+        let point = match self.tiling {
+            Some(tiling) => match tiling.tile_point(point) {
+                Some(point) => point,
+                None => return LinearRgba::NONE,
+            },
+            None => point,
+        };
+        if let PhysicalGradientShape::Conic {
+            center,
+            aspect_ratio,
+            inner_radius,
+            outer_radius,
+            ..
+        } = self.shape
+        {
+            // Outside of the ring, see `ring_coverage` in `gradient.wgsl`
+            let d = point - center;
+            let r = Vec2::new(d.x, d.y * aspect_ratio).length();
+            if r < inner_radius || (0. < outer_radius && outer_radius < r) {
+                return LinearRgba::NONE;
+            }
+        }
+        let color = interpolate_stops(&self.stops, self.spread_distance(self.distance(point)));
+        match self.filter {
+            Some(filter) => filter.apply(color),
+            None => color,
+        }
+    }
+}
+
+/// Finds the color at `distance` along a list of sorted, resolved color stops.
+pub(crate) fn interpolate_stops(
+    stops: &[(LinearRgba, f32, f32, Interpolation, StopOscillation)],
+    distance: f32,
+) -> LinearRgba {
+    let Some(first) = stops.first() else {
+        return LinearRgba::NONE;
+    };
+
+    if distance <= first.1 {
+        return first.0;
+    }
+
+    for window in stops.windows(2) {
+        let (start_color, start, hint, interpolation, _) = window[0];
+        let (end_color, end, ..) = window[1];
+        if distance < end {
+            if start == end {
+                return end_color;
+            }
+            let mut t = (distance - start) / (end - start);
+            if t < hint {
+                t = 0.5 * t / hint;
+            } else {
+                t = 0.5 * (1. + (t - hint) / (1. - hint));
+            }
+            let t = interpolation.sample(t);
+            return mix_linear_rgb_in_srgb_space(start_color, end_color, t);
+        }
+    }
+
+    stops[stops.len() - 1].0
+}
+
+/// Mixes two linear colors in (approximate) sRGB space, matching the gradient shader.
+pub(crate) fn mix_linear_rgb_in_srgb_space(a: LinearRgba, b: LinearRgba, t: f32) -> LinearRgba {
+    let a_srgb = Vec4::new(a.red, a.green, a.blue, 0.).powf(1. / 2.2);
+    let b_srgb = Vec4::new(b.red, b.green, b.blue, 0.).powf(1. / 2.2);
+    let mixed = a_srgb.lerp(b_srgb, t).powf(2.2);
+    LinearRgba::new(mixed.x, mixed.y, mixed.z, a.alpha + (b.alpha - a.alpha) * t)
+}
+
+/// Draws `src` over `dst` with standard alpha blending.
+pub fn blend_over(dst: LinearRgba, src: LinearRgba) -> LinearRgba {
+    let alpha = src.alpha + dst.alpha * (1. - src.alpha);
+    if alpha <= 0. {
+        return LinearRgba::NONE;
+    }
+    let blend = |s: f32, d: f32| (s * src.alpha + d * dst.alpha * (1. - src.alpha)) / alpha;
+    LinearRgba::new(
+        blend(src.red, dst.red),
+        blend(src.green, dst.green),
+        blend(src.blue, dst.blue),
+        alpha,
+    )
+}
+
+impl GradientBlendMode {
+    /// Composites `src` over `dst` with the blend mode, like the blend state of the gradient pipeline
+    pub fn blend(self, dst: LinearRgba, src: LinearRgba) -> LinearRgba {
+        // The GPU blends into a render target holding colors premultiplied by their alpha
+        let d = Vec3::new(dst.red, dst.green, dst.blue) * dst.alpha;
+        let s = Vec3::new(src.red, src.green, src.blue);
+        let over_alpha = src.alpha + dst.alpha * (1. - src.alpha);
+        let (color, alpha) = match self {
+            GradientBlendMode::Normal => return blend_over(dst, src),
+            GradientBlendMode::Multiply => (d * Vec3::ONE.lerp(s, src.alpha), dst.alpha),
+            GradientBlendMode::Screen => {
+                let s = s * src.alpha;
+                (s + d * (Vec3::ONE - s), over_alpha)
+            }
+            GradientBlendMode::Add => (d + s * src.alpha, over_alpha),
+        };
+        if alpha <= 0. {
+            return LinearRgba::NONE;
+        }
+        let color = color / alpha;
+        LinearRgba::new(color.x, color.y, color.z, alpha)
+    }
+}
+
+// See `plasma` in `gradient.wgsl`
+fn plasma(point: Vec2, scale: f32, time: f32) -> f32 {
+    let p = TAU * point / scale;
+    let c = p + 2. * Vec2::new(sin(0.2 * time), cos(0.3 * time));
+    let v = sin(p.x + time)
+        + sin(0.5 * (p.y + time))
+        + sin(0.5 * (p.x + p.y + time))
+        + sin(c.length() + time);
+    0.5 + 0.125 * v
+}
+
+// See `sd_rounded_box` in `gradient.wgsl`
+fn sd_rounded_box(point: Vec2, size: Vec2, radius: ResolvedBorderRadius) -> f32 {
+    let radius = match (0. < point.x, 0. < point.y) {
+        (false, false) => radius.top_left,
+        (true, false) => radius.top_right,
+        (true, true) => radius.bottom_right,
+        (false, true) => radius.bottom_left,
+    };
+    let corner_to_point = point.abs() - 0.5 * size;
+    let q = corner_to_point + radius;
+    let l = q.max(Vec2::ZERO).length();
+    let m = q.x.max(q.y).min(0.);
+    l + m - radius
+}
+
+/// The point half a pixel inside a node's rounded rect, on the ray from the node's center in `direction`
+fn edge_point(size: Vec2, radius: ResolvedBorderRadius, direction: Vec2) -> Vec2 {
+    let Some(direction) = direction.try_normalize() else {
+        return Vec2::ZERO;
+    };
+    // The distance along the ray to the edge of the node's rect, ignoring its corner radii
+    let mut t = (0.5 * size / direction.abs()).min_element();
+    // Step inwards past the rounded corners. The signed distance is never longer than the distance
+    // along the ray, so the steps don't overshoot.
+    for _ in 0..8 {
+        let distance = sd_rounded_box(t * direction, size, radius) + 0.5;
+        if distance <= 0. {
+            break;
+        }
+        t -= distance;
+    }
+    t.max(0.) * direction
+}
+
+// See `sd_inset_rounded_box` in `gradient.wgsl`
+fn sd_inset_rounded_box(
+    point: Vec2,
+    size: Vec2,
+    radius: ResolvedBorderRadius,
+    inset: BorderRect,
+) -> f32 {
+    let inner_size = size - Vec2::new(inset.left + inset.right, inset.top + inset.bottom);
+    let inner_center = Vec2::new(inset.left, inset.top) + 0.5 * inner_size - 0.5 * size;
+    let inner_point = point - inner_center;
+    sd_rounded_box(
+        inner_point,
+        inner_size,
+        inset_radius(radius, inset, inner_size),
+    )
+}
+
+// The corner radii of a box inset from a rounded box, see `sd_inset_rounded_box` in `gradient.wgsl`
+fn inset_radius(
+    radius: ResolvedBorderRadius,
+    inset: BorderRect,
+    inner_size: Vec2,
+) -> ResolvedBorderRadius {
+    let min_size = (0.5 * inner_size).min_element();
+    let r = |r: f32, a: f32, b: f32| (r - a.max(b)).max(0.).min(min_size);
+    ResolvedBorderRadius {
+        top_left: r(radius.top_left, inset.left, inset.top),
+        top_right: r(radius.top_right, inset.right, inset.top),
+        bottom_right: r(radius.bottom_right, inset.right, inset.bottom),
+        bottom_left: r(radius.bottom_left, inset.left, inset.bottom),
+    }
+}
+
+/// Composites a stack of gradients, resolved for `node`, at `point`.
+/// Later gradients are drawn over earlier ones.
+/// The node's `transform` places gradients with [`GradientAttachment::Fixed`] in the viewport,
+/// and `group` is the node's [`GradientGroupBounds`] if it's in a [`GradientGroup`].
+pub fn sample_gradients(
+    gradients: &[Gradient],
+    point: Vec2,
+    node: &ComputedNode,
+    target: &ComputedNodeTarget,
+    transform: &GlobalTransform,
+    group: Option<&GradientGroupBounds>,
+) -> LinearRgba {
+    gradients
+        .iter()
+        .filter(|gradient| !gradient.is_empty())
+        .fold(LinearRgba::NONE, |color, gradient| {
+            let layer = PhysicalGradient::for_node(gradient, node, target, transform, group);
+            gradient
+                .layer()
+                .blend_mode
+                .blend(color, layer.color_at(point))
+        })
+}
+
+/// The composited color of a node's background and border gradients at `point`,
+/// in physical pixels relative to the center of the node.
+/// Points outside of the node's rounded rect are transparent.
+/// Background gradients are only drawn inside their clip boxes, border gradients are drawn over them in the border.
+/// `shared` gradients are drawn over the `background` gradients, like the renderer draws them.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "the node's components are passed separately, as they're queried"
+)]
+pub fn sample_node(
+    point: Vec2,
+    node: &ComputedNode,
+    target: &ComputedNodeTarget,
+    transform: &GlobalTransform,
+    group: Option<&GradientGroupBounds>,
+    background: Option<&BackgroundGradient>,
+    shared: Option<&SharedBackgroundGradient>,
+    border: Option<&BorderGradient>,
+) -> LinearRgba {
+    let size = node.size();
+    let radius = node.border_radius();
+    if 0. < sd_rounded_box(point, size, radius) {
+        return LinearRgba::NONE;
+    }
+    let mut color = LinearRgba::NONE;
+    for gradient in background
+        .map(|background| background.0.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .chain(shared.map(|shared| &shared.0[..]).unwrap_or_default())
+        .filter(|gradient| !gradient.is_empty())
+    {
+        let inset = gradient.layer().clip.inset(node.border(), node.padding());
+        if sd_inset_rounded_box(point, size, radius, inset) <= 0. {
+            let layer = PhysicalGradient::for_node(gradient, node, target, transform, group);
+            color = gradient
+                .layer()
+                .blend_mode
+                .blend(color, layer.color_at(point));
+        }
+    }
+    if let Some(border) =
+        border.filter(|_| 0. < sd_inset_rounded_box(point, size, radius, node.border()))
+    {
+        color = blend_over(
+            color,
+            sample_gradients(&border.0, point, node, target, transform, group),
+        );
+    }
+    color
+}
+
+/// Raises the distance from the center of a radial gradient to the power of `falloff`, as a fraction of `radius`.
+/// See `radial_falloff` in `gradient.wgsl`.
+fn radial_falloff(distance: f32, radius: f32, falloff: f32) -> f32 {
+    if falloff == 1. || radius <= 0. {
+        distance
+    } else {
+        radius * powf(distance / radius, falloff)
+    }
+}
+
+/// Maps an angle clockwise from the start of a conic gradient onto its stops.
+/// The last revolution of the sweep that covers the angle is drawn. See `sweep_distance` in `gradient.wgsl`.
+fn sweep_distance(angle: f32, sweep: f32) -> f32 {
+    let angle = if sweep < 0. {
+        (TAU - angle) % TAU
+    } else {
+        angle
+    };
+    let length = sweep.abs();
+    let revolution = (((length - angle) / TAU).ceil() - 1.).max(0.);
+    (angle + revolution * TAU) * TAU / length
+}
+
+impl Gradient {
+    /// The color of the gradient at `point`, for a node with the given physical size.
+    /// `point` is in physical pixels relative to the center of the node.
+    pub fn color_at(
+        &self,
+        point: Vec2,
+        scale_factor: f32,
+        physical_size: Vec2,
+        physical_target_size: Vec2,
+    ) -> Color {
+        PhysicalGradient::new(self, scale_factor, physical_size, physical_target_size)
+            .color_at(point)
+            .into()
+    }
+}
+
+/// A [`SystemParam`] for querying the composited gradient color of a UI node at a point,
+/// for eyedropper tools and effects that react to the color under the cursor.
+#[derive(SystemParam)]
+pub struct GradientSampler<'w, 's> {
+    ui_scale: Res<'w, UiScale>,
+    nodes: Query<'w, 's, SampledNodeData>,
+}
+
+type SampledNodeData = (
+    &'static ComputedNode,
+    &'static ComputedNodeTarget,
+    &'static GlobalTransform,
+    Option<&'static GradientGroupBounds>,
+    Option<&'static BackgroundGradient>,
+    Option<&'static SharedBackgroundGradient>,
+    Option<&'static BorderGradient>,
+);
+
+impl GradientSampler<'_, '_> {
+    /// The size of the node in physical pixels, or `None` if `entity` isn't a UI node
+    pub(crate) fn node_size(&self, entity: Entity) -> Option<Vec2> {
+        self.nodes.get(entity).ok().map(|(node, ..)| node.size())
+    }
+
+    /// The composited color of the node's gradients at `point`, in physical pixels relative to the center of the node.
+    ///
+    /// Returns `None` if `entity` isn't a UI node.
+    pub fn sample_node(&self, entity: Entity, point: Vec2) -> Option<Color> {
+        let (node, target, transform, group, background, shared, border) =
+            self.nodes.get(entity).ok()?;
+        Some(
+            sample_node(
+                point, node, target, transform, group, background, shared, border,
+            )
+            .into(),
+        )
+    }
+
+    /// The composited color of the node's gradients just inside its rounded edge, on the ray from its
+    /// center in `direction`. A zero `direction` samples the center of the node.
+    ///
+    /// Returns `None` if `entity` isn't a UI node.
+    pub fn sample_edge(&self, entity: Entity, direction: Vec2) -> Option<Color> {
+        let (node, ..) = self.nodes.get(entity).ok()?;
+        self.sample_node(
+            entity,
+            edge_point(node.size(), node.border_radius(), direction),
+        )
+    }
+
+    /// The composited color of the node's gradients at `position`, in logical pixels relative to
+    /// the top-left corner of the viewport of the node's camera, the same coordinates as
+    /// `Window::cursor_position` for a camera that fills the window.
+    ///
+    /// Returns `None` if `entity` isn't a UI node.
+    pub fn sample_viewport(&self, entity: Entity, position: Vec2) -> Option<Color> {
+        let (node, target, transform, group, background, shared, border) =
+            self.nodes.get(entity).ok()?;
+        let physical_position = position * target.scale_factor() / self.ui_scale.0;
+        let point = transform
+            .affine()
+            .inverse()
+            .transform_point3(physical_position.extend(0.))
+            .truncate();
+        Some(
+            sample_node(
+                point, node, target, transform, group, background, shared, border,
+            )
+            .into(),
+        )
+    }
+}
@@ -0,0 +1,115 @@
+use crate::*;
+use bevy::{
+    ecs::{change_detection::DetectChangesMut, entity::Entity, system::Query},
+    ui::{BoxShadow, Val},
+};
+
+/// Tints a node's `BoxShadow` with the colors of its gradients, so the shadows of multicolored
+/// panels match them without syncing their colors by hand.
+///
+/// Each shadow's color is set every frame to the node's composited gradient color just inside the
+/// edge or corner the shadow is offset towards. Shadows without an offset take the color at the
+/// center of the node.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ui_gradients::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     Node::default(),
+///     BackgroundGradient::from(LinearGradient::to_right(vec![
+///         Color::srgb(1., 0.3, 0.).into(),
+///         Color::srgb(0., 0.3, 1.).into(),
+///     ])),
+///     BoxShadow::new(
+///         Color::BLACK,
+///         Val::Px(8.),
+///         Val::Px(8.),
+///         Val::ZERO,
+///         Val::Px(12.),
+///     ),
+///     GradientShadowColor::default(),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct GradientShadowColor {
+    /// How far the sampled color is mixed towards black, from `0.` to `1.`
+    pub darkness: f32,
+    /// Multiplies the alpha of the sampled color
+    pub alpha: f32,
+}
+
+impl Default for GradientShadowColor {
+    fn default() -> Self {
+        Self {
+            darkness: 0.5,
+            alpha: 0.6,
+        }
+    }
+}
+
+impl GradientShadowColor {
+    /// Sets how far the sampled color is mixed towards black
+    pub fn with_darkness(mut self, darkness: f32) -> Self {
+        self.darkness = darkness;
+        self
+    }
+
+    /// Sets the multiplier of the sampled color's alpha
+    pub fn with_alpha(mut self, alpha: f32) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// The color of a shadow from the gradient color `sampled` at its edge
+    pub fn shadow_color(&self, sampled: Color) -> Color {
+        let color = LinearRgba::from(sampled);
+        let brightness = 1. - self.darkness.clamp(0., 1.);
+        LinearRgba::new(
+            color.red * brightness,
+            color.green * brightness,
+            color.blue * brightness,
+            color.alpha * self.alpha,
+        )
+        .into()
+    }
+}
+
+/// Sets the colors of the `BoxShadow`s of nodes with a [`GradientShadowColor`] from their gradients.
+/// Runs in `PostUpdate` after layout, so the shadows follow changes to the gradients made in `Update`.
+pub fn update_gradient_shadow_colors(
+    sampler: GradientSampler,
+    mut query: Query<(Entity, &GradientShadowColor, &mut BoxShadow)>,
+) {
+    for (entity, shadow_color, mut box_shadow) in query.iter_mut() {
+        let Some(size) = sampler.node_size(entity) else {
+            continue;
+        };
+        let mut changed = false;
+        for style in box_shadow.bypass_change_detection().0.iter_mut() {
+            // The offsets are only used for their direction, so pixels aren't scaled
+            let resolve = |val: Val, base: f32| match val {
+                Val::Px(px) => px,
+                Val::Percent(percent) => percent / 100. * base,
+                _ => 0.,
+            };
+            let direction = Vec2::new(
+                resolve(style.x_offset, size.x),
+                resolve(style.y_offset, size.y),
+            );
+            let Some(sampled) = sampler.sample_edge(entity, direction) else {
+                continue;
+            };
+            let color = shadow_color.shadow_color(sampled);
+            if style.color != color {
+                style.color = color;
+                changed = true;
+            }
+        }
+        if changed {
+            box_shadow.set_changed();
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::*;
+use std::sync::Arc;
+
+/// A stack of background gradients shared between nodes.
+///
+/// Cloning a `SharedBackgroundGradient` only clones a pointer, so the same gradients can be added to
+/// thousands of list items without duplicating their stops. It's drawn like a [`BackgroundGradient`],
+/// over the node's `BackgroundGradient` if it has both.
+///
+/// Extraction resolves shared gradients once for all the nodes with the same size, border and padding,
+/// instead of once for each node. Gradients with [`GradientAttachment::Fixed`] or in a [`GradientGroup`]
+/// depend on the node's position and are still resolved for each node.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ui_gradients::*;
+/// let shared = SharedBackgroundGradient::new(LinearGradient::to_right(vec![
+///     Color::BLACK.into(),
+///     Color::WHITE.into(),
+/// ]));
+/// let item = shared.clone();
+/// assert!(item.ptr_eq(&shared));
+/// ```
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct SharedBackgroundGradient(pub Arc<[Gradient]>);
+
+impl SharedBackgroundGradient {
+    /// Create a new `SharedBackgroundGradient` from a stack of gradients
+    pub fn new(gradients: impl Into<BackgroundGradient>) -> Self {
+        Self(gradients.into().0.into())
+    }
+
+    /// Returns true if both share the same gradients, without comparing them
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: Into<BackgroundGradient>> From<T> for SharedBackgroundGradient {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,172 @@
+use crate::*;
+use bevy::ecs::{query::Changed, system::Query};
+
+/// A named keyframe of a [`SkyGradient`]
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
+pub struct SkyKeyframe {
+    /// The name of the keyframe, such as `"dawn"` or `"night"`
+    pub name: String,
+    /// The time of day of the keyframe in hours, from `0.` to `24.`
+    pub hour: f32,
+    /// The gradients displayed at this time of day
+    pub gradients: BackgroundGradient,
+}
+
+/// Blends a node's [`BackgroundGradient`] between named keyframes, such as dawn, noon, dusk and night,
+/// from the time of day in `hour`, for menu backdrops and weather UI.
+///
+/// The keyframes wrap around midnight, so the last keyframe of the day blends into the first.
+/// Gradients of the same kind are interpolated smoothly, even with different numbers of stops,
+/// otherwise the next keyframe's gradients are faded in over the previous keyframe's.
+///
+/// The default sky has vertical gradients for dawn at 6:00, noon at 12:00, dusk at 18:00 and night at 0:00.
+/// The node's gradients are updated by [`update_sky_gradients`] when the sky changes.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+#[require(BackgroundGradient)]
+pub struct SkyGradient {
+    /// The keyframes, sorted by hour
+    pub keyframes: Vec<SkyKeyframe>,
+    /// The time of day in hours, wrapped into `0.` to `24.`
+    pub hour: f32,
+}
+
+impl SkyGradient {
+    /// The number of hours in a day, the length of the sky's cycle
+    pub const HOURS_PER_DAY: f32 = 24.;
+
+    /// A sky without any keyframes at `hour`
+    pub fn new(hour: f32) -> Self {
+        Self {
+            keyframes: vec![],
+            hour,
+        }
+    }
+
+    /// A sky with a keyframe for each of dawn at 6:00, noon at 12:00, dusk at 18:00 and night at 0:00
+    pub fn dawn_noon_dusk_night(
+        dawn: impl Into<BackgroundGradient>,
+        noon: impl Into<BackgroundGradient>,
+        dusk: impl Into<BackgroundGradient>,
+        night: impl Into<BackgroundGradient>,
+    ) -> Self {
+        Self::new(12.)
+            .with_keyframe("night", 0., night)
+            .with_keyframe("dawn", 6., dawn)
+            .with_keyframe("noon", 12., noon)
+            .with_keyframe("dusk", 18., dusk)
+    }
+
+    /// Adds a keyframe named `name` at `hour`
+    pub fn with_keyframe(
+        mut self,
+        name: impl Into<String>,
+        hour: f32,
+        gradients: impl Into<BackgroundGradient>,
+    ) -> Self {
+        self.insert_keyframe(SkyKeyframe {
+            name: name.into(),
+            hour,
+            gradients: gradients.into(),
+        });
+        self
+    }
+
+    /// Sets the time of day in hours
+    pub fn with_hour(mut self, hour: f32) -> Self {
+        self.hour = hour;
+        self
+    }
+
+    /// Inserts a keyframe, keeping the keyframes sorted by hour.
+    /// The keyframe's hour is wrapped into `0.` to `24.`.
+    pub fn insert_keyframe(&mut self, mut keyframe: SkyKeyframe) {
+        keyframe.hour = keyframe.hour.rem_euclid(Self::HOURS_PER_DAY);
+        let index = self
+            .keyframes
+            .partition_point(|other| other.hour <= keyframe.hour);
+        self.keyframes.insert(index, keyframe);
+    }
+
+    /// The keyframe named `name`
+    pub fn keyframe(&self, name: &str) -> Option<&SkyKeyframe> {
+        self.keyframes.iter().find(|keyframe| keyframe.name == name)
+    }
+
+    /// The keyframe named `name`, for changing its gradients.
+    /// Change hours with [`SkyGradient::insert_keyframe`] to keep the keyframes sorted.
+    pub fn keyframe_mut(&mut self, name: &str) -> Option<&mut SkyKeyframe> {
+        self.keyframes
+            .iter_mut()
+            .find(|keyframe| keyframe.name == name)
+    }
+
+    /// The blended gradients at `hour`
+    pub fn sample(&self, hour: f32) -> Vec<Gradient> {
+        let (Some(first), Some(last)) = (self.keyframes.first(), self.keyframes.last()) else {
+            return vec![];
+        };
+        let hour = hour.rem_euclid(Self::HOURS_PER_DAY);
+        let next = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.hour <= hour);
+        // Before the first keyframe and after the last, blend across midnight
+        let (from, to, from_hour, to_hour) = match next {
+            0 => (last, first, last.hour - Self::HOURS_PER_DAY, first.hour),
+            next if next == self.keyframes.len() => {
+                (last, first, last.hour, first.hour + Self::HOURS_PER_DAY)
+            }
+            next => {
+                let (from, to) = (&self.keyframes[next - 1], &self.keyframes[next]);
+                (from, to, from.hour, to.hour)
+            }
+        };
+        if to_hour <= from_hour {
+            return from.gradients.0.clone();
+        }
+        let t = (hour - from_hour) / (to_hour - from_hour);
+        lerp_gradient_stacks(&from.gradients.0, &to.gradients.0, t)
+    }
+}
+
+impl Default for SkyGradient {
+    fn default() -> Self {
+        let sky =
+            |colors: [Srgba; 3]| LinearGradient::to_bottom(colors.into_iter().map(ColorStop::from));
+        Self::dawn_noon_dusk_night(
+            sky([
+                Srgba::rgb_u8(58, 76, 140),
+                Srgba::rgb_u8(232, 150, 160),
+                Srgba::rgb_u8(255, 196, 120),
+            ]),
+            sky([
+                Srgba::rgb_u8(30, 110, 220),
+                Srgba::rgb_u8(90, 170, 240),
+                Srgba::rgb_u8(190, 225, 250),
+            ]),
+            sky([
+                Srgba::rgb_u8(40, 40, 100),
+                Srgba::rgb_u8(200, 80, 90),
+                Srgba::rgb_u8(255, 140, 50),
+            ]),
+            sky([
+                Srgba::rgb_u8(2, 4, 16),
+                Srgba::rgb_u8(10, 16, 48),
+                Srgba::rgb_u8(24, 32, 72),
+            ]),
+        )
+    }
+}
+
+/// Updates the [`BackgroundGradient`]s of nodes with a changed [`SkyGradient`]
+pub fn update_sky_gradients(
+    mut query: Query<(&SkyGradient, &mut BackgroundGradient), Changed<SkyGradient>>,
+) {
+    for (sky, mut background) in query.iter_mut() {
+        let gradients = sky.sample(sky.hour);
+        if background.0 != gradients {
+            background.0 = gradients;
+        }
+    }
+}
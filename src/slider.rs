@@ -0,0 +1,151 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        hierarchy::Children,
+        query::{Added, With},
+        system::{Commands, Query},
+    },
+    prelude::DetectChangesMut,
+    ui::{BackgroundColor, Interaction, Node, PositionType, RelativeCursorPosition},
+};
+
+/// A horizontal slider whose track is drawn by the node's [`BackgroundGradient`],
+/// for temperature, intensity and other color scale pickers.
+///
+/// Pressing or dragging on the track moves the handle to the cursor.
+/// Each frame the composited color of the track under the handle is sampled on the CPU and
+/// stored in `color`.
+///
+/// The handle is spawned as a child of the slider with the [`GradientSliderHandle`] marker.
+/// Spawn sliders with [`gradient_slider`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+#[require(Node, BackgroundGradient, Interaction, RelativeCursorPosition)]
+pub struct GradientSlider {
+    /// The position of the handle along the track, from `0.` at the left to `1.` at the right
+    pub value: f32,
+    /// The color of the track under the handle, updated by [`update_gradient_sliders`]
+    pub color: Color,
+    /// The width of the handle in logical pixels
+    pub handle_width: f32,
+    /// The color of the handle
+    pub handle_color: Color,
+}
+
+impl Default for GradientSlider {
+    fn default() -> Self {
+        Self {
+            value: 0.5,
+            color: Color::NONE,
+            handle_width: 6.,
+            handle_color: Color::WHITE,
+        }
+    }
+}
+
+impl GradientSlider {
+    /// Sets the position of the handle, between `0.` and `1.`
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the width of the handle in logical pixels
+    pub fn with_handle_width(mut self, handle_width: f32) -> Self {
+        self.handle_width = handle_width;
+        self
+    }
+
+    /// Sets the color of the handle
+    pub fn with_handle_color(mut self, handle_color: impl Into<Color>) -> Self {
+        self.handle_color = handle_color.into();
+        self
+    }
+}
+
+/// Marks the handle node of a [`GradientSlider`]
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct GradientSliderHandle;
+
+/// A slider node with a track of `gradient`, `width` long and `height` high, with its handle at `value`
+pub fn gradient_slider(
+    gradient: impl Into<Gradient>,
+    value: f32,
+    width: Val,
+    height: Val,
+) -> impl Bundle {
+    (
+        Node {
+            width,
+            height,
+            ..Default::default()
+        },
+        BackgroundGradient::from(gradient.into()),
+        GradientSlider::default().with_value(value),
+    )
+}
+
+/// Spawns the handles of new [`GradientSlider`]s, moves handles to the cursor while their track is pressed,
+/// and samples the color of the track under each handle.
+pub fn update_gradient_sliders(
+    mut commands: Commands,
+    added: Query<Entity, Added<GradientSlider>>,
+    mut sliders: Query<(
+        Entity,
+        &mut GradientSlider,
+        &Interaction,
+        &RelativeCursorPosition,
+        Option<&Children>,
+    )>,
+    mut handles: Query<(&mut Node, &mut BackgroundColor), With<GradientSliderHandle>>,
+    sampler: GradientSampler,
+) {
+    for entity in &added {
+        commands.entity(entity).with_child((
+            Node {
+                position_type: PositionType::Absolute,
+                height: Val::Percent(100.),
+                ..Default::default()
+            },
+            BackgroundColor::default(),
+            GradientSliderHandle,
+        ));
+    }
+
+    for (entity, mut slider, interaction, cursor, children) in sliders.iter_mut() {
+        if let (Interaction::Pressed, Some(position)) = (*interaction, cursor.normalized) {
+            let value = position.x.clamp(0., 1.);
+            if slider.value != value {
+                slider.value = value;
+            }
+        }
+
+        if let Some(size) = sampler.node_size(entity) {
+            let point = Vec2::new((slider.value.clamp(0., 1.) - 0.5) * size.x, 0.);
+            if let Some(color) = sampler
+                .sample_node(entity, point)
+                .filter(|&color| slider.color != color)
+            {
+                slider.color = color;
+            }
+        }
+
+        for &child in children.map(|children| &children[..]).unwrap_or_default() {
+            if let Ok((mut node, mut background)) = handles.get_mut(child) {
+                let left = Val::Percent(100. * slider.value.clamp(0., 1.));
+                let width = Val::Px(slider.handle_width);
+                let margin = Val::Px(-0.5 * slider.handle_width);
+                if node.left != left || node.width != width || node.margin.left != margin {
+                    node.left = left;
+                    node.width = width;
+                    // Center the handle on the value
+                    node.margin.left = margin;
+                }
+                background.set_if_neq(BackgroundColor(slider.handle_color));
+            }
+        }
+    }
+}
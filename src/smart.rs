@@ -0,0 +1,56 @@
+use crate::*;
+use bevy::color::{Mix, Oklcha};
+
+/// The number of segments a [`LinearGradient::smart`] blend is divided into
+pub const SMART_BLEND_SEGMENTS: usize = 8;
+
+impl ColorStop {
+    /// Evenly spaced stops blending from `from` to `to` in OKLCH space.
+    ///
+    /// The gradient shader mixes colors in sRGB space, where the midpoint between complementary colors like
+    /// blue and orange is a dull gray. These stops follow the shortest path around the hue wheel instead,
+    /// keeping the lightness and chroma of the midtones, approximated by [`SMART_BLEND_SEGMENTS`] segments.
+    /// Colors outside of the sRGB gamut are clamped.
+    pub fn smart_blend(from: impl Into<Color>, to: impl Into<Color>) -> Vec<Self> {
+        let mut from = Oklcha::from(from.into());
+        let mut to = Oklcha::from(to.into());
+        // The hue of a gray is meaningless, use the other color's hue so the blend doesn't sweep through other hues
+        if from.chroma < 1e-4 {
+            from.hue = to.hue;
+        } else if to.chroma < 1e-4 {
+            to.hue = from.hue;
+        }
+        (0..=SMART_BLEND_SEGMENTS)
+            .map(|i| {
+                let color = from.mix(&to, i as f32 / SMART_BLEND_SEGMENTS as f32);
+                ColorStop::auto(clamp_to_srgb(color))
+            })
+            .collect()
+    }
+}
+
+/// Converts an OKLCH color to sRGB, clamping colors outside of the sRGB gamut
+pub(crate) fn clamp_to_srgb(color: Oklcha) -> Color {
+    let srgba = Srgba::from(color);
+    Srgba::new(
+        srgba.red.clamp(0., 1.),
+        srgba.green.clamp(0., 1.),
+        srgba.blue.clamp(0., 1.),
+        srgba.alpha,
+    )
+    .into()
+}
+
+impl LinearGradient {
+    /// A left to right gradient from `from` to `to` through perceptual midtones, without the gray dead zone
+    /// in the middle of a plain two stop gradient between complementary colors. See [`ColorStop::smart_blend`].
+    ///
+    /// ```
+    /// # use bevy_ui_gradients::LinearGradient;
+    /// # use bevy::color::palettes::css::{BLUE, ORANGE};
+    /// let gradient = LinearGradient::smart(BLUE, ORANGE);
+    /// ```
+    pub fn smart(from: impl Into<Color>, to: impl Into<Color>) -> Self {
+        Self::to_right(ColorStop::smart_blend(from, to))
+    }
+}
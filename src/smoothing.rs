@@ -0,0 +1,117 @@
+use crate::*;
+use bevy::math::Vec4;
+
+/// The number of color stops each segment of a smoothed gradient is divided into
+pub const SMOOTHING_SUBDIVISIONS: usize = 8;
+
+/// A smoothing mode for the colors of a whole gradient.
+///
+/// Linear interpolation between stops has a kink in the rate the color changes at each stop, which shows as
+/// a visible band (Mach banding) in large gradients. A smoothed gradient follows a spline through the stops'
+/// colors instead, approximated by [`SMOOTHING_SUBDIVISIONS`] linear segments between each pair of stops.
+///
+/// The stops' hints and [`Interpolation`]s are ignored by smoothed gradients. Stops at the same position
+/// still make a hard edge, and the spline is split there.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum GradientSmoothing {
+    /// The colors are interpolated between each pair of stops
+    #[default]
+    None,
+    /// A Catmull-Rom spline passing through each stop's color. It can overshoot the stops' colors slightly.
+    CatmullRom,
+    /// A cubic B-spline with the stops' colors as control points.
+    /// Smoother than [`GradientSmoothing::CatmullRom`], but it only passes through the colors of the first and last stops.
+    BSpline,
+}
+
+impl GradientSmoothing {
+    /// Replaces the resolved color stops from `range_start` to the end of `stops` with `subdivisions` stops
+    /// sampled from the spline between each pair of stops. The splines are evaluated in sRGB space,
+    /// like the gradient shader's interpolation.
+    pub fn smooth_stops(
+        self,
+        stops: &mut Vec<(LinearRgba, f32, f32, Interpolation, StopOscillation)>,
+        range_start: usize,
+        subdivisions: usize,
+    ) {
+        let range_end = stops.len();
+        if self == GradientSmoothing::None || range_end - range_start < 3 || subdivisions < 2 {
+            return;
+        }
+
+        for i in range_start..range_end - 1 {
+            let (x1, x2) = (stops[i].1, stops[i + 1].1);
+            if x1 == x2 {
+                // A hard edge, keep the stop
+                stops.push((stops[i].0, x1, 0.5, Interpolation::Linear, stops[i].4));
+                continue;
+            }
+            let (p1, p2) = (to_srgb(stops[i].0), to_srgb(stops[i + 1].0));
+
+            // The neighbouring stops, or points extrapolated from the segment at hard edges and the ends
+            let (p0, x0) = if range_start < i && stops[i - 1].1 != x1 {
+                (to_srgb(stops[i - 1].0), stops[i - 1].1)
+            } else {
+                (2. * p1 - p2, 2. * x1 - x2)
+            };
+            let (p3, x3) = if i + 2 < range_end && stops[i + 2].1 != x2 {
+                (to_srgb(stops[i + 2].0), stops[i + 2].1)
+            } else {
+                (2. * p2 - p1, 2. * x2 - x1)
+            };
+
+            for k in 0..subdivisions {
+                let t = k as f32 / subdivisions as f32;
+                let color = match self {
+                    GradientSmoothing::CatmullRom => {
+                        // Tangents scaled by the stops' spacing, so the rate of change is continuous at the stops
+                        let h = x2 - x1;
+                        let m1 = (p2 - p0) * (h / (x2 - x0));
+                        let m2 = (p3 - p1) * (h / (x3 - x1));
+                        let (t2, t3) = (t * t, t * t * t);
+                        (2. * t3 - 3. * t2 + 1.) * p1
+                            + (t3 - 2. * t2 + t) * m1
+                            + (-2. * t3 + 3. * t2) * p2
+                            + (t3 - t2) * m2
+                    }
+                    _ => {
+                        let u = 1. - t;
+                        let (t2, t3) = (t * t, t * t * t);
+                        (u * u * u * p0
+                            + (3. * t3 - 6. * t2 + 4.) * p1
+                            + (-3. * t3 + 3. * t2 + 3. * t + 1.) * p2
+                            + t3 * p3)
+                            / 6.
+                    }
+                };
+                stops.push((
+                    from_srgb(color),
+                    x1 + t * (x2 - x1),
+                    0.5,
+                    Interpolation::Linear,
+                    // Only the sampled stop at the original stop keeps its oscillation
+                    if k == 0 {
+                        stops[i].4
+                    } else {
+                        StopOscillation::default()
+                    },
+                ));
+            }
+        }
+        let last = stops[range_end - 1];
+        stops.push((last.0, last.1, 0.5, Interpolation::Linear, last.4));
+        stops.drain(range_start..range_end);
+    }
+}
+
+fn to_srgb(color: LinearRgba) -> Vec4 {
+    Vec4::new(color.red, color.green, color.blue, 0.)
+        .powf(1. / 2.2)
+        .with_w(color.alpha)
+}
+
+fn from_srgb(color: Vec4) -> LinearRgba {
+    let rgb = color.max(Vec4::ZERO).powf(2.2);
+    LinearRgba::new(rgb.x, rgb.y, rgb.z, color.w.clamp(0., 1.))
+}
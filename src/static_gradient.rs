@@ -0,0 +1,149 @@
+use crate::*;
+
+/// A gradient that borrows its color stops, so it can be defined as a `static` or `const` item
+/// without allocating.
+///
+/// Static gradients convert into [`Gradient`]s, [`BackgroundGradient`]s and [`BorderGradient`]s,
+/// copying their stops. Stacks of static gradients convert from slices.
+/// Build the stops with the `const` constructors [`ColorStop::const_new`] and [`AngularColorStop::const_at`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ui_gradients::*;
+/// static PANEL: StaticGradient = StaticGradient::linear(
+///     LinearGradient::TO_BOTTOM,
+///     &[
+///         ColorStop::const_percent(Color::srgb(0.2, 0.2, 0.3), 0.),
+///         ColorStop::const_percent(Color::srgb(0.1, 0.1, 0.15), 100.),
+///     ],
+/// );
+///
+/// let theme = GradientTheme::default().with("panel", PANEL);
+/// assert_eq!(theme.get("panel").unwrap()[0], Gradient::from(PANEL));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StaticGradient {
+    /// A [`LinearGradient`]
+    Linear {
+        /// The direction of the gradient
+        angle: Angle,
+        /// The list of color stops
+        stops: &'static [ColorStop],
+        /// How the areas before the first color stop and after the last color stop are filled
+        spread: SpreadMethod,
+    },
+    /// A [`RadialGradient`]
+    Radial {
+        /// The center of the radial gradient
+        position: Position,
+        /// Defines the end shape of the radial gradient
+        shape: RadialGradientShape,
+        /// The list of color stops
+        stops: &'static [ColorStop],
+        /// How the areas before the first color stop and after the last color stop are filled
+        spread: SpreadMethod,
+    },
+    /// A [`ConicGradient`]
+    Conic {
+        /// The starting angle of the gradient
+        start: Angle,
+        /// The center of the conic gradient
+        position: Position,
+        /// The list of color stops
+        stops: &'static [AngularColorStop],
+        /// How the areas before the first color stop and after the last color stop are filled
+        spread: SpreadMethod,
+    },
+}
+
+impl StaticGradient {
+    /// A linear gradient at `angle`
+    pub const fn linear(angle: Angle, stops: &'static [ColorStop]) -> Self {
+        Self::Linear {
+            angle,
+            stops,
+            spread: SpreadMethod::Pad,
+        }
+    }
+
+    /// A radial gradient centered at `position`
+    pub const fn radial(
+        position: Position,
+        shape: RadialGradientShape,
+        stops: &'static [ColorStop],
+    ) -> Self {
+        Self::Radial {
+            position,
+            shape,
+            stops,
+            spread: SpreadMethod::Pad,
+        }
+    }
+
+    /// A conic gradient centered on the node, starting at the top
+    pub const fn conic(stops: &'static [AngularColorStop]) -> Self {
+        Self::Conic {
+            start: Angle::ZERO,
+            position: Position::CENTER,
+            stops,
+            spread: SpreadMethod::Pad,
+        }
+    }
+
+    /// Sets how the areas before the first color stop and after the last color stop are filled
+    pub const fn with_spread(mut self, spread: SpreadMethod) -> Self {
+        match &mut self {
+            Self::Linear { spread: s, .. }
+            | Self::Radial { spread: s, .. }
+            | Self::Conic { spread: s, .. } => *s = spread,
+        }
+        self
+    }
+}
+
+impl From<StaticGradient> for Gradient {
+    fn from(gradient: StaticGradient) -> Self {
+        match gradient {
+            StaticGradient::Linear {
+                angle,
+                stops,
+                spread,
+            } => Gradient::Linear(LinearGradient {
+                stops: stops.into(),
+                ..LinearGradient::new(angle, vec![]).with_spread(spread)
+            }),
+            StaticGradient::Radial {
+                position,
+                shape,
+                stops,
+                spread,
+            } => Gradient::Radial(RadialGradient {
+                stops: stops.into(),
+                ..RadialGradient::new(position, shape, vec![]).with_spread(spread)
+            }),
+            StaticGradient::Conic {
+                start,
+                position,
+                stops,
+                spread,
+            } => Gradient::Conic(ConicGradient {
+                start,
+                position,
+                stops: stops.into(),
+                ..ConicGradient::new(vec![]).with_spread(spread)
+            }),
+        }
+    }
+}
+
+impl From<&[StaticGradient]> for BackgroundGradient {
+    fn from(gradients: &[StaticGradient]) -> Self {
+        Self(gradients.iter().copied().map(Gradient::from).collect())
+    }
+}
+
+impl From<&[StaticGradient]> for BorderGradient {
+    fn from(gradients: &[StaticGradient]) -> Self {
+        Self(gradients.iter().copied().map(Gradient::from).collect())
+    }
+}
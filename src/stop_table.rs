@@ -0,0 +1,182 @@
+use crate::render::resolve_linear_gradient_line;
+use crate::*;
+use core::fmt;
+
+/// Formats a gradient's color stops as aligned tables, for debugging automatic stops in logs.
+///
+/// The first table lists the stops as they're declared: their positions, colors in hex and hints.
+/// The second lists the stops resolved for a node of the table's size, after stops with two positions
+/// are expanded, explicit stops are sorted and automatic positions are interpolated.
+/// Resolved distances are in physical pixels along the gradient line, or radians for conic gradients,
+/// and `t` is the distance as a fraction of the gradient line or full turn.
+///
+/// ```
+/// # use bevy_ui_gradients::{ColorStop, Gradient, LinearGradient};
+/// # use bevy::{color::palettes::css::{RED, LIME, BLUE}, math::Vec2, ui::Val};
+/// let gradient: Gradient = LinearGradient::to_right(vec![
+///     ColorStop::auto(RED),
+///     ColorStop::new(LIME, Val::Px(25.)),
+///     ColorStop::auto(BLUE),
+/// ])
+/// .into();
+/// println!("{}", gradient.stop_table(Vec2::new(200., 100.)));
+/// ```
+pub struct GradientStopTable<'a> {
+    gradient: &'a Gradient,
+    size: Vec2,
+}
+
+impl Gradient {
+    /// A [`Display`](fmt::Display)able table of the gradient's declared and resolved stops,
+    /// resolved for a node with a size of `size` physical pixels
+    pub fn stop_table(&self, size: Vec2) -> GradientStopTable<'_> {
+        GradientStopTable {
+            gradient: self,
+            size,
+        }
+    }
+}
+
+impl fmt::Display for GradientStopTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.gradient {
+            Gradient::Linear(_) => "linear",
+            Gradient::Radial(_) => "radial",
+            Gradient::Conic(_) => "conic",
+            Gradient::Inset(_) => "inset",
+            Gradient::Plasma(_) => "plasma",
+        };
+        writeln!(
+            f,
+            "{kind} gradient, {} stops",
+            self.gradient.authored_stops_len()
+        )?;
+
+        let declared: Vec<[String; 4]> = match self.gradient {
+            Gradient::Linear(LinearGradient { stops, .. })
+            | Gradient::Radial(RadialGradient { stops, .. })
+            | Gradient::Inset(InsetGradient { stops, .. })
+            | Gradient::Plasma(PlasmaGradient { stops, .. }) => stops
+                .iter()
+                .enumerate()
+                .map(|(i, stop)| {
+                    let mut position = format_val(stop.point);
+                    if let Some(end_point) = stop.end_point {
+                        position = format!("{position} {}", format_val(end_point));
+                    }
+                    [
+                        i.to_string(),
+                        position,
+                        format_color(stop.color),
+                        stop.hint_point
+                            .map_or_else(|| format!("{:.2}", stop.hint), format_val),
+                    ]
+                })
+                .collect(),
+            Gradient::Conic(ConicGradient { stops, .. }) => stops
+                .iter()
+                .enumerate()
+                .map(|(i, stop)| {
+                    let position = match stop.angle {
+                        AngularPosition::Auto => "auto".to_string(),
+                        AngularPosition::Radians(angle) => format!("{angle}rad"),
+                        AngularPosition::Percent(percent) => format!("{percent}%"),
+                        AngularPosition::Turns(turns) => format!("{turns}turn"),
+                    };
+                    [
+                        i.to_string(),
+                        position,
+                        format_color(stop.color),
+                        format!("{:.2}", stop.hint),
+                    ]
+                })
+                .collect(),
+        };
+        write_table(f, ["#", "position", "color", "hint"], &declared)?;
+
+        // Smoothing is left out so the resolved stops match the declared stops
+        let gradient = self
+            .gradient
+            .clone()
+            .with_smoothing(GradientSmoothing::None);
+        let physical = PhysicalGradient::new(&gradient, 1., self.size, self.size);
+        let length = match &gradient {
+            Gradient::Linear(gradient) => {
+                resolve_linear_gradient_line(gradient, 1., self.size, self.size).2
+            }
+            Gradient::Radial(RadialGradient {
+                position, shape, ..
+            }) => {
+                let center = position.resolve(1., self.size, self.size);
+                shape.resolve(center, 1., self.size, self.size).x
+            }
+            Gradient::Conic(_) => TAU,
+            Gradient::Inset(_) => 0.5 * self.size.min_element(),
+            Gradient::Plasma(gradient) => gradient.scale.max(1.),
+        };
+        let resolved: Vec<[String; 5]> = physical
+            .stops
+            .iter()
+            .enumerate()
+            .map(|(i, (color, distance, hint, ..))| {
+                [
+                    i.to_string(),
+                    format!("{distance:.2}"),
+                    if length != 0. {
+                        format!("{:.3}", distance / length)
+                    } else {
+                        "-".to_string()
+                    },
+                    format_color((*color).into()),
+                    format!("{hint:.2}"),
+                ]
+            })
+            .collect();
+        writeln!(f, "resolved for {}x{}:", self.size.x, self.size.y)?;
+        write_table(f, ["#", "distance", "t", "color", "hint"], &resolved)
+    }
+}
+
+fn format_val(val: Val) -> String {
+    match val {
+        Val::Auto => "auto".to_string(),
+        Val::Px(value) => format!("{value}px"),
+        Val::Percent(value) => format!("{value}%"),
+        Val::Vw(value) => format!("{value}vw"),
+        Val::Vh(value) => format!("{value}vh"),
+        Val::VMin(value) => format!("{value}vmin"),
+        Val::VMax(value) => format!("{value}vmax"),
+    }
+}
+
+fn format_color(color: Color) -> String {
+    Srgba::from(color).to_hex()
+}
+
+/// Writes the rows with each column padded to the width of its widest cell
+fn write_table<const N: usize>(
+    f: &mut fmt::Formatter<'_>,
+    headers: [&str; N],
+    rows: &[[String; N]],
+) -> fmt::Result {
+    let mut widths = headers.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let mut write_row = |cells: [&str; N]| {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(f, "  {}", line.trim_end())
+    };
+    write_row(headers)?;
+    for row in rows {
+        write_row(row.each_ref().map(String::as_str))?;
+    }
+    Ok(())
+}
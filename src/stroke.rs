@@ -0,0 +1,117 @@
+use crate::*;
+use bevy::math::{
+    Vec2,
+    ops::{acos, cos, sin},
+};
+
+/// A segment of a [`GradientStroke`]'s path, continuing from the end of the previous segment
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub enum StrokeSegment {
+    /// A straight line to a point
+    Line(Vec2),
+    /// A circular arc around `center`, turning `sweep` radians clockwise from the current point.
+    /// Negative sweeps turn anticlockwise.
+    Arc { center: Vec2, sweep: f32 },
+}
+
+/// A gradient-filled stroke along a path, drawn over the node's gradients.
+///
+/// The path is given in logical pixels relative to the top-left corner of the node.
+/// The color stops run along the length of the path, percentages resolve against the path's total length.
+///
+/// Each line of the path is drawn as a separate quad with round ends, so translucent strokes
+/// show overlaps at their joins. Strokes are clipped by their ancestors' overflow like the node's
+/// gradients, and are ignored by gradient sampling and picking.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
+pub struct GradientStroke {
+    /// Where the path starts
+    pub start: Vec2,
+    /// The segments of the path
+    pub segments: Vec<StrokeSegment>,
+    /// The width of the stroke in logical pixels
+    pub width: f32,
+    /// The color stops along the path
+    pub stops: Vec<ColorStop>,
+    /// The fraction of the path's length that is drawn, between `0.` and `1.`.
+    /// The stops are resolved against the full length of the path, so they don't move as the progress changes.
+    pub progress: f32,
+}
+
+impl GradientStroke {
+    /// Create a new stroke starting at `start`, with no segments
+    pub fn new(start: Vec2, width: f32, stops: Vec<ColorStop>) -> Self {
+        Self {
+            start,
+            segments: vec![],
+            width,
+            stops,
+            progress: 1.,
+        }
+    }
+
+    /// A stroke along a circular arc around `center`, starting at `start_angle` and turning `sweep`
+    /// radians clockwise. An angle of `0.` points upward, like CSS angles.
+    pub fn arc(
+        center: Vec2,
+        radius: f32,
+        start_angle: f32,
+        sweep: f32,
+        width: f32,
+        stops: Vec<ColorStop>,
+    ) -> Self {
+        let start = center + radius * Vec2::new(sin(start_angle), -cos(start_angle));
+        Self::new(start, width, stops).arc_to(center, sweep)
+    }
+
+    /// Adds a straight line to `point`
+    pub fn line_to(mut self, point: Vec2) -> Self {
+        self.segments.push(StrokeSegment::Line(point));
+        self
+    }
+
+    /// Adds a circular arc around `center`, turning `sweep` radians clockwise
+    pub fn arc_to(mut self, center: Vec2, sweep: f32) -> Self {
+        self.segments.push(StrokeSegment::Arc { center, sweep });
+        self
+    }
+
+    /// Sets the fraction of the path's length that is drawn
+    pub fn with_progress(mut self, progress: f32) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Flattens the path into a polyline, scaled by `scale_factor`.
+    /// Arcs are subdivided so the polyline is within `tolerance` of the arc.
+    pub fn flatten(&self, scale_factor: f32, tolerance: f32) -> Vec<Vec2> {
+        let mut points = vec![self.start * scale_factor];
+        let mut current = self.start;
+        for segment in &self.segments {
+            match *segment {
+                StrokeSegment::Line(point) => {
+                    points.push(point * scale_factor);
+                    current = point;
+                }
+                StrokeSegment::Arc { center, sweep } => {
+                    let v = current - center;
+                    let radius = v.length() * scale_factor;
+                    let max_step = if tolerance < radius {
+                        2. * acos(1. - tolerance / radius)
+                    } else {
+                        sweep.abs()
+                    };
+                    let steps = (sweep.abs() / max_step).ceil().clamp(1., 256.) as usize;
+                    for i in 1..=steps {
+                        let angle = sweep * i as f32 / steps as f32;
+                        let (s, c) = (sin(angle), cos(angle));
+                        current = center + Vec2::new(v.x * c - v.y * s, v.x * s + v.y * c);
+                        points.push(current * scale_factor);
+                    }
+                }
+            }
+        }
+        points
+    }
+}
@@ -0,0 +1,323 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        change_detection::{DetectChanges, Ref},
+        entity::Entity,
+        resource::Resource,
+        system::{Commands, EntityCommands, Query, Res},
+    },
+    log::warn,
+};
+use core::str::FromStr;
+
+/// Applies the rules of the [`GradientStylesheet`] resource to nodes with a [`GradientClass`].
+///
+/// Only available with the `stylesheet` feature. Like [`UiGradientsPlugin`], it must be added after `DefaultPlugins`.
+#[derive(Default)]
+pub struct GradientStylesheetPlugin;
+
+impl Plugin for GradientStylesheetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GradientStylesheet>()
+            .add_systems(Update, apply_gradient_stylesheet);
+    }
+}
+
+/// The value of a gradient property in a CSS declaration.
+///
+/// Stylesheet crates can use [`GradientProperty::parse`] and [`GradientProperty::apply`] to support
+/// gradient properties in their own stylesheets.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GradientProperty {
+    /// A `background` or `background-image` property
+    Background(BackgroundGradient),
+    /// A `border-image` or `border-image-source` property
+    BorderImage(BorderGradient),
+}
+
+impl GradientProperty {
+    /// The names of the properties that can be parsed into a `GradientProperty`
+    pub const NAMES: [&str; 4] = [
+        "background",
+        "background-image",
+        "border-image",
+        "border-image-source",
+    ];
+
+    /// Parses the value of the property `name`.
+    /// Returns `None` if `name` isn't one of the gradient properties in [`GradientProperty::NAMES`].
+    ///
+    /// ```
+    /// # use bevy_ui_gradients::*;
+    /// let property = GradientProperty::parse("border-image", "linear-gradient(red, blue) 1");
+    /// assert!(matches!(property, Some(Ok(GradientProperty::BorderImage(_)))));
+    /// assert_eq!(GradientProperty::parse("color", "red"), None);
+    /// ```
+    pub fn parse(name: &str, value: &str) -> Option<Result<Self, ParseGradientError>> {
+        let name = name.trim();
+        if name.eq_ignore_ascii_case("background") || name.eq_ignore_ascii_case("background-image")
+        {
+            Some(value.parse().map(Self::Background))
+        } else if name.eq_ignore_ascii_case("border-image")
+            || name.eq_ignore_ascii_case("border-image-source")
+        {
+            Some(value.parse().map(Self::BorderImage))
+        } else {
+            None
+        }
+    }
+
+    /// Inserts the gradients into the entity, replacing its [`BackgroundGradient`] or [`BorderGradient`]
+    pub fn apply(&self, entity: &mut EntityCommands) {
+        match self {
+            Self::Background(gradient) => entity.insert(gradient.clone()),
+            Self::BorderImage(gradient) => entity.insert(gradient.clone()),
+        };
+    }
+}
+
+/// A rule of a [`GradientStylesheet`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GradientRule {
+    /// The selectors of the rule. Each selector is a list of classes, all of which a node must have to match.
+    pub selectors: Vec<Vec<String>>,
+    /// The gradient properties declared by the rule, in order
+    pub properties: Vec<GradientProperty>,
+}
+
+impl GradientRule {
+    /// The number of classes in the longest selector matching `class`, or `None` if no selector matches
+    pub fn specificity(&self, class: &GradientClass) -> Option<usize> {
+        self.selectors
+            .iter()
+            .filter(|selector| selector.iter().all(|name| class.contains(name)))
+            .map(Vec::len)
+            .max()
+    }
+}
+
+/// A stylesheet of rules setting the gradients of nodes by their [`GradientClass`], added by [`GradientStylesheetPlugin`].
+///
+/// Stylesheets are parsed from CSS with `FromStr`. Only class selectors like `.panel` and `.panel.active`
+/// are supported, and declarations of properties other than the gradient properties in [`GradientProperty::NAMES`]
+/// are ignored, so a stylesheet can be shared with other stylesheet crates. Other selectors, at-rules and
+/// gradient property values without a supported gradient, like `url(..)` images, are skipped with a warning.
+/// Parsing fails if a comment or block isn't closed, or a gradient in a gradient property is malformed.
+///
+/// Like CSS, rules with more classes in their selector take precedence, then later rules.
+/// Nodes whose classes don't match any rule declaring a property keep their current gradients.
+///
+/// ```
+/// # use bevy_ui_gradients::*;
+/// let stylesheet: GradientStylesheet = "
+///     .panel { background: linear-gradient(red, blue); color: white; }
+///     .panel.active, .highlight { border-image: conic-gradient(red, yellow, red) 1; }
+///     @media (min-width: 600px) { .panel { color: black; } }
+///     #logo, .logo { background: url(logo.png); }
+/// "
+/// .parse()
+/// .unwrap();
+/// assert_eq!(stylesheet.rules.len(), 3);
+/// assert_eq!(stylesheet.rules[2].selectors, vec![vec!["logo".to_string()]]);
+/// assert!(stylesheet.rules[2].properties.is_empty());
+///
+/// assert!(".panel { background: linear-gradient(red, ) }".parse::<GradientStylesheet>().is_err());
+/// ```
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct GradientStylesheet {
+    /// The rules, in the order they were declared
+    pub rules: Vec<GradientRule>,
+}
+
+impl GradientStylesheet {
+    /// The properties of the rules matching `class`, with the properties that take precedence last
+    pub fn matching_properties(&self, class: &GradientClass) -> Vec<&GradientProperty> {
+        let mut rules = self
+            .rules
+            .iter()
+            .filter_map(|rule| Some((rule.specificity(class)?, rule)))
+            .collect::<Vec<_>>();
+        // The sort is stable, so later rules stay after earlier rules with the same specificity
+        rules.sort_by_key(|(specificity, _)| *specificity);
+        rules
+            .into_iter()
+            .flat_map(|(_, rule)| &rule.properties)
+            .collect()
+    }
+}
+
+impl FromStr for GradientStylesheet {
+    type Err = ParseGradientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut text = String::with_capacity(s.len());
+        let mut rest = s;
+        while let Some(start) = rest.find("/*") {
+            text.push_str(&rest[..start]);
+            let end = rest[start..]
+                .find("*/")
+                .ok_or_else(|| ParseGradientError::InvalidValue(rest[start..].to_string()))?;
+            rest = &rest[start + end + 2..];
+        }
+        text.push_str(rest);
+
+        let mut rules = vec![];
+        let mut rest = text.trim();
+        while !rest.is_empty() {
+            // At-rules without a block, like `@import`, end at a semicolon
+            if rest.starts_with('@') {
+                if let Some((statement, after)) = rest
+                    .split_once(';')
+                    .filter(|(statement, _)| !statement.contains('{'))
+                {
+                    warn!("Skipping unsupported at-rule `{}`", statement.trim());
+                    rest = after.trim();
+                    continue;
+                }
+            }
+            let (prelude, block) = rest
+                .split_once('{')
+                .ok_or_else(|| ParseGradientError::InvalidValue(rest.to_string()))?;
+            let (declarations, after) = split_block(block)
+                .ok_or_else(|| ParseGradientError::InvalidValue(block.to_string()))?;
+            rest = after.trim();
+
+            let prelude = prelude.trim();
+            if prelude.starts_with('@') {
+                warn!("Skipping unsupported at-rule `{prelude}`");
+                continue;
+            }
+            let selectors = prelude
+                .split(',')
+                .filter_map(|selector| {
+                    let classes = parse_class_selector(selector);
+                    if classes.is_none() {
+                        warn!("Skipping unsupported selector `{}`", selector.trim());
+                    }
+                    classes
+                })
+                .collect::<Vec<_>>();
+            if selectors.is_empty() {
+                continue;
+            }
+
+            let mut properties = vec![];
+            for declaration in split_top_level(declarations, |c| c == ';')? {
+                if declaration.is_empty() {
+                    continue;
+                }
+                let Some((name, value)) = declaration.split_once(':') else {
+                    warn!("Skipping invalid declaration `{declaration}`");
+                    continue;
+                };
+                match GradientProperty::parse(name, value) {
+                    Some(Ok(property)) => properties.push(property),
+                    Some(Err(error)) if is_unsupported_value(value, &error) => {
+                        warn!(
+                            "Skipping unsupported `{}` value `{}`",
+                            name.trim(),
+                            value.trim()
+                        );
+                    }
+                    Some(Err(error)) => return Err(error),
+                    None => {}
+                }
+            }
+            rules.push(GradientRule {
+                selectors,
+                properties,
+            });
+        }
+        Ok(Self { rules })
+    }
+}
+
+/// Splits the contents of a block from the text after its closing brace, skipping nested blocks
+fn split_block(block: &str) -> Option<(&str, &str)> {
+    let mut depth = 0;
+    for (index, c) in block.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some((&block[..index], &block[index + 1..])),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses a selector like `.panel.active` into its classes.
+/// Returns `None` if the selector isn't a compound of class selectors.
+fn parse_class_selector(selector: &str) -> Option<Vec<String>> {
+    let classes = selector
+        .trim()
+        .strip_prefix('.')?
+        .split('.')
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if classes.iter().any(|class| {
+        class.is_empty()
+            || !class
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    }) {
+        return None;
+    }
+    Some(classes)
+}
+
+/// Returns true if a gradient property's `value` failed to parse because it's unsupported, like
+/// `url(..)` images and keywords, rather than because it has a malformed gradient
+fn is_unsupported_value(value: &str, error: &ParseGradientError) -> bool {
+    matches!(error, ParseGradientError::UnsupportedFunction(_))
+        || !value.to_ascii_lowercase().contains("gradient(")
+}
+
+/// The classes of a node, matched against the selectors of the [`GradientStylesheet`]
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct GradientClass(pub Vec<String>);
+
+impl GradientClass {
+    /// Creates a `GradientClass` from a list of whitespace separated classes, like an HTML `class` attribute
+    pub fn new(classes: &str) -> Self {
+        Self(classes.split_whitespace().map(str::to_string).collect())
+    }
+
+    /// Returns true if the node has the class `name`
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|class| class == name)
+    }
+}
+
+impl From<&str> for GradientClass {
+    fn from(classes: &str) -> Self {
+        Self::new(classes)
+    }
+}
+
+/// Sets the gradients of nodes whose [`GradientClass`] changed, or of every classed node when the
+/// [`GradientStylesheet`] changes
+pub fn apply_gradient_stylesheet(
+    mut commands: Commands,
+    stylesheet: Res<GradientStylesheet>,
+    nodes: Query<(Entity, Ref<GradientClass>)>,
+) {
+    for (entity, class) in nodes.iter() {
+        if !stylesheet.is_changed() && !class.is_changed() {
+            continue;
+        }
+        let mut background = None;
+        let mut border = None;
+        for property in stylesheet.matching_properties(&class) {
+            match property {
+                GradientProperty::Background(_) => background = Some(property),
+                GradientProperty::BorderImage(_) => border = Some(property),
+            }
+        }
+        let mut entity = commands.entity(entity);
+        for property in background.into_iter().chain(border) {
+            property.apply(&mut entity);
+        }
+    }
+}
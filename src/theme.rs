@@ -0,0 +1,253 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        change_detection::DetectChanges,
+        resource::Resource,
+        system::{Query, Res},
+        world::Ref,
+    },
+    platform::collections::HashMap,
+    time::Time,
+};
+use std::borrow::Cow;
+
+/// The light or dark variant of a [`GradientTheme`]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum GradientThemeVariant {
+    #[default]
+    Light,
+    Dark,
+}
+
+/// A registry of named gradient stacks, looked up by [`ThemedGradient`] and [`ThemedBorderGradient`].
+///
+/// Changing the theme restyles every themed node.
+/// Tokens are strings, like `"panel.header"`. Enum tokens can be used by implementing `Into<Cow<'static, str>>` for the enum.
+///
+/// Tokens can have a separate dark variant, used in place of the default gradients while the
+/// theme's variant is [`GradientThemeVariant::Dark`]. Tokens without a dark variant use their default gradients.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct GradientTheme {
+    /// The default, light gradients for each token
+    pub gradients: HashMap<Cow<'static, str>, Vec<Gradient>>,
+    /// Dark variants of the gradients
+    pub dark: HashMap<Cow<'static, str>, Vec<Gradient>>,
+    /// The active variant
+    pub variant: GradientThemeVariant,
+    /// When the theme changes, themed nodes interpolate to their new gradients over this many seconds.
+    /// If zero, the new gradients are displayed immediately.
+    pub transition_duration: f32,
+}
+
+impl GradientTheme {
+    /// Adds or replaces the gradients for `token`
+    pub fn insert(
+        &mut self,
+        token: impl Into<Cow<'static, str>>,
+        gradients: impl Into<BackgroundGradient>,
+    ) {
+        self.gradients.insert(token.into(), gradients.into().0);
+    }
+
+    /// Adds or replaces the dark variant of the gradients for `token`
+    pub fn insert_dark(
+        &mut self,
+        token: impl Into<Cow<'static, str>>,
+        gradients: impl Into<BackgroundGradient>,
+    ) {
+        self.dark.insert(token.into(), gradients.into().0);
+    }
+
+    /// Builder version of [`GradientTheme::insert`]
+    pub fn with(
+        mut self,
+        token: impl Into<Cow<'static, str>>,
+        gradients: impl Into<BackgroundGradient>,
+    ) -> Self {
+        self.insert(token, gradients);
+        self
+    }
+
+    /// Builder version of [`GradientTheme::insert_dark`]
+    pub fn with_dark(
+        mut self,
+        token: impl Into<Cow<'static, str>>,
+        gradients: impl Into<BackgroundGradient>,
+    ) -> Self {
+        self.insert_dark(token, gradients);
+        self
+    }
+
+    /// Sets the transition duration in seconds
+    pub fn with_transition_duration(mut self, duration: f32) -> Self {
+        self.transition_duration = duration;
+        self
+    }
+
+    /// Switches to `variant`, with themed nodes interpolating to their new gradients over `duration` seconds.
+    pub fn switch_variant(&mut self, variant: GradientThemeVariant, duration: f32) {
+        self.variant = variant;
+        self.transition_duration = duration;
+    }
+
+    /// Switches between the light and dark variants, with themed nodes interpolating to their new
+    /// gradients over `duration` seconds.
+    pub fn toggle_variant(&mut self, duration: f32) {
+        let variant = match self.variant {
+            GradientThemeVariant::Light => GradientThemeVariant::Dark,
+            GradientThemeVariant::Dark => GradientThemeVariant::Light,
+        };
+        self.switch_variant(variant, duration);
+    }
+
+    /// The gradients for `token` in the active variant
+    pub fn get(&self, token: &str) -> Option<&[Gradient]> {
+        self.get_variant(token, self.variant)
+    }
+
+    /// The gradients for `token` in `variant`
+    pub fn get_variant(&self, token: &str, variant: GradientThemeVariant) -> Option<&[Gradient]> {
+        match variant {
+            GradientThemeVariant::Dark => {
+                self.dark.get(token).or_else(|| self.gradients.get(token))
+            }
+            GradientThemeVariant::Light => self.gradients.get(token),
+        }
+        .map(Vec::as_slice)
+    }
+}
+
+/// Sets the node's [`BackgroundGradient`] from a token in the [`GradientTheme`].
+/// If the token isn't in the theme the node's gradients are left unchanged.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
+#[require(BackgroundGradient, ThemedGradientTransition)]
+pub struct ThemedGradient(pub Cow<'static, str>);
+
+impl ThemedGradient {
+    pub fn new(token: impl Into<Cow<'static, str>>) -> Self {
+        Self(token.into())
+    }
+}
+
+/// Sets the node's [`BorderGradient`] from a token in the [`GradientTheme`].
+/// If the token isn't in the theme the node's gradients are left unchanged.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
+#[require(BorderGradient, ThemedBorderGradientTransition)]
+pub struct ThemedBorderGradient(pub Cow<'static, str>);
+
+impl ThemedBorderGradient {
+    pub fn new(token: impl Into<Cow<'static, str>>) -> Self {
+        Self(token.into())
+    }
+}
+
+/// The state of a themed node's transition to new gradients after the theme changes
+#[derive(Clone, Debug)]
+struct ThemeTransition {
+    from: Vec<Gradient>,
+    elapsed: f32,
+}
+
+impl Default for ThemeTransition {
+    fn default() -> Self {
+        Self {
+            from: vec![],
+            elapsed: f32::INFINITY,
+        }
+    }
+}
+
+impl ThemeTransition {
+    /// Returns the node's next gradients, or `None` if they don't need updating
+    fn update(
+        &mut self,
+        theme: &GradientTheme,
+        token: &str,
+        token_changed: bool,
+        theme_changed: bool,
+        delta_secs: f32,
+        current: &[Gradient],
+    ) -> Option<Vec<Gradient>> {
+        let target = theme.get(token)?;
+        if token_changed {
+            // New nodes and tokens are displayed immediately
+            self.elapsed = f32::INFINITY;
+        } else if theme_changed {
+            self.from = current.to_vec();
+            self.elapsed = 0.;
+        } else if theme.transition_duration < self.elapsed {
+            return None;
+        }
+        self.elapsed += delta_secs;
+        let t = if 0. < theme.transition_duration {
+            self.elapsed / theme.transition_duration
+        } else {
+            1.
+        };
+        let next = lerp_gradient_stacks(&self.from, target, t);
+        (current != next).then_some(next)
+    }
+}
+
+/// Tracks the transition of a [`ThemedGradient`] node's background gradients between themes.
+/// Added automatically with [`ThemedGradient`].
+#[derive(Component, Default, Clone, Debug)]
+pub struct ThemedGradientTransition(ThemeTransition);
+
+/// Tracks the transition of a [`ThemedBorderGradient`] node's border gradients between themes.
+/// Added automatically with [`ThemedBorderGradient`].
+#[derive(Component, Default, Clone, Debug)]
+pub struct ThemedBorderGradientTransition(ThemeTransition);
+
+/// Updates the gradients of themed nodes when the [`GradientTheme`] or their tokens change,
+/// interpolating to the new gradients over the theme's transition duration.
+pub fn apply_gradient_theme(
+    time: Res<Time>,
+    theme: Res<GradientTheme>,
+    accessibility: Res<GradientAccessibility>,
+    mut backgrounds: Query<(
+        Ref<ThemedGradient>,
+        &mut BackgroundGradient,
+        &mut ThemedGradientTransition,
+    )>,
+    mut borders: Query<(
+        Ref<ThemedBorderGradient>,
+        &mut BorderGradient,
+        &mut ThemedBorderGradientTransition,
+    )>,
+) {
+    let theme_changed = theme.is_changed();
+    // With reduced motion transitions complete immediately
+    let delta_secs = if accessibility.reduced_motion {
+        f32::INFINITY
+    } else {
+        time.delta_secs()
+    };
+    for (token, mut background, mut transition) in backgrounds.iter_mut() {
+        if let Some(next) = transition.0.update(
+            &theme,
+            &token.0,
+            token.is_changed(),
+            theme_changed,
+            delta_secs,
+            &background.0,
+        ) {
+            background.0 = next;
+        }
+    }
+    for (token, mut border, mut transition) in borders.iter_mut() {
+        if let Some(next) = transition.0.update(
+            &theme,
+            &token.0,
+            token.is_changed(),
+            theme_changed,
+            delta_secs,
+            &border.0,
+        ) {
+            border.0 = next;
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::*;
+use bevy::{
+    ecs::{
+        change_detection::DetectChanges,
+        system::{Query, Res},
+    },
+    math::curve::{Curve, EaseFunction},
+    time::Time,
+};
+
+/// How a [`GradientTimeline`] continues after reaching its last keyframe
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum TimelineLoop {
+    /// Play once and hold the last keyframe
+    #[default]
+    Once,
+    /// Restart from the first keyframe
+    Repeat,
+    /// Play backwards to the first keyframe, then forwards again
+    PingPong,
+}
+
+/// A keyframe in a [`GradientTimeline`]
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
+pub struct GradientKeyframe {
+    /// Time of the keyframe in seconds from the start of the timeline
+    pub time: f32,
+    /// The gradients displayed at this keyframe
+    pub gradients: BackgroundGradient,
+    /// The easing of the transition from this keyframe to the next
+    pub easing: EaseFunction,
+}
+
+/// Animates a node's [`BackgroundGradient`] through a sequence of timestamped keyframes.
+///
+/// Between keyframes, gradients of the same kind are interpolated smoothly, even with different numbers of stops.
+/// Otherwise the next keyframe's gradients are faded in over the previous keyframe's.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+#[require(BackgroundGradient)]
+pub struct GradientTimeline {
+    /// The keyframes, sorted by time
+    pub keyframes: Vec<GradientKeyframe>,
+    /// What happens after the last keyframe is reached
+    pub loop_mode: TimelineLoop,
+    /// Playback rate multiplier
+    pub speed: f32,
+    /// Seconds played since the start of the timeline, scaled by `speed`
+    pub elapsed: f32,
+    /// If true, the timeline doesn't advance
+    pub paused: bool,
+}
+
+impl GradientTimeline {
+    /// Create an empty timeline
+    pub fn new(loop_mode: TimelineLoop) -> Self {
+        Self {
+            keyframes: vec![],
+            loop_mode,
+            speed: 1.,
+            elapsed: 0.,
+            paused: false,
+        }
+    }
+
+    /// Adds a keyframe with linear easing to the next keyframe
+    pub fn with_keyframe(self, time: f32, gradients: impl Into<BackgroundGradient>) -> Self {
+        self.with_eased_keyframe(time, gradients, EaseFunction::Linear)
+    }
+
+    /// Adds a keyframe with the given easing to the next keyframe
+    pub fn with_eased_keyframe(
+        mut self,
+        time: f32,
+        gradients: impl Into<BackgroundGradient>,
+        easing: EaseFunction,
+    ) -> Self {
+        self.insert_keyframe(GradientKeyframe {
+            time,
+            gradients: gradients.into(),
+            easing,
+        });
+        self
+    }
+
+    /// Sets the playback rate
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Inserts a keyframe, keeping the keyframes sorted by time.
+    /// Keyframes at the same time are kept in insertion order.
+    pub fn insert_keyframe(&mut self, keyframe: GradientKeyframe) {
+        let index = self
+            .keyframes
+            .partition_point(|other| other.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+    }
+
+    /// The time of the last keyframe
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0., |keyframe| keyframe.time)
+    }
+
+    /// True if a timeline with [`TimelineLoop::Once`] has reached its last keyframe
+    pub fn is_finished(&self) -> bool {
+        self.loop_mode == TimelineLoop::Once && self.duration() <= self.elapsed
+    }
+
+    /// Seeks to `time` seconds from the start of the timeline
+    pub fn seek(&mut self, time: f32) {
+        self.elapsed = time;
+    }
+
+    /// Restarts the timeline from the first keyframe
+    pub fn restart(&mut self) {
+        self.seek(0.);
+    }
+
+    /// The gradients at `time` seconds from the start of the timeline, after applying the loop mode.
+    pub fn sample(&self, time: f32) -> Vec<Gradient> {
+        let (Some(first), Some(last)) = (self.keyframes.first(), self.keyframes.last()) else {
+            return vec![];
+        };
+        let duration = last.time - first.time;
+        let mut time = time - first.time;
+        if 0. < duration {
+            time = match self.loop_mode {
+                TimelineLoop::Once => time.clamp(0., duration),
+                TimelineLoop::Repeat => time.rem_euclid(duration),
+                TimelineLoop::PingPong => {
+                    duration - (time.rem_euclid(2. * duration) - duration).abs()
+                }
+            };
+        }
+        let time = time + first.time;
+
+        let next = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+        if next == 0 {
+            return first.gradients.0.clone();
+        }
+        if next == self.keyframes.len() {
+            return last.gradients.0.clone();
+        }
+        let from = &self.keyframes[next - 1];
+        let to = &self.keyframes[next];
+        let t = (time - from.time) / (to.time - from.time);
+        lerp_gradient_stacks(
+            &from.gradients.0,
+            &to.gradients.0,
+            from.easing.sample_clamped(t),
+        )
+    }
+}
+
+/// Advances [`GradientTimeline`]s and updates their nodes' [`BackgroundGradient`]s
+pub fn update_gradient_timelines(
+    time: Res<Time>,
+    accessibility: Res<GradientAccessibility>,
+    mut query: Query<(&mut GradientTimeline, &mut BackgroundGradient)>,
+) {
+    for (mut timeline, mut background) in query.iter_mut() {
+        if !timeline.paused && !timeline.is_finished() && !accessibility.reduced_motion {
+            timeline.elapsed += time.delta_secs() * timeline.speed;
+        }
+        if !timeline.is_changed() {
+            continue;
+        }
+        let gradients = timeline.sample(timeline.elapsed);
+        if background.0 != gradients {
+            background.0 = gradients;
+        }
+    }
+}
@@ -0,0 +1,657 @@
+use crate::render::{compute_angular_color_stops, compute_color_stops};
+use crate::*;
+use bevy::{
+    animation::animatable::{Animatable, BlendInput},
+    color::{Alpha, Mix},
+    ecs::{
+        change_detection::{DetectChanges, DetectChangesMut},
+        system::{Query, Res},
+    },
+    math::{
+        FloatExt,
+        curve::{Curve, Ease, FunctionCurve, Interval},
+    },
+    time::Time,
+    ui::Interaction,
+};
+
+/// Interpolates between two stacks of gradients.
+///
+/// Pairs of gradients of the same kind are interpolated stop by stop, after resampling them
+/// to a common set of stops if their stops or spread methods don't match, see [`Gradient::mix`].
+/// Values with different units switch halfway through. If the stacks can't be interpolated,
+/// `to` is drawn over `from` with its opacity scaled by `t`.
+pub(crate) fn lerp_gradient_stacks(from: &[Gradient], to: &[Gradient], t: f32) -> Vec<Gradient> {
+    if t <= 0. {
+        return from.to_vec();
+    }
+    if 1. <= t {
+        return to.to_vec();
+    }
+
+    if from.len() == to.len() {
+        if let Some(gradients) = from
+            .iter()
+            .zip(to)
+            .map(|(a, b)| lerp_gradient(a, b, t).or_else(|| resample_and_lerp(a, b, t)))
+            .collect()
+        {
+            return gradients;
+        }
+    }
+
+    from.iter()
+        .cloned()
+        .chain(to.iter().map(|gradient| scale_alpha(gradient, t)))
+        .collect()
+}
+
+fn lerp_gradient(a: &Gradient, b: &Gradient, t: f32) -> Option<Gradient> {
+    if a.layer().spread != b.layer().spread {
+        return None;
+    }
+    match (a, b) {
+        (Gradient::Linear(a), Gradient::Linear(b)) if a.stops.len() == b.stops.len() => {
+            Some(Gradient::Linear(LinearGradient {
+                angle: a.angle.lerp(b.angle, t),
+                endpoints: match (a.endpoints, b.endpoints) {
+                    (Some(a), Some(b)) => Some(GradientEndpoints {
+                        start: lerp_position(a.start, b.start, t),
+                        end: lerp_position(a.end, b.end, t),
+                    }),
+                    _ => step(a.endpoints, b.endpoints, t),
+                },
+                stops: lerp_color_stops(&a.stops, &b.stops, t),
+                layer: lerp_layer(&a.layer, &b.layer, t),
+            }))
+        }
+        (Gradient::Radial(a), Gradient::Radial(b)) if a.stops.len() == b.stops.len() => {
+            Some(Gradient::Radial(RadialGradient {
+                position: lerp_position(a.position, b.position, t),
+                shape: lerp_shape(a.shape, b.shape, t),
+                falloff: a.falloff.lerp(b.falloff, t),
+                stops: lerp_color_stops(&a.stops, &b.stops, t),
+                layer: lerp_layer(&a.layer, &b.layer, t),
+            }))
+        }
+        (Gradient::Conic(a), Gradient::Conic(b)) if a.stops.len() == b.stops.len() => {
+            Some(Gradient::Conic(ConicGradient {
+                start: a.start.lerp(b.start, t),
+                position: lerp_position(a.position, b.position, t),
+                aspect_ratio: a.aspect_ratio.lerp(b.aspect_ratio, t),
+                end: match (a.end, b.end) {
+                    (Some(a), Some(b)) => Some(a.lerp(b, t)),
+                    (a, b) => step(a, b, t),
+                },
+                inner_radius: lerp_val(a.inner_radius, b.inner_radius, t),
+                outer_radius: lerp_val(a.outer_radius, b.outer_radius, t),
+                stops: a
+                    .stops
+                    .iter()
+                    .zip(&b.stops)
+                    .map(|(a, b)| AngularColorStop::interpolate(a, b, t))
+                    .collect(),
+                layer: lerp_layer(&a.layer, &b.layer, t),
+            }))
+        }
+        (Gradient::Inset(a), Gradient::Inset(b)) if a.stops.len() == b.stops.len() => {
+            Some(Gradient::Inset(InsetGradient {
+                stops: lerp_color_stops(&a.stops, &b.stops, t),
+                layer: lerp_layer(&a.layer, &b.layer, t),
+            }))
+        }
+        (Gradient::Plasma(a), Gradient::Plasma(b)) if a.stops.len() == b.stops.len() => {
+            Some(Gradient::Plasma(PlasmaGradient {
+                stops: lerp_color_stops(&a.stops, &b.stops, t),
+                scale: a.scale.lerp(b.scale, t),
+                speed: a.speed.lerp(b.speed, t),
+                layer: lerp_layer(&a.layer, &b.layer, t),
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// Interpolates the properties shared by every kind of gradient.
+/// The spread is taken from `a`, as [`lerp_gradient`] only interpolates gradients with matching spreads.
+fn lerp_layer(a: &GradientLayer, b: &GradientLayer, t: f32) -> GradientLayer {
+    GradientLayer {
+        spread: a.spread,
+        grain: lerp_grain(a.grain, b.grain, t),
+        clip: step(a.clip, b.clip, t),
+        origin: step(a.origin, b.origin, t),
+        tiling: step(a.tiling, b.tiling, t),
+        attachment: step(a.attachment, b.attachment, t),
+        filter: lerp_filter(a.filter, b.filter, t),
+        custom_function: step(a.custom_function, b.custom_function, t),
+        smoothing: step(a.smoothing, b.smoothing, t),
+        blend_mode: step(a.blend_mode, b.blend_mode, t),
+        texture: lerp_texture(&a.texture, &b.texture, t),
+    }
+}
+
+/// The nominal length in pixels of the gradient line that stops positioned in pixels are resolved against
+/// when gradients are resampled, as there is no node to resolve them for
+const RESAMPLE_LINE_LENGTH: f32 = 100.;
+
+/// A resolved color stop: its color, position, hint, interpolation and oscillation
+type ResolvedStop = (LinearRgba, f32, f32, Interpolation, StopOscillation);
+
+impl Gradient {
+    /// Interpolates from the gradient to `other` at `t`, between `0.` and `1.`.
+    ///
+    /// Gradients of the same kind with matching stops are interpolated stop by stop. Otherwise both gradients
+    /// are first resampled at the union of their stop positions, so that each has a stop wherever the other
+    /// does, and theme transitions between gradients with different numbers of stops stay smooth.
+    /// Stops at the same position keep their hard edges. Resampled stops are linear, without hints.
+    ///
+    /// Gradients of different kinds are converted to linear ramps of their stops first, along the angle of
+    /// the linear gradient if one of them is linear, or to the bottom otherwise.
+    /// Plasma gradients can't be converted, so mixing one with a gradient of another kind switches halfway through.
+    ///
+    /// Stop positions are resampled as fractions of the gradient line, or of the full turn for conic gradients.
+    /// Positions in pixels are resolved against a nominal 100 pixel gradient line.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_gradients::*;
+    /// let a: Gradient = LinearGradient::to_right(vec![Color::BLACK.into(), Color::WHITE.into()]).into();
+    /// let b: Gradient = RadialGradient::new(
+    ///     Position::CENTER,
+    ///     RadialGradientShape::FarthestCorner,
+    ///     ColorStop::percentages(&[(0., Color::WHITE), (40., Color::BLACK), (100., Color::WHITE)]),
+    /// )
+    /// .into();
+    /// let mixed = a.mix(&b, 0.5);
+    /// assert!(matches!(mixed, Gradient::Linear(_)));
+    /// assert_eq!(mixed.authored_stops_len(), 3);
+    /// ```
+    pub fn mix(&self, other: &Gradient, t: f32) -> Gradient {
+        if t <= 0. {
+            return self.clone();
+        }
+        if 1. <= t {
+            return other.clone();
+        }
+        if let Some(gradient) =
+            lerp_gradient(self, other, t).or_else(|| resample_and_lerp(self, other, t))
+        {
+            return gradient;
+        }
+        let (a, b) = match (self, other) {
+            (Gradient::Plasma(_), _) | (_, Gradient::Plasma(_)) => {
+                return step(self, other, t).clone();
+            }
+            (Gradient::Linear(linear), _) => (self.clone(), to_linear_ramp(other, linear.angle)),
+            (_, Gradient::Linear(linear)) => (to_linear_ramp(self, linear.angle), other.clone()),
+            _ => (
+                to_linear_ramp(self, LinearGradient::TO_BOTTOM),
+                to_linear_ramp(other, LinearGradient::TO_BOTTOM),
+            ),
+        };
+        resample_and_lerp(&a, &b, t).unwrap_or_else(|| step(self, other, t).clone())
+    }
+}
+
+/// Resamples two gradients of the same kind at the union of their stop positions and interpolates them.
+/// Returns `None` if the gradients are of different kinds.
+fn resample_and_lerp(a: &Gradient, b: &Gradient, t: f32) -> Option<Gradient> {
+    if core::mem::discriminant(a) != core::mem::discriminant(b) {
+        return None;
+    }
+    let stops = resample_stops(&resolve_stop_fractions(a), &resolve_stop_fractions(b));
+    let a_stops = stops.iter().map(|&(position, color, _)| (color, position));
+    let b_stops = stops.iter().map(|&(position, _, color)| (color, position));
+    let spread = step(a.layer().spread, b.layer().spread, t);
+    lerp_gradient(
+        &with_resampled_stops(a, a_stops).with_spread(spread),
+        &with_resampled_stops(b, b_stops).with_spread(spread),
+        t,
+    )
+}
+
+/// The gradient's resolved stops, positioned as fractions of the gradient line or of the full turn
+fn resolve_stop_fractions(gradient: &Gradient) -> Vec<ResolvedStop> {
+    let mut scratch = vec![];
+    let mut stops = vec![];
+    let length = match gradient {
+        Gradient::Conic(conic) => {
+            compute_angular_color_stops(&conic.stops, &mut scratch, &mut stops);
+            TAU
+        }
+        Gradient::Linear(LinearGradient {
+            stops: color_stops, ..
+        })
+        | Gradient::Radial(RadialGradient {
+            stops: color_stops, ..
+        })
+        | Gradient::Inset(InsetGradient {
+            stops: color_stops, ..
+        })
+        | Gradient::Plasma(PlasmaGradient {
+            stops: color_stops, ..
+        }) => {
+            let length = RESAMPLE_LINE_LENGTH;
+            compute_color_stops(
+                color_stops,
+                1.,
+                length,
+                Vec2::splat(length),
+                &mut scratch,
+                &mut stops,
+            );
+            length
+        }
+    };
+    for stop in &mut stops {
+        stop.1 /= length;
+    }
+    stops
+}
+
+/// Samples both lists of resolved stops at the union of their positions, returning each position with
+/// the colors of `a` and `b` there. Positions with more than one stop in either list are repeated, so hard
+/// edges are kept.
+fn resample_stops(a: &[ResolvedStop], b: &[ResolvedStop]) -> Vec<(f32, LinearRgba, LinearRgba)> {
+    let mut resampled = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        let position = a
+            .get(i)
+            .map_or(f32::INFINITY, |stop| stop.1)
+            .min(b.get(j).map_or(f32::INFINITY, |stop| stop.1));
+        let take = |stops: &[ResolvedStop], index: &mut usize| {
+            let start = *index;
+            while stops.get(*index).is_some_and(|stop| stop.1 == position) {
+                *index += 1;
+            }
+            stops[start..*index]
+                .iter()
+                .map(|stop| stop.0)
+                .collect::<Vec<_>>()
+        };
+        let (a_colors, b_colors) = (take(a, &mut i), take(b, &mut j));
+        for k in 0..a_colors.len().max(b_colors.len()) {
+            // Lists without a stop here are sampled, shorter runs of stops repeat their last color
+            let color = |colors: &[LinearRgba], stops| match colors.get(k).or(colors.last()) {
+                Some(&color) => color,
+                None => interpolate_stops(stops, position),
+            };
+            resampled.push((position, color(&a_colors, a), color(&b_colors, b)));
+        }
+    }
+    resampled
+}
+
+/// A copy of the gradient with its stops replaced by linear stops at the resampled positions
+fn with_resampled_stops(
+    gradient: &Gradient,
+    stops: impl Iterator<Item = (LinearRgba, f32)>,
+) -> Gradient {
+    let mut gradient = gradient.clone();
+    match &mut gradient {
+        Gradient::Conic(conic) => {
+            conic.stops = stops
+                .map(|(color, position)| AngularColorStop::new(color, position * TAU))
+                .collect();
+        }
+        Gradient::Linear(LinearGradient {
+            stops: color_stops, ..
+        })
+        | Gradient::Radial(RadialGradient {
+            stops: color_stops, ..
+        })
+        | Gradient::Inset(InsetGradient {
+            stops: color_stops, ..
+        })
+        | Gradient::Plasma(PlasmaGradient {
+            stops: color_stops, ..
+        }) => {
+            *color_stops = stops
+                .map(|(color, position)| ColorStop::percent(color, 100. * position))
+                .collect();
+        }
+    }
+    gradient
+}
+
+/// A linear gradient at `angle` with the resolved stops and the other properties of `gradient`
+fn to_linear_ramp(gradient: &Gradient, angle: Angle) -> Gradient {
+    let stops = resolve_stop_fractions(gradient)
+        .into_iter()
+        .map(|(color, position, hint, interpolation, _)| ColorStop {
+            hint,
+            interpolation,
+            ..ColorStop::percent(color, 100. * position)
+        })
+        .collect();
+    Gradient::Linear(LinearGradient {
+        angle,
+        endpoints: None,
+        stops,
+        layer: gradient.layer().clone(),
+    })
+}
+
+pub(crate) fn lerp_color_stops(a: &[ColorStop], b: &[ColorStop], t: f32) -> ColorStops {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| ColorStop::interpolate(a, b, t))
+        .collect()
+}
+
+/// Blends the inputs in order, interpolating towards each input by its weight.
+///
+/// Positions and stops have no meaningful sum, so additive inputs are blended the same way.
+fn blend_inputs<T: Animatable + Default>(inputs: impl Iterator<Item = BlendInput<T>>) -> T {
+    inputs
+        .fold(None, |value: Option<T>, input| {
+            Some(match value {
+                Some(value) => T::interpolate(&value, &input.value, input.weight),
+                None => input.value,
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// Anchors are interpolated linearly. `Val`s with the same unit are interpolated, otherwise they switch halfway through.
+impl Animatable for Position {
+    fn interpolate(a: &Self, b: &Self, time: f32) -> Self {
+        lerp_position(*a, *b, time)
+    }
+
+    fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self {
+        blend_inputs(inputs)
+    }
+}
+
+impl Ease for Position {
+    fn interpolating_curve_unbounded(start: Self, end: Self) -> impl Curve<Self> {
+        FunctionCurve::new(Interval::EVERYWHERE, move |t| {
+            Self::interpolate(&start, &end, t)
+        })
+    }
+}
+
+/// Colors are mixed in sRGB space, like the gradient shader.
+/// Positions and hints with the same unit are interpolated, otherwise they switch halfway through.
+impl Animatable for ColorStop {
+    fn interpolate(a: &Self, b: &Self, time: f32) -> Self {
+        ColorStop {
+            color: lerp_color(a.color, b.color, time),
+            point: lerp_val(a.point, b.point, time),
+            hint: a.hint.lerp(b.hint, time),
+            hint_point: match (a.hint_point, b.hint_point) {
+                (Some(a), Some(b)) => Some(lerp_val(a, b, time)),
+                (a, b) => step(a, b, time),
+            },
+            interpolation: step(a.interpolation, b.interpolation, time),
+            end_point: match (a.end_point, b.end_point) {
+                (Some(a), Some(b)) => Some(lerp_val(a, b, time)),
+                (a, b) => step(a, b, time),
+            },
+            oscillation: step(a.oscillation, b.oscillation, time),
+        }
+    }
+
+    fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self {
+        blend_inputs(inputs)
+    }
+}
+
+impl Ease for ColorStop {
+    fn interpolating_curve_unbounded(start: Self, end: Self) -> impl Curve<Self> {
+        FunctionCurve::new(Interval::EVERYWHERE, move |t| {
+            Self::interpolate(&start, &end, t)
+        })
+    }
+}
+
+/// Colors are mixed in sRGB space, like the gradient shader.
+/// Explicit angles are interpolated in radians, [`AngularPosition::Auto`] angles switch halfway through.
+impl Animatable for AngularColorStop {
+    fn interpolate(a: &Self, b: &Self, time: f32) -> Self {
+        AngularColorStop {
+            color: lerp_color(a.color, b.color, time),
+            angle: match (a.angle.resolve(), b.angle.resolve()) {
+                (Some(a), Some(b)) => AngularPosition::Radians(a.lerp(b, time)),
+                _ => step(a.angle, b.angle, time),
+            },
+            hint: a.hint.lerp(b.hint, time),
+            interpolation: step(a.interpolation, b.interpolation, time),
+            oscillation: step(a.oscillation, b.oscillation, time),
+        }
+    }
+
+    fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self {
+        blend_inputs(inputs)
+    }
+}
+
+impl Ease for AngularColorStop {
+    fn interpolating_curve_unbounded(start: Self, end: Self) -> impl Curve<Self> {
+        FunctionCurve::new(Interval::EVERYWHERE, move |t| {
+            Self::interpolate(&start, &end, t)
+        })
+    }
+}
+
+/// Mixes colors in sRGB space, like the gradient shader
+fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    Srgba::from(a).mix(&Srgba::from(b), t).into()
+}
+
+fn step<T>(a: T, b: T, t: f32) -> T {
+    if t < 0.5 { a } else { b }
+}
+
+fn lerp_val(a: Val, b: Val, t: f32) -> Val {
+    match (a, b) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(a.lerp(b, t)),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(a.lerp(b, t)),
+        (Val::Vw(a), Val::Vw(b)) => Val::Vw(a.lerp(b, t)),
+        (Val::Vh(a), Val::Vh(b)) => Val::Vh(a.lerp(b, t)),
+        (Val::VMin(a), Val::VMin(b)) => Val::VMin(a.lerp(b, t)),
+        (Val::VMax(a), Val::VMax(b)) => Val::VMax(a.lerp(b, t)),
+        _ => step(a, b, t),
+    }
+}
+
+pub(crate) fn lerp_position(a: Position, b: Position, t: f32) -> Position {
+    Position {
+        anchor: a.anchor.lerp(b.anchor, t),
+        x: lerp_val(a.x, b.x, t),
+        y: lerp_val(a.y, b.y, t),
+    }
+}
+
+fn lerp_grain(a: Option<GradientGrain>, b: Option<GradientGrain>, t: f32) -> Option<GradientGrain> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(GradientGrain {
+            intensity: a.intensity.lerp(b.intensity, t),
+            scale: a.scale.lerp(b.scale, t),
+            animated: step(a.animated, b.animated, t),
+            pattern: step(a.pattern, b.pattern, t),
+        }),
+        // Fade the grain in or out
+        (Some(a), None) => Some(GradientGrain {
+            intensity: a.intensity * (1. - t),
+            ..a
+        }),
+        (None, Some(b)) => Some(GradientGrain {
+            intensity: b.intensity * t,
+            ..b
+        }),
+        (None, None) => None,
+    }
+}
+
+fn lerp_texture(
+    a: &Option<GradientTexture>,
+    b: &Option<GradientTexture>,
+    t: f32,
+) -> Option<GradientTexture> {
+    match (a, b) {
+        (Some(a), Some(b)) if a.image == b.image && a.tile_size == b.tile_size => {
+            let modulation = match (a.modulation, b.modulation) {
+                (TextureModulation::Alpha(a), TextureModulation::Alpha(b)) => {
+                    TextureModulation::Alpha(a.lerp(b, t))
+                }
+                (TextureModulation::Displace(a), TextureModulation::Displace(b)) => {
+                    TextureModulation::Displace(a.lerp(b, t))
+                }
+                (a, b) => step(a, b, t),
+            };
+            Some(GradientTexture {
+                modulation,
+                ..a.clone()
+            })
+        }
+        (a, b) => step(a.clone(), b.clone(), t),
+    }
+}
+
+fn lerp_filter(
+    a: Option<GradientFilter>,
+    b: Option<GradientFilter>,
+    t: f32,
+) -> Option<GradientFilter> {
+    if a.is_none() && b.is_none() {
+        return None;
+    }
+    // A missing filter is interpolated as the identity
+    let (a, b) = (a.unwrap_or_default(), b.unwrap_or_default());
+    Some(GradientFilter {
+        hue_rotate: a.hue_rotate.lerp(b.hue_rotate, t),
+        saturate: a.saturate.lerp(b.saturate, t),
+        grayscale: a.grayscale.lerp(b.grayscale, t),
+        brightness: a.brightness.lerp(b.brightness, t),
+    })
+}
+
+fn lerp_shape(a: RadialGradientShape, b: RadialGradientShape, t: f32) -> RadialGradientShape {
+    match (a, b) {
+        (RadialGradientShape::Circle(a), RadialGradientShape::Circle(b)) => {
+            RadialGradientShape::Circle(lerp_val(a, b, t))
+        }
+        (RadialGradientShape::Ellipse(ax, ay), RadialGradientShape::Ellipse(bx, by)) => {
+            RadialGradientShape::Ellipse(lerp_val(ax, bx, t), lerp_val(ay, by, t))
+        }
+        _ => step(a, b, t),
+    }
+}
+
+/// Multiplies the alpha of each of the gradient's stops by `factor`
+fn scale_alpha(gradient: &Gradient, factor: f32) -> Gradient {
+    let scale = |color: Color| color.with_alpha(color.alpha() * factor);
+    let mut gradient = gradient.clone();
+    for color in gradient.colors_mut() {
+        *color = scale(*color);
+    }
+    gradient
+}
+
+/// Cross-fades a button's [`BackgroundGradient`] between gradients for each of its [`Interaction`] states.
+///
+/// Gradients of the same kind are interpolated smoothly, even with different numbers of stops.
+/// Otherwise the new gradient is faded in over the old one.
+#[derive(Component, Clone, Debug, Reflect)]
+#[require(Interaction, BackgroundGradient)]
+pub struct InteractionGradients {
+    /// Displayed when the node isn't hovered or pressed
+    pub none: BackgroundGradient,
+    /// Displayed while the node is hovered
+    pub hovered: BackgroundGradient,
+    /// Displayed while the node is pressed
+    pub pressed: BackgroundGradient,
+    /// The duration of the transition between states in seconds
+    pub duration: f32,
+    #[reflect(ignore)]
+    state: Option<(Interaction, Vec<Gradient>, f32)>,
+}
+
+impl InteractionGradients {
+    /// The default transition duration in seconds
+    pub const DEFAULT_DURATION: f32 = 0.15;
+
+    /// Create a new `InteractionGradients`
+    pub fn new(
+        none: impl Into<BackgroundGradient>,
+        hovered: impl Into<BackgroundGradient>,
+        pressed: impl Into<BackgroundGradient>,
+    ) -> Self {
+        Self {
+            none: none.into(),
+            hovered: hovered.into(),
+            pressed: pressed.into(),
+            duration: Self::DEFAULT_DURATION,
+            state: None,
+        }
+    }
+
+    /// Sets the duration of the transition between states in seconds
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// The gradients for the given interaction state
+    pub fn get(&self, interaction: Interaction) -> &BackgroundGradient {
+        match interaction {
+            Interaction::Pressed => &self.pressed,
+            Interaction::Hovered => &self.hovered,
+            Interaction::None => &self.none,
+        }
+    }
+}
+
+/// Updates the [`BackgroundGradient`]s of nodes with [`InteractionGradients`], cross-fading between states.
+pub fn update_interaction_gradients(
+    time: Res<Time>,
+    accessibility: Res<GradientAccessibility>,
+    mut query: Query<(
+        &Interaction,
+        &mut InteractionGradients,
+        &mut BackgroundGradient,
+    )>,
+) {
+    for (interaction, mut gradients, mut background) in query.iter_mut() {
+        let edited = gradients.is_changed();
+        // The transition state is internal, updating it doesn't count as a change
+        let gradients = gradients.bypass_change_detection();
+        let target = match &mut gradients.state {
+            // The first state is displayed immediately
+            None => {
+                gradients.state = Some((*interaction, vec![], f32::INFINITY));
+                gradients.get(*interaction).0.clone()
+            }
+            Some((current, from, elapsed)) => {
+                if *current != *interaction {
+                    *current = *interaction;
+                    *from = background.0.clone();
+                    *elapsed = 0.;
+                } else if gradients.duration <= *elapsed && !edited {
+                    // The transition is done and the target gradients haven't been edited since
+                    continue;
+                }
+                *elapsed += if accessibility.reduced_motion {
+                    f32::INFINITY
+                } else {
+                    time.delta_secs()
+                };
+                let t = if 0. < gradients.duration {
+                    *elapsed / gradients.duration
+                } else {
+                    1.
+                };
+                let target = match *current {
+                    Interaction::Pressed => &gradients.pressed,
+                    Interaction::Hovered => &gradients.hovered,
+                    Interaction::None => &gradients.none,
+                };
+                lerp_gradient_stacks(from, &target.0, t)
+            }
+        };
+        background.set_if_neq(BackgroundGradient(target));
+    }
+}
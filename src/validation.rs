@@ -0,0 +1,217 @@
+use crate::*;
+use bevy::{ecs::resource::Resource, log::warn_once, prelude::ReflectResource};
+use core::fmt;
+
+/// What the gradient renderer does when it finds an invalid value in a gradient: a hint outside of `0.` to `1.`,
+/// a NaN angle, or a NaN or infinite stop position.
+///
+/// Invalid values are always replaced before the gradient is drawn, see [`InvalidGradientValue`], so content bugs
+/// never reach the shader. The policy only controls how they're reported.
+/// [`InvalidValuePolicy::DebugPanic`] surfaces content bugs in development builds without crashing shipping builds.
+///
+/// Insert the resource after adding [`UiGradientsPlugin`] to change the policy.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub enum InvalidValuePolicy {
+    /// Invalid values are silently replaced
+    Clamp,
+    /// Invalid values are replaced, and a warning is logged the first time each kind of invalid value is found
+    #[default]
+    Warn,
+    /// Panics on invalid values in debug builds. In release builds invalid values are replaced with a warning, like `Warn`.
+    DebugPanic,
+}
+
+/// A kind of invalid value found in a gradient
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InvalidGradientValue {
+    /// A hint outside of `0.` to `1.`, which is clamped, or a NaN hint, which is replaced with `0.5`
+    Hint,
+    /// A NaN or infinite angle. Gradient angles are replaced with zero, and conic stops with NaN or infinite
+    /// angles are treated as automatic stops.
+    Angle,
+    /// A NaN or infinite stop position, which is treated as automatic. NaN or infinite end points and hint
+    /// positions are removed.
+    Position,
+}
+
+impl InvalidGradientValue {
+    /// All the kinds of invalid values
+    pub const ALL: [Self; 3] = [Self::Hint, Self::Angle, Self::Position];
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl fmt::Display for InvalidGradientValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hint => write!(f, "gradient hint outside of 0 to 1"),
+            Self::Angle => write!(f, "NaN or infinite gradient angle"),
+            Self::Position => write!(f, "NaN or infinite gradient stop position"),
+        }
+    }
+}
+
+impl InvalidValuePolicy {
+    /// Returns a copy of `gradient` with its invalid values replaced, reporting them according to the policy,
+    /// or `None` if the gradient is valid.
+    ///
+    /// # Panics
+    ///
+    /// With [`InvalidValuePolicy::DebugPanic`] in debug builds, if the gradient has an invalid value.
+    pub fn sanitize(self, gradient: &Gradient) -> Option<Gradient> {
+        if invalid_values(gradient) == 0 {
+            return None;
+        }
+        let mut gradient = gradient.clone();
+        let found = sanitize_gradient(&mut gradient);
+        for kind in InvalidGradientValue::ALL {
+            if found & kind.bit() != 0 {
+                self.report(kind);
+            }
+        }
+        Some(gradient)
+    }
+
+    fn report(self, kind: InvalidGradientValue) {
+        match self {
+            InvalidValuePolicy::Clamp => {}
+            InvalidValuePolicy::Warn => warn_invalid(kind),
+            InvalidValuePolicy::DebugPanic => {
+                if cfg!(debug_assertions) {
+                    panic!("invalid gradient value: {kind}");
+                }
+                warn_invalid(kind);
+            }
+        }
+    }
+}
+
+/// Logs a warning the first time each kind of invalid value is found
+fn warn_invalid(kind: InvalidGradientValue) {
+    match kind {
+        InvalidGradientValue::Hint => warn_once!("Invalid gradient value replaced: {kind}"),
+        InvalidGradientValue::Angle => warn_once!("Invalid gradient value replaced: {kind}"),
+        InvalidGradientValue::Position => warn_once!("Invalid gradient value replaced: {kind}"),
+    }
+}
+
+/// The kinds of invalid values in `gradient`, as bits
+fn invalid_values(gradient: &Gradient) -> u8 {
+    let mut found = 0;
+    match gradient {
+        Gradient::Linear(LinearGradient { angle, stops, .. }) => {
+            found |= sanitize_angle(&mut { *angle });
+            found |= color_stops_invalid_values(stops);
+        }
+        Gradient::Radial(RadialGradient { stops, .. })
+        | Gradient::Inset(InsetGradient { stops, .. })
+        | Gradient::Plasma(PlasmaGradient { stops, .. }) => {
+            found |= color_stops_invalid_values(stops);
+        }
+        Gradient::Conic(ConicGradient { start, stops, .. }) => {
+            found |= sanitize_angle(&mut { *start });
+            for stop in stops {
+                found |= sanitize_angular_color_stop(&mut { *stop });
+            }
+        }
+    }
+    found
+}
+
+fn color_stops_invalid_values(stops: &[ColorStop]) -> u8 {
+    stops
+        .iter()
+        .fold(0, |found, stop| found | sanitize_color_stop(&mut { *stop }))
+}
+
+/// Replaces the invalid values in `gradient`, returning the kinds found as bits
+fn sanitize_gradient(gradient: &mut Gradient) -> u8 {
+    let mut found = 0;
+    match gradient {
+        Gradient::Linear(LinearGradient { angle, stops, .. }) => {
+            found |= sanitize_angle(angle);
+            for stop in stops {
+                found |= sanitize_color_stop(stop);
+            }
+        }
+        Gradient::Radial(RadialGradient { stops, .. })
+        | Gradient::Inset(InsetGradient { stops, .. })
+        | Gradient::Plasma(PlasmaGradient { stops, .. }) => {
+            for stop in stops {
+                found |= sanitize_color_stop(stop);
+            }
+        }
+        Gradient::Conic(ConicGradient { start, stops, .. }) => {
+            found |= sanitize_angle(start);
+            for stop in stops {
+                found |= sanitize_angular_color_stop(stop);
+            }
+        }
+    }
+    found
+}
+
+fn sanitize_angle(angle: &mut Angle) -> u8 {
+    if angle.to_radians().is_finite() {
+        return 0;
+    }
+    *angle = Angle::ZERO;
+    InvalidGradientValue::Angle.bit()
+}
+
+fn sanitize_hint(hint: &mut f32) -> u8 {
+    if (0. ..=1.).contains(hint) {
+        return 0;
+    }
+    *hint = if hint.is_nan() {
+        0.5
+    } else {
+        hint.clamp(0., 1.)
+    };
+    InvalidGradientValue::Hint.bit()
+}
+
+fn is_finite_val(val: Val) -> bool {
+    match val {
+        Val::Auto => true,
+        Val::Px(value)
+        | Val::Percent(value)
+        | Val::Vw(value)
+        | Val::Vh(value)
+        | Val::VMin(value)
+        | Val::VMax(value) => value.is_finite(),
+    }
+}
+
+fn sanitize_color_stop(stop: &mut ColorStop) -> u8 {
+    let mut found = sanitize_hint(&mut stop.hint);
+    if !is_finite_val(stop.point) {
+        stop.point = Val::Auto;
+        found |= InvalidGradientValue::Position.bit();
+    }
+    for point in [&mut stop.end_point, &mut stop.hint_point] {
+        if point.is_some_and(|point| !is_finite_val(point)) {
+            *point = None;
+            found |= InvalidGradientValue::Position.bit();
+        }
+    }
+    found
+}
+
+fn sanitize_angular_color_stop(stop: &mut AngularColorStop) -> u8 {
+    let mut found = sanitize_hint(&mut stop.hint);
+    let finite = match stop.angle {
+        AngularPosition::Auto => true,
+        AngularPosition::Radians(value)
+        | AngularPosition::Percent(value)
+        | AngularPosition::Turns(value) => value.is_finite(),
+    };
+    if !finite {
+        stop.angle = AngularPosition::Auto;
+        found |= InvalidGradientValue::Angle.bit();
+    }
+    found
+}
@@ -0,0 +1,99 @@
+//! The background colors of nodes with gradients are extracted into the gradient pipeline,
+//! so they're drawn beneath the node's gradients.
+#![cfg(feature = "render-internals")]
+
+use bevy::{
+    ecs::system::RunSystemOnce,
+    prelude::*,
+    render::{MainWorld, sync_world::MainEntity},
+    sprite::BorderRect,
+    ui::{
+        ComputedNodeTarget, ExtractedUiItem, ExtractedUiNode, ExtractedUiNodes, NodeType,
+        ResolvedBorderRadius,
+    },
+};
+use bevy_ui_gradients::{
+    render::{
+        BACKGROUND_COLOR_Z_OFFSET, ExtractedColorStops, ExtractedGradients,
+        extract_gradient_background_colors,
+    },
+    *,
+};
+
+fn extract_background_color(gradient: impl Bundle) -> (ExtractedGradients, ExtractedUiNodes) {
+    let mut main_world = World::new();
+    let entity = main_world
+        .spawn((
+            ComputedNode {
+                size: Vec2::splat(100.),
+                ..default()
+            },
+            ComputedNodeTarget::default(),
+            GlobalTransform::default(),
+            gradient,
+        ))
+        .id();
+
+    let mut render_world = World::new();
+    render_world.init_resource::<MainWorld>();
+    **render_world.resource_mut::<MainWorld>() = main_world;
+    render_world.init_resource::<ExtractedGradients>();
+    render_world.init_resource::<ExtractedColorStops>();
+    render_world.insert_resource(ExtractedUiNodes {
+        uinodes: vec![ExtractedUiNode {
+            stack_index: 0,
+            color: LinearRgba::RED,
+            rect: Rect::new(0., 0., 100., 100.),
+            image: AssetId::default(),
+            clip: None,
+            extracted_camera_entity: Entity::PLACEHOLDER,
+            item: ExtractedUiItem::Node {
+                atlas_scaling: None,
+                flip_x: false,
+                flip_y: false,
+                border_radius: ResolvedBorderRadius::ZERO,
+                border: BorderRect::ZERO,
+                node_type: NodeType::Rect,
+                transform: Mat4::IDENTITY,
+            },
+            main_entity: MainEntity::from(entity),
+            render_entity: Entity::PLACEHOLDER,
+        }],
+        glyphs: vec![],
+    });
+
+    render_world
+        .run_system_once(extract_gradient_background_colors)
+        .unwrap();
+
+    (
+        render_world.remove_resource().unwrap(),
+        render_world.remove_resource().unwrap(),
+    )
+}
+
+fn assert_extracted_beneath_gradients(
+    (extracted_gradients, extracted_uinodes): (ExtractedGradients, ExtractedUiNodes),
+) {
+    assert!(extracted_uinodes.uinodes.is_empty());
+    assert_eq!(extracted_gradients.items.len(), 1);
+    assert_eq!(
+        extracted_gradients.items[0].z_offset,
+        BACKGROUND_COLOR_Z_OFFSET
+    );
+    assert_eq!(extracted_gradients.image_nodes.len(), 1);
+}
+
+#[test]
+fn background_color_beneath_gradient() {
+    assert_extracted_beneath_gradients(extract_background_color(BackgroundGradient::from(
+        LinearGradient::to_bottom(vec![Color::WHITE.into(), Color::BLACK.into()]),
+    )));
+}
+
+#[test]
+fn background_color_beneath_shared_gradient() {
+    assert_extracted_beneath_gradients(extract_background_color(SharedBackgroundGradient::new(
+        LinearGradient::to_bottom(vec![Color::WHITE.into(), Color::BLACK.into()]),
+    )));
+}
@@ -0,0 +1,55 @@
+//! Nodes with `InteractionGradients` stop updating their gradients once a transition is done,
+//! unless the gradients of the states are edited.
+
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use bevy_ui_gradients::*;
+
+fn spawn_button(world: &mut World) -> Entity {
+    world.init_resource::<Time>();
+    world.init_resource::<GradientAccessibility>();
+    world
+        .spawn((
+            Interaction::None,
+            InteractionGradients::new(
+                LinearGradient::to_top(vec![Color::BLACK.into()]),
+                LinearGradient::to_top(vec![Color::WHITE.into()]),
+                LinearGradient::to_top(vec![Color::WHITE.into()]),
+            ),
+            BackgroundGradient::default(),
+        ))
+        .id()
+}
+
+fn changed(world: &mut World, entity: Entity) -> (bool, bool) {
+    let mut query = world.query::<(Ref<InteractionGradients>, Ref<BackgroundGradient>)>();
+    let (gradients, background) = query.get(world, entity).unwrap();
+    (gradients.is_changed(), background.is_changed())
+}
+
+#[test]
+fn settled_transition_is_not_updated() {
+    let mut world = World::new();
+    let entity = spawn_button(&mut world);
+    world.run_system_once(update_interaction_gradients).unwrap();
+    assert_eq!(world.get::<BackgroundGradient>(entity).unwrap().0.len(), 1);
+
+    world.clear_trackers();
+    world.run_system_once(update_interaction_gradients).unwrap();
+    assert_eq!(changed(&mut world, entity), (false, false));
+}
+
+#[test]
+fn edited_gradients_are_applied_after_the_transition() {
+    let mut world = World::new();
+    let entity = spawn_button(&mut world);
+    world.run_system_once(update_interaction_gradients).unwrap();
+
+    world.clear_trackers();
+    let red: Gradient = LinearGradient::to_top(vec![Color::srgb(1., 0., 0.).into()]).into();
+    world.get_mut::<InteractionGradients>(entity).unwrap().none = red.clone().into();
+    world.run_system_once(update_interaction_gradients).unwrap();
+    assert_eq!(
+        world.get::<BackgroundGradient>(entity).unwrap().0,
+        vec![red]
+    );
+}